@@ -17,6 +17,8 @@ use zksync_web3_decl::{
 #[derive(Debug, Deserialize, Clone, PartialEq)]
 pub struct RemoteENConfig {
     pub diamond_proxy_addr: Address,
+    pub bridgehub_proxy_addr: Option<Address>,
+    pub scheduler_vk_hash: Option<H256>,
     pub l1_erc20_bridge_proxy_addr: Address,
     pub l2_erc20_bridge_addr: Address,
     pub l1_weth_bridge_proxy_addr: Option<Address>,
@@ -40,6 +42,15 @@ impl RemoteENConfig {
             .get_main_contract()
             .await
             .context("Failed to fetch L1 contract address")?;
+        let bridgehub_proxy_addr = client
+            .get_bridgehub_contract()
+            .await
+            .context("Failed to fetch Bridgehub contract address")?
+            .map(|bridgehub| bridgehub.bridgehub_proxy_addr);
+        let scheduler_vk_hash = client
+            .get_verification_key_hash(None)
+            .await
+            .context("Failed to fetch verification key hash")?;
         let l2_chain_id = L2ChainId(
             client
                 .chain_id()
@@ -57,6 +68,8 @@ impl RemoteENConfig {
 
         Ok(Self {
             diamond_proxy_addr,
+            bridgehub_proxy_addr,
+            scheduler_vk_hash,
             l2_testnet_paymaster_addr,
             l1_erc20_bridge_proxy_addr: bridges.l1_erc20_default_bridge,
             l2_erc20_bridge_addr: bridges.l2_erc20_default_bridge,
@@ -110,6 +123,53 @@ pub struct OptionalENConfig {
     vm_concurrency_limit: Option<usize>,
     /// Smart contract source code cache size for the API server.
     factory_deps_cache_size_mb: Option<usize>,
+    /// Max possible number of miniblocks that can be covered by a single `eth_getLogs` request.
+    /// If not set, 10000 miniblocks is used.
+    max_logs_block_range: Option<u32>,
+    /// Number of miniblocks that have to be sealed on top of the miniblock including a transaction
+    /// for the transaction to be considered soft-confirmed.
+    /// If not set, inclusion into a sealed miniblock is enough.
+    soft_confirmation_blocks: Option<u32>,
+    /// Max possible number of L1 batches that can be covered by a single `zks_getBatchDiff` request.
+    max_batch_diff_range: Option<u32>,
+    /// Maximum number of leaves that `zks_getL2ToL1LogProof` returns when the full tree is requested.
+    max_l2_to_l1_log_proof_leaves: Option<usize>,
+    /// Maximum number of transactions that `zks_getTransactionsInBatch` returns in a single page.
+    max_batch_transactions_page_size: Option<u32>,
+    /// Whether `zks_getBatchL1CommitData` is enabled. Disabled by default since the commit data
+    /// includes all the batch pubdata and factory deps and may be large.
+    l1_commit_data_enabled: Option<bool>,
+    /// Maximum number of tokens `zks_getConfirmedTokens` returns when on-chain verification is requested.
+    max_verified_tokens: Option<u32>,
+    /// Safety buffer (in percent) that `zks_getRecommendedL1ToL2GasLimit` adds on top of the estimated gas limit.
+    l1_to_l2_gas_limit_buffer_percent: Option<u32>,
+    /// Whether `zks_getBatchCommitmentInputs` is enabled. Disabled by default since the inputs
+    /// include all the batch L2 -> L1 logs and storage writes and may be large.
+    commitment_inputs_enabled: Option<bool>,
+    /// Maximum number of storage keys that can be requested in a single `zks_getStorageAtBatch` call.
+    max_storage_keys_per_request: Option<u32>,
+    /// Maximum number of proofs that `zks_getBatchL2ToL1LogProofs` returns in a single page.
+    max_batch_l2_to_l1_log_proofs_page_size: Option<u32>,
+    /// Whether `zks_getConnectionPoolStats` is available. Should only be enabled on internal endpoints.
+    connection_pool_stats_enabled: Option<bool>,
+    /// Maximum number of transaction hashes accepted by a single `zks_getTransactionDetailsBatch` call.
+    max_transaction_details_batch_size: Option<u32>,
+    /// Maximum number of keys that `zks_getAccountStorageKeys` returns in a single page.
+    max_account_storage_keys_page_size: Option<u32>,
+    /// How often the counts returned by `zks_getChainStats` are refreshed, in seconds.
+    chain_stats_refresh_interval_sec: Option<u64>,
+    /// Maximum number of balances that `zks_getAccountBalancesPaged` returns in a single page.
+    max_account_balances_page_size: Option<u32>,
+    /// Time-to-live of cached `zks_getAllAccountBalances` responses, in milliseconds.
+    account_balances_cache_ttl_ms: Option<u64>,
+    /// Maximum number of accounts with cached `zks_getAllAccountBalances` responses.
+    account_balances_cache_capacity: Option<u64>,
+    /// Whether unsafe administrative methods (e.g. `zks_setKnownBytecode`) are enabled. Must stay disabled on public nodes.
+    unsafe_admin_methods_enabled: Option<bool>,
+    /// Maximum number of miniblocks that can be requested in a single `zks_getBlockDetailsRange` call.
+    max_block_details_range: Option<u32>,
+    /// Maximum number of miniblocks that can be requested in a single `zks_getL1GasPriceHistory` call.
+    max_l1_gas_price_history_range: Option<u32>,
 }
 
 impl OptionalENConfig {
@@ -169,6 +229,90 @@ impl OptionalENConfig {
         // 128MB is the default smart contract code cache size.
         self.factory_deps_cache_size_mb.unwrap_or(128)
     }
+
+    pub fn max_logs_block_range(&self) -> u32 {
+        self.max_logs_block_range.unwrap_or(10000)
+    }
+
+    pub fn soft_confirmation_blocks(&self) -> u32 {
+        self.soft_confirmation_blocks.unwrap_or(0)
+    }
+
+    pub fn max_batch_diff_range(&self) -> u32 {
+        self.max_batch_diff_range.unwrap_or(100)
+    }
+
+    pub fn max_l2_to_l1_log_proof_leaves(&self) -> usize {
+        self.max_l2_to_l1_log_proof_leaves.unwrap_or(1024)
+    }
+
+    pub fn max_batch_transactions_page_size(&self) -> u32 {
+        self.max_batch_transactions_page_size.unwrap_or(100)
+    }
+
+    pub fn l1_commit_data_enabled(&self) -> bool {
+        self.l1_commit_data_enabled.unwrap_or(false)
+    }
+
+    pub fn max_verified_tokens(&self) -> u32 {
+        self.max_verified_tokens.unwrap_or(50)
+    }
+
+    pub fn l1_to_l2_gas_limit_buffer_percent(&self) -> u32 {
+        self.l1_to_l2_gas_limit_buffer_percent.unwrap_or(20)
+    }
+
+    pub fn commitment_inputs_enabled(&self) -> bool {
+        self.commitment_inputs_enabled.unwrap_or(false)
+    }
+
+    pub fn max_storage_keys_per_request(&self) -> u32 {
+        self.max_storage_keys_per_request.unwrap_or(100)
+    }
+
+    pub fn max_batch_l2_to_l1_log_proofs_page_size(&self) -> u32 {
+        self.max_batch_l2_to_l1_log_proofs_page_size.unwrap_or(100)
+    }
+
+    pub fn connection_pool_stats_enabled(&self) -> bool {
+        self.connection_pool_stats_enabled.unwrap_or(false)
+    }
+
+    pub fn max_transaction_details_batch_size(&self) -> u32 {
+        self.max_transaction_details_batch_size.unwrap_or(100)
+    }
+
+    pub fn max_account_storage_keys_page_size(&self) -> u32 {
+        self.max_account_storage_keys_page_size.unwrap_or(1000)
+    }
+
+    pub fn chain_stats_refresh_interval_sec(&self) -> u64 {
+        self.chain_stats_refresh_interval_sec.unwrap_or(60)
+    }
+
+    pub fn max_account_balances_page_size(&self) -> u32 {
+        self.max_account_balances_page_size.unwrap_or(100)
+    }
+
+    pub fn account_balances_cache_ttl_ms(&self) -> u64 {
+        self.account_balances_cache_ttl_ms.unwrap_or(1000)
+    }
+
+    pub fn account_balances_cache_capacity(&self) -> u64 {
+        self.account_balances_cache_capacity.unwrap_or(10000)
+    }
+
+    pub fn unsafe_admin_methods_enabled(&self) -> bool {
+        self.unsafe_admin_methods_enabled.unwrap_or(false)
+    }
+
+    pub fn max_block_details_range(&self) -> u32 {
+        self.max_block_details_range.unwrap_or(100)
+    }
+
+    pub fn max_l1_gas_price_history_range(&self) -> u32 {
+        self.max_l1_gas_price_history_range.unwrap_or(1000)
+    }
 }
 
 /// This part of the external node config is required for its operation.
@@ -327,8 +471,37 @@ impl From<ExternalNodeConfig> for InternalApiConfig {
                 l2_weth_bridge: config.remote.l2_weth_bridge_addr,
             },
             diamond_proxy_addr: config.remote.diamond_proxy_addr,
+            bridgehub_proxy_addr: config.remote.bridgehub_proxy_addr,
+            scheduler_vk_hash: config.remote.scheduler_vk_hash,
             l2_testnet_paymaster_addr: config.remote.l2_testnet_paymaster_addr,
             req_entities_limit: config.optional.req_entities_limit(),
+            max_logs_block_range: config.optional.max_logs_block_range(),
+            soft_confirmation_blocks: config.optional.soft_confirmation_blocks(),
+            max_batch_diff_range: config.optional.max_batch_diff_range(),
+            max_l2_to_l1_log_proof_leaves: config.optional.max_l2_to_l1_log_proof_leaves(),
+            max_batch_transactions_page_size: config.optional.max_batch_transactions_page_size(),
+            l1_commit_data_enabled: config.optional.l1_commit_data_enabled(),
+            max_verified_tokens: config.optional.max_verified_tokens(),
+            l1_to_l2_gas_limit_buffer_percent: config.optional.l1_to_l2_gas_limit_buffer_percent(),
+            commitment_inputs_enabled: config.optional.commitment_inputs_enabled(),
+            max_storage_keys_per_request: config.optional.max_storage_keys_per_request(),
+            max_batch_l2_to_l1_log_proofs_page_size: config
+                .optional
+                .max_batch_l2_to_l1_log_proofs_page_size(),
+            connection_pool_stats_enabled: config.optional.connection_pool_stats_enabled(),
+            max_transaction_details_batch_size: config
+                .optional
+                .max_transaction_details_batch_size(),
+            max_account_storage_keys_page_size: config
+                .optional
+                .max_account_storage_keys_page_size(),
+            chain_stats_refresh_interval_sec: config.optional.chain_stats_refresh_interval_sec(),
+            max_account_balances_page_size: config.optional.max_account_balances_page_size(),
+            account_balances_cache_ttl_ms: config.optional.account_balances_cache_ttl_ms(),
+            account_balances_cache_capacity: config.optional.account_balances_cache_capacity(),
+            unsafe_admin_methods_enabled: config.optional.unsafe_admin_methods_enabled(),
+            max_block_details_range: config.optional.max_block_details_range(),
+            max_l1_gas_price_history_range: config.optional.max_l1_gas_price_history_range(),
         }
     }
 }
@@ -345,6 +518,9 @@ impl From<ExternalNodeConfig> for TxSenderConfig {
             validation_computational_gas_limit: config.required.validation_computational_gas_limit,
             default_aa: config.required.default_aa_hash,
             bootloader: config.required.bootloader_hash,
+            // The external node doesn't seal batches on its own.
+            block_commit_deadline_ms: None,
+            transaction_slots: None,
         }
     }
 }
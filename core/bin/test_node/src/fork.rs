@@ -202,7 +202,7 @@ impl ForkDetails {
         chain_id: Option<L2ChainId>,
     ) -> Self {
         let block_details = client
-            .get_block_details(MiniblockNumber(miniblock as u32))
+            .get_block_details(MiniblockNumber(miniblock as u32).into())
             .await
             .unwrap()
             .unwrap_or_else(|| panic!("Could not find block {:?} in {:?}", miniblock, url));
@@ -274,8 +274,10 @@ impl ForkDetails {
         let miniblock = MiniblockNumber(tx_details.block_number.unwrap().as_u32());
 
         // And we're fetching all the transactions from this miniblock.
-        let block_transactions: Vec<zksync_types::Transaction> =
-            client.get_raw_block_transactions(miniblock).await.unwrap();
+        let block_transactions: Vec<zksync_types::Transaction> = client
+            .get_raw_block_transactions(miniblock.into())
+            .await
+            .unwrap();
         let mut tx_to_apply = Vec::new();
 
         for tx in block_transactions {
@@ -17,20 +17,30 @@ impl ZksNamespaceT for ZkMockNamespaceImpl {
     fn estimate_fee(
         &self,
         _req: zksync_types::transaction_request::CallRequest,
-    ) -> jsonrpc_core::BoxFuture<jsonrpc_core::Result<zksync_types::fee::Fee>> {
+        _deterministic: Option<bool>,
+        format: Option<zksync_types::fee::FeeFormat>,
+    ) -> jsonrpc_core::BoxFuture<jsonrpc_core::Result<zksync_types::fee::FeeEstimate>> {
         Box::pin(async move {
-            Ok(zksync_types::fee::Fee {
+            let fee = zksync_types::fee::Fee {
                 gas_limit: U256::from(1000000000),
                 max_fee_per_gas: U256::from(1000000000),
                 max_priority_fee_per_gas: U256::from(1000000000),
                 gas_per_pubdata_limit: U256::from(1000000000),
-            })
+            };
+            Ok(zksync_types::fee::FeeEstimate::new(fee, format))
         })
     }
 
+    fn estimate_fee_detailed(
+        &self,
+        _req: zksync_types::transaction_request::CallRequest,
+    ) -> jsonrpc_core::BoxFuture<jsonrpc_core::Result<zksync_types::fee::DetailedFee>> {
+        not_implemented!()
+    }
+
     fn get_raw_block_transactions(
         &self,
-        _block_number: MiniblockNumber,
+        _block: zksync_types::api::MiniblockId,
     ) -> jsonrpc_core::BoxFuture<jsonrpc_core::Result<Vec<zksync_types::Transaction>>> {
         not_implemented!()
     }
@@ -38,7 +48,33 @@ impl ZksNamespaceT for ZkMockNamespaceImpl {
     fn estimate_gas_l1_to_l2(
         &self,
         _req: zksync_types::transaction_request::CallRequest,
-    ) -> jsonrpc_core::BoxFuture<jsonrpc_core::Result<U256>> {
+    ) -> jsonrpc_core::BoxFuture<jsonrpc_core::Result<zksync_basic_types::U256>> {
+        not_implemented!()
+    }
+
+    fn estimate_gas_l1_to_l2_encoded(
+        &self,
+        _req: zksync_types::transaction_request::CallRequest,
+        _encoding: Option<zksync_types::api::IntegerEncoding>,
+    ) -> jsonrpc_core::BoxFuture<jsonrpc_core::Result<zksync_types::api::EncodedU256>> {
+        not_implemented!()
+    }
+
+    fn estimate_gas_l1_to_l2_detailed(
+        &self,
+        _req: zksync_types::transaction_request::CallRequest,
+        _gas_per_pubdata: Option<zksync_basic_types::U256>,
+        _refund_recipient: Option<zksync_basic_types::Address>,
+    ) -> jsonrpc_core::BoxFuture<jsonrpc_core::Result<zksync_basic_types::U256>> {
+        not_implemented!()
+    }
+
+    fn get_recommended_l1_to_l2_gas_limit(
+        &self,
+        _req: zksync_types::transaction_request::CallRequest,
+    ) -> jsonrpc_core::BoxFuture<
+        jsonrpc_core::Result<zksync_types::api::L1ToL2GasLimitRecommendation>,
+    > {
         not_implemented!()
     }
 
@@ -48,6 +84,13 @@ impl ZksNamespaceT for ZkMockNamespaceImpl {
         not_implemented!()
     }
 
+    fn get_bridgehub_contract(
+        &self,
+    ) -> jsonrpc_core::BoxFuture<jsonrpc_core::Result<Option<zksync_types::api::BridgehubContract>>>
+    {
+        not_implemented!()
+    }
+
     fn get_testnet_paymaster(
         &self,
     ) -> jsonrpc_core::BoxFuture<jsonrpc_core::Result<Option<zksync_basic_types::Address>>> {
@@ -66,14 +109,52 @@ impl ZksNamespaceT for ZkMockNamespaceImpl {
         not_implemented!()
     }
 
+    fn get_current_block_gas_limit(
+        &self,
+    ) -> jsonrpc_core::BoxFuture<jsonrpc_core::Result<zksync_types::api::BlockGasLimit>> {
+        not_implemented!()
+    }
+
     fn get_confirmed_tokens(
         &self,
         _from: u32,
         _limit: u8,
+        _eth_first: Option<bool>,
+        _verify_onchain: Option<bool>,
+        _liquidity_filter: Option<zksync_types::api::TokenLiquidityFilter>,
+    ) -> jsonrpc_core::BoxFuture<jsonrpc_core::Result<Vec<zksync_web3_decl::types::Token>>> {
+        not_implemented!()
+    }
+
+    fn get_confirmed_tokens_if_modified(
+        &self,
+        _if_modified_after: u64,
+        _from: u32,
+        _limit: u8,
+        _eth_first: Option<bool>,
+        _verify_onchain: Option<bool>,
+    ) -> jsonrpc_core::BoxFuture<jsonrpc_core::Result<Option<Vec<zksync_web3_decl::types::Token>>>>
+    {
+        not_implemented!()
+    }
+
+    fn get_confirmed_tokens_filtered(
+        &self,
+        _symbol: Option<String>,
+        _sort: Option<zksync_types::api::TokenSortOrder>,
+        _from: u32,
+        _limit: u8,
     ) -> jsonrpc_core::BoxFuture<jsonrpc_core::Result<Vec<zksync_web3_decl::types::Token>>> {
         not_implemented!()
     }
 
+    fn get_token_by_l2_address(
+        &self,
+        _l2_address: zksync_basic_types::Address,
+    ) -> jsonrpc_core::BoxFuture<jsonrpc_core::Result<Option<zksync_web3_decl::types::Token>>> {
+        not_implemented!()
+    }
+
     fn get_token_price(
         &self,
         _token_address: zksync_basic_types::Address,
@@ -81,15 +162,53 @@ impl ZksNamespaceT for ZkMockNamespaceImpl {
         not_implemented!()
     }
 
+    fn get_token_price_at(
+        &self,
+        _token_address: zksync_basic_types::Address,
+        _at: zksync_types::api::BlockIdVariant,
+    ) -> jsonrpc_core::BoxFuture<jsonrpc_core::Result<zksync_types::tokens::TokenPrice>> {
+        not_implemented!()
+    }
+
+    fn get_base_token_price(
+        &self,
+    ) -> jsonrpc_core::BoxFuture<jsonrpc_core::Result<Option<zksync_types::api::BaseTokenPrice>>>
+    {
+        not_implemented!()
+    }
+
     fn get_all_account_balances(
         &self,
         _address: zksync_basic_types::Address,
     ) -> jsonrpc_core::BoxFuture<
-        jsonrpc_core::Result<std::collections::HashMap<zksync_basic_types::Address, U256>>,
+        jsonrpc_core::Result<
+            std::collections::HashMap<zksync_basic_types::Address, zksync_basic_types::U256>,
+        >,
+    > {
+        not_implemented!()
+    }
+
+    fn get_all_account_balances_encoded(
+        &self,
+        _address: zksync_basic_types::Address,
+        _encoding: Option<zksync_types::api::IntegerEncoding>,
+    ) -> jsonrpc_core::BoxFuture<
+        jsonrpc_core::Result<
+            std::collections::HashMap<zksync_basic_types::Address, zksync_types::api::EncodedU256>,
+        >,
     > {
         not_implemented!()
     }
 
+    fn get_account_balances_paged(
+        &self,
+        _address: zksync_basic_types::Address,
+        _after_token: Option<zksync_basic_types::Address>,
+        _limit: Option<u32>,
+    ) -> jsonrpc_core::BoxFuture<jsonrpc_core::Result<zksync_types::api::AccountBalancesPage>> {
+        not_implemented!()
+    }
+
     fn get_l2_to_l1_msg_proof(
         &self,
         _block: zksync_basic_types::MiniblockNumber,
@@ -105,26 +224,99 @@ impl ZksNamespaceT for ZkMockNamespaceImpl {
         &self,
         _tx_hash: zksync_basic_types::H256,
         _index: Option<usize>,
+        _full_tree: Option<bool>,
     ) -> jsonrpc_core::BoxFuture<jsonrpc_core::Result<Option<zksync_types::api::L2ToL1LogProof>>>
     {
         not_implemented!()
     }
 
+    fn get_withdrawal_params(
+        &self,
+        _withdrawal_hash: zksync_basic_types::H256,
+        _index: Option<usize>,
+    ) -> jsonrpc_core::BoxFuture<jsonrpc_core::Result<Option<zksync_types::api::WithdrawalParams>>>
+    {
+        not_implemented!()
+    }
+
+    fn get_l2_to_l1_log_proof_for_batch(
+        &self,
+        _batch: zksync_basic_types::L1BatchNumber,
+        _index: usize,
+    ) -> jsonrpc_core::BoxFuture<jsonrpc_core::Result<Option<zksync_types::api::L2ToL1LogProof>>>
+    {
+        not_implemented!()
+    }
+
+    fn get_batch_l2_to_l1_log_proofs(
+        &self,
+        _batch: zksync_basic_types::L1BatchNumber,
+        _cursor: Option<u32>,
+        _limit: Option<u32>,
+    ) -> jsonrpc_core::BoxFuture<
+        jsonrpc_core::Result<Option<zksync_types::api::BatchL2ToL1LogProofsPage>>,
+    > {
+        not_implemented!()
+    }
+
+    fn verify_l2_to_l1_log_proof(
+        &self,
+        _proof: zksync_types::api::L2ToL1LogProof,
+        _batch: zksync_basic_types::L1BatchNumber,
+        _leaf: zksync_basic_types::H256,
+    ) -> jsonrpc_core::BoxFuture<
+        jsonrpc_core::Result<Option<zksync_types::api::L2ToL1LogProofVerification>>,
+    > {
+        not_implemented!()
+    }
+
     fn get_l1_batch_number(
         &self,
     ) -> jsonrpc_core::BoxFuture<jsonrpc_core::Result<zksync_basic_types::U64>> {
         not_implemented!()
     }
 
+    fn get_next_batch_info(
+        &self,
+    ) -> jsonrpc_core::BoxFuture<jsonrpc_core::Result<zksync_types::api::NextL1BatchInfo>> {
+        not_implemented!()
+    }
+
     fn get_block_details(
         &self,
-        _block_number: zksync_basic_types::MiniblockNumber,
+        _block: zksync_types::api::MiniblockId,
     ) -> jsonrpc_core::BoxFuture<
         jsonrpc_core::Result<Option<zksync_types::explorer_api::BlockDetails>>,
     > {
         not_implemented!()
     }
 
+    fn get_block_details_range(
+        &self,
+        _from: zksync_basic_types::MiniblockNumber,
+        _to: zksync_basic_types::MiniblockNumber,
+    ) -> jsonrpc_core::BoxFuture<jsonrpc_core::Result<Vec<zksync_types::explorer_api::BlockDetails>>>
+    {
+        not_implemented!()
+    }
+
+    fn get_miniblock_header(
+        &self,
+        _block_number: zksync_basic_types::MiniblockNumber,
+    ) -> jsonrpc_core::BoxFuture<
+        jsonrpc_core::Result<Option<zksync_types::explorer_api::BlockHeaderDetails>>,
+    > {
+        not_implemented!()
+    }
+
+    fn get_miniblock_by_timestamp(
+        &self,
+        _timestamp: u64,
+        _rounding: zksync_types::api::TimestampRounding,
+    ) -> jsonrpc_core::BoxFuture<jsonrpc_core::Result<Option<MiniblockNumber>>> {
+        not_implemented!()
+    }
+
     fn get_miniblock_range(
         &self,
         _batch: zksync_basic_types::L1BatchNumber,
@@ -144,11 +336,34 @@ impl ZksNamespaceT for ZkMockNamespaceImpl {
     fn get_transaction_details(
         &self,
         _hash: zksync_basic_types::H256,
+        _include_gas_estimate: Option<bool>,
+        _include_raw: Option<bool>,
     ) -> jsonrpc_core::BoxFuture<jsonrpc_core::Result<Option<zksync_types::api::TransactionDetails>>>
     {
         not_implemented!()
     }
 
+    fn get_transaction_details_batch(
+        &self,
+        _hashes: Vec<zksync_basic_types::H256>,
+    ) -> jsonrpc_core::BoxFuture<
+        jsonrpc_core::Result<
+            Vec<(
+                zksync_basic_types::H256,
+                Option<zksync_types::api::TransactionDetails>,
+            )>,
+        >,
+    > {
+        not_implemented!()
+    }
+
+    fn send_raw_transaction_with_detailed_error(
+        &self,
+        _tx_bytes: zksync_types::Bytes,
+    ) -> jsonrpc_core::BoxFuture<jsonrpc_core::Result<zksync_basic_types::H256>> {
+        not_implemented!()
+    }
+
     fn get_l1_batch_details(
         &self,
         _batch: zksync_basic_types::L1BatchNumber,
@@ -158,6 +373,54 @@ impl ZksNamespaceT for ZkMockNamespaceImpl {
         not_implemented!()
     }
 
+    fn get_l1_batch_stats(
+        &self,
+        _batch: zksync_basic_types::L1BatchNumber,
+    ) -> jsonrpc_core::BoxFuture<jsonrpc_core::Result<Option<zksync_types::api::L1BatchStats>>>
+    {
+        not_implemented!()
+    }
+
+    fn get_latest_l1_batch_details(
+        &self,
+        _stage: Option<zksync_types::api::L1BatchStage>,
+    ) -> jsonrpc_core::BoxFuture<
+        jsonrpc_core::Result<Option<zksync_types::explorer_api::L1BatchDetails>>,
+    > {
+        not_implemented!()
+    }
+
+    fn get_batch_l1_commit_data(
+        &self,
+        _batch: zksync_basic_types::L1BatchNumber,
+    ) -> jsonrpc_core::BoxFuture<jsonrpc_core::Result<Option<zksync_types::api::BatchL1CommitData>>>
+    {
+        not_implemented!()
+    }
+
+    fn get_batch_commitment_inputs(
+        &self,
+        _batch: zksync_basic_types::L1BatchNumber,
+    ) -> jsonrpc_core::BoxFuture<
+        jsonrpc_core::Result<Option<zksync_types::api::BatchCommitmentInputs>>,
+    > {
+        not_implemented!()
+    }
+
+    fn get_l1_batch_fees(
+        &self,
+        _batch: zksync_basic_types::L1BatchNumber,
+    ) -> jsonrpc_core::BoxFuture<jsonrpc_core::Result<Option<zksync_types::api::L1BatchFees>>> {
+        not_implemented!()
+    }
+
+    fn get_effective_l1_gas_price_for_batch(
+        &self,
+        _batch: zksync_basic_types::L1BatchNumber,
+    ) -> jsonrpc_core::BoxFuture<jsonrpc_core::Result<Option<zksync_types::U64>>> {
+        not_implemented!()
+    }
+
     fn get_bytecode_by_hash(
         &self,
         _hash: zksync_basic_types::H256,
@@ -165,9 +428,189 @@ impl ZksNamespaceT for ZkMockNamespaceImpl {
         not_implemented!()
     }
 
+    fn get_bytecode_details_by_hash(
+        &self,
+        _hash: zksync_basic_types::H256,
+    ) -> jsonrpc_core::BoxFuture<jsonrpc_core::Result<Option<zksync_types::api::BytecodeDetails>>>
+    {
+        not_implemented!()
+    }
+
+    fn get_contract_creation_code(
+        &self,
+        _address: zksync_basic_types::Address,
+    ) -> jsonrpc_core::BoxFuture<
+        jsonrpc_core::Result<Option<zksync_types::api::ContractCreationCode>>,
+    > {
+        not_implemented!()
+    }
+
     fn get_l1_gas_price(
         &self,
     ) -> jsonrpc_core::BoxFuture<jsonrpc_core::Result<zksync_basic_types::U64>> {
         not_implemented!()
     }
+
+    fn get_l1_gas_price_history(
+        &self,
+        _from_miniblock: zksync_basic_types::MiniblockNumber,
+        _to_miniblock: zksync_basic_types::MiniblockNumber,
+    ) -> jsonrpc_core::BoxFuture<
+        jsonrpc_core::Result<Vec<(zksync_basic_types::MiniblockNumber, zksync_basic_types::U64)>>,
+    > {
+        not_implemented!()
+    }
+
+    fn get_l1_gas_price_wei(&self) -> jsonrpc_core::BoxFuture<jsonrpc_core::Result<U256>> {
+        not_implemented!()
+    }
+
+    fn get_fee_params(
+        &self,
+    ) -> jsonrpc_core::BoxFuture<jsonrpc_core::Result<zksync_types::api::FeeModelParams>> {
+        not_implemented!()
+    }
+
+    fn get_nonce_at(
+        &self,
+        _address: zksync_basic_types::Address,
+        _block_or_batch: zksync_types::api::BlockOrBatchNumber,
+    ) -> jsonrpc_core::BoxFuture<jsonrpc_core::Result<U256>> {
+        not_implemented!()
+    }
+
+    fn get_transaction_confirmations_needed(
+        &self,
+        _hash: zksync_basic_types::H256,
+    ) -> jsonrpc_core::BoxFuture<
+        jsonrpc_core::Result<Option<zksync_types::api::TransactionConfirmationsNeeded>>,
+    > {
+        not_implemented!()
+    }
+
+    fn get_batch_diff(
+        &self,
+        _from_batch: zksync_basic_types::L1BatchNumber,
+        _to_batch: zksync_basic_types::L1BatchNumber,
+        _after: Option<zksync_basic_types::H256>,
+    ) -> jsonrpc_core::BoxFuture<jsonrpc_core::Result<zksync_types::api::BatchDiff>> {
+        not_implemented!()
+    }
+
+    fn get_batch_proving_status(
+        &self,
+        _batch: zksync_basic_types::L1BatchNumber,
+    ) -> jsonrpc_core::BoxFuture<
+        jsonrpc_core::Result<Option<zksync_types::api::L1BatchProvingStatus>>,
+    > {
+        not_implemented!()
+    }
+
+    fn get_address_activity(
+        &self,
+        _address: zksync_basic_types::Address,
+    ) -> jsonrpc_core::BoxFuture<jsonrpc_core::Result<zksync_types::api::AddressActivity>> {
+        not_implemented!()
+    }
+
+    fn get_address_transaction_count(
+        &self,
+        _address: zksync_basic_types::Address,
+    ) -> jsonrpc_core::BoxFuture<jsonrpc_core::Result<zksync_types::api::AddressTransactionCount>>
+    {
+        not_implemented!()
+    }
+
+    fn get_verification_key_hash(
+        &self,
+        _batch: Option<zksync_basic_types::L1BatchNumber>,
+    ) -> jsonrpc_core::BoxFuture<jsonrpc_core::Result<Option<zksync_basic_types::H256>>> {
+        not_implemented!()
+    }
+
+    fn get_protocol_version(
+        &self,
+        _version_id: Option<u16>,
+    ) -> jsonrpc_core::BoxFuture<jsonrpc_core::Result<Option<zksync_types::api::ProtocolVersion>>>
+    {
+        not_implemented!()
+    }
+
+    fn get_transactions_in_batch(
+        &self,
+        _batch: zksync_basic_types::L1BatchNumber,
+        _cursor: Option<u32>,
+        _limit: Option<u32>,
+    ) -> jsonrpc_core::BoxFuture<jsonrpc_core::Result<zksync_types::api::BatchTransactionsPage>>
+    {
+        not_implemented!()
+    }
+
+    fn get_contract_deployment_bytecode_hash(
+        &self,
+        _address: zksync_basic_types::Address,
+        _block: Option<zksync_types::api::BlockId>,
+    ) -> jsonrpc_core::BoxFuture<jsonrpc_core::Result<Option<zksync_basic_types::H256>>> {
+        not_implemented!()
+    }
+
+    fn get_transaction_receipt(
+        &self,
+        _tx_hash: zksync_basic_types::H256,
+    ) -> jsonrpc_core::BoxFuture<
+        jsonrpc_core::Result<Option<zksync_types::api::ExtendedTransactionReceipt>>,
+    > {
+        not_implemented!()
+    }
+
+    fn get_storage_at_batch(
+        &self,
+        _address: zksync_basic_types::Address,
+        _keys: Vec<zksync_basic_types::H256>,
+        _block: Option<zksync_basic_types::MiniblockNumber>,
+    ) -> jsonrpc_core::BoxFuture<
+        jsonrpc_core::Result<
+            std::collections::HashMap<zksync_basic_types::H256, zksync_basic_types::H256>,
+        >,
+    > {
+        not_implemented!()
+    }
+
+    fn get_account_storage_keys(
+        &self,
+        _address: zksync_basic_types::Address,
+        _block: Option<zksync_basic_types::MiniblockNumber>,
+        _cursor: Option<zksync_basic_types::H256>,
+        _limit: Option<u32>,
+    ) -> jsonrpc_core::BoxFuture<jsonrpc_core::Result<zksync_types::api::AccountStorageKeysPage>>
+    {
+        not_implemented!()
+    }
+
+    fn replay_transaction(
+        &self,
+        _hash: zksync_basic_types::H256,
+    ) -> jsonrpc_core::BoxFuture<jsonrpc_core::Result<Option<zksync_types::api::TransactionReplay>>>
+    {
+        not_implemented!()
+    }
+
+    fn get_connection_pool_stats(
+        &self,
+    ) -> jsonrpc_core::BoxFuture<jsonrpc_core::Result<zksync_types::api::ConnectionPoolStats>> {
+        not_implemented!()
+    }
+
+    fn get_chain_stats(
+        &self,
+    ) -> jsonrpc_core::BoxFuture<jsonrpc_core::Result<zksync_types::api::ChainStats>> {
+        not_implemented!()
+    }
+
+    fn get_recent_batch_events(
+        &self,
+        _limit: u32,
+    ) -> jsonrpc_core::BoxFuture<jsonrpc_core::Result<Vec<zksync_types::api::L1BatchEvent>>> {
+        not_implemented!()
+    }
 }
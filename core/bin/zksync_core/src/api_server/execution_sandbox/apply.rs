@@ -185,7 +185,12 @@ impl BlockArgs {
                 .get_provisional_l1_batch_number_of_miniblock_unchecked(self.resolved_block_number)
                 .await
                 .unwrap();
-            (self.resolved_block_number, l1_batch_number)
+            let state_block_number = if self.before_block {
+                self.resolved_block_number - 1
+            } else {
+                self.resolved_block_number
+            };
+            (state_block_number, l1_batch_number)
         }
     }
 
@@ -6,6 +6,7 @@ use std::{collections::HashMap, mem};
 
 use vm::{
     utils::ETH_CALL_GAS_LIMIT,
+    vm::VmTxExecutionResult,
     vm_with_bootloader::{
         push_transaction_to_bootloader_memory, BootloaderJobType, TxExecutionMode,
     },
@@ -113,7 +114,7 @@ pub(crate) async fn execute_tx_eth_call(
 #[tracing::instrument(skip_all)]
 pub(crate) async fn execute_tx_with_pending_state(
     vm_permit: &VmPermit<'_>, // Proof that permit was acquired.
-    mut shared_args: TxSharedArgs,
+    shared_args: TxSharedArgs,
     execution_args: TxExecutionArgs,
     connection_pool: ConnectionPool,
     tx: Transaction,
@@ -125,6 +126,32 @@ pub(crate) async fn execute_tx_with_pending_state(
     let mut connection = connection_pool.access_storage_tagged("api").await;
     let block_args = BlockArgs::pending(&mut connection).await;
     drop(connection);
+    execute_tx_with_block_state(
+        vm_permit,
+        shared_args,
+        execution_args,
+        connection_pool,
+        tx,
+        block_args,
+        storage_read_cache,
+    )
+    .await
+}
+
+/// Same as [`execute_tx_with_pending_state()`], but executes the transaction on top of the specified block.
+#[tracing::instrument(skip_all)]
+pub(crate) async fn execute_tx_with_block_state(
+    vm_permit: &VmPermit<'_>, // Proof that permit was acquired.
+    mut shared_args: TxSharedArgs,
+    execution_args: TxExecutionArgs,
+    connection_pool: ConnectionPool,
+    tx: Transaction,
+    block_args: BlockArgs,
+    storage_read_cache: &mut HashMap<StorageKey, H256>,
+) -> (
+    Result<VmExecutionResult, SandboxExecutionError>,
+    TransactionExecutionMetrics,
+) {
     // In order for execution to pass smoothlessly, we need to ensure that block's required gasPerPubdata will be
     // <= to the one in the transaction itself.
     shared_args.adjust_l1_gas_price(tx.gas_per_pubdata_byte_limit());
@@ -206,3 +233,64 @@ async fn execute_tx_in_sandbox(
     };
     (result, tx_execution_metrics)
 }
+
+/// Re-executes an already processed transaction. `preceding_txs` are the transactions included
+/// into the same miniblock before `tx`; they are executed first to reproduce the state `tx` was executed on.
+/// `block_args` are expected to be created with [`BlockArgs::for_replay()`].
+#[allow(clippy::too_many_arguments)]
+#[tracing::instrument(skip_all)]
+pub(crate) async fn replay_tx_in_sandbox(
+    vm_permit: &VmPermit<'_>, // Proof that permit was acquired.
+    shared_args: TxSharedArgs,
+    connection_pool: ConnectionPool,
+    preceding_txs: Vec<Transaction>,
+    tx: Transaction,
+    block_args: BlockArgs,
+    base_fee: u64,
+    validation_computational_gas_limit: u32,
+) -> Result<VmTxExecutionResult, SandboxExecutionError> {
+    let execution_args = TxExecutionArgs {
+        execution_mode: TxExecutionMode::VerifyExecute,
+        enforced_nonce: None,
+        added_balance: U256::zero(),
+        enforced_base_fee: Some(base_fee),
+    };
+
+    let rt_handle = vm_permit.rt_handle();
+    let (result, _) = tokio::task::spawn_blocking(move || {
+        let span = span!(Level::DEBUG, "replay_in_sandbox").entered();
+        let result = apply::apply_vm_in_sandbox(
+            rt_handle,
+            &shared_args,
+            &execution_args,
+            &connection_pool,
+            tx,
+            block_args,
+            HashMap::new(),
+            |vm, tx| {
+                for preceding_tx in &preceding_txs {
+                    push_transaction_to_bootloader_memory(
+                        vm,
+                        preceding_tx,
+                        TxExecutionMode::VerifyExecute,
+                        None,
+                    );
+                    vm.execute_next_tx(validation_computational_gas_limit, false)?;
+                }
+                push_transaction_to_bootloader_memory(
+                    vm,
+                    &tx,
+                    TxExecutionMode::VerifyExecute,
+                    None,
+                );
+                vm.execute_next_tx(validation_computational_gas_limit, false)
+            },
+        );
+        span.exit();
+        result
+    })
+    .await
+    .unwrap();
+
+    result.map_err(Into::into)
+}
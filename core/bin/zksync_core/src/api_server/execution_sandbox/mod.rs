@@ -6,7 +6,7 @@ use zksync_config::constants::PUBLISH_BYTECODE_OVERHEAD;
 use zksync_contracts::BaseSystemContracts;
 use zksync_dal::{ConnectionPool, SqlxError, StorageProcessor};
 use zksync_state::{FactoryDepsCache, PostgresStorage, ReadStorage, StorageView};
-use zksync_types::{api, AccountTreeId, MiniblockNumber, U256};
+use zksync_types::{api, block::MiniblockHeader, AccountTreeId, MiniblockNumber, U256};
 use zksync_utils::bytecode::{compress_bytecode, hash_bytecode};
 
 // Note: keep the modules private, and instead re-export functions that make public interface.
@@ -18,7 +18,10 @@ mod vm_metrics;
 
 pub(super) use self::{
     error::SandboxExecutionError,
-    execute::{execute_tx_eth_call, execute_tx_with_pending_state, TxExecutionArgs},
+    execute::{
+        execute_tx_eth_call, execute_tx_with_block_state, execute_tx_with_pending_state,
+        replay_tx_in_sandbox, TxExecutionArgs,
+    },
 };
 
 /// Permit to invoke VM code.
@@ -217,6 +220,9 @@ pub(crate) struct BlockArgs {
     block_id: api::BlockId,
     resolved_block_number: MiniblockNumber,
     block_timestamp_s: Option<u64>,
+    /// If set, the VM is initialized with the state *preceding* `resolved_block_number`
+    /// rather than the state after it.
+    before_block: bool,
 }
 
 impl BlockArgs {
@@ -226,6 +232,18 @@ impl BlockArgs {
             block_id,
             resolved_block_number,
             block_timestamp_s: None,
+            before_block: false,
+        }
+    }
+
+    /// Creates block information for re-executing transactions of the specified miniblock.
+    /// The VM will use the miniblock timestamp and the state at the end of the previous miniblock.
+    pub fn for_replay(miniblock: &MiniblockHeader) -> Self {
+        Self {
+            block_id: api::BlockId::Number(api::BlockNumber::Number(miniblock.number.0.into())),
+            resolved_block_number: miniblock.number,
+            block_timestamp_s: Some(miniblock.timestamp),
+            before_block: true,
         }
     }
 
@@ -248,6 +266,7 @@ impl BlockArgs {
             block_id,
             resolved_block_number,
             block_timestamp_s,
+            before_block: false,
         }))
     }
 }
@@ -1,6 +1,7 @@
 use crate::api_server::execution_sandbox::SandboxExecutionError;
 use thiserror::Error;
 use vm::oracles::tracer::ValidationError;
+use zksync_types::api::TransactionRejectionReason;
 use zksync_types::l2::error::TxCheckError;
 use zksync_types::U256;
 
@@ -49,6 +50,10 @@ pub enum SubmitTxError {
         "too many factory dependencies in the transaction. {0} provided, while only {1} allowed"
     )]
     TooManyFactoryDependencies(usize, usize),
+    #[error(
+        "transaction requires {0} bytes of pubdata, while at most {1} can be published in an L1 batch"
+    )]
+    PubdataLimitExceeded(u32, u32),
     #[error("max fee per gas higher than 2^32")]
     FeePerGasTooHigh,
     #[error("max fee per pubdata byte higher than 2^32")]
@@ -90,6 +95,7 @@ impl SubmitTxError {
             SubmitTxError::UnexpectedVMBehavior(_) => "unexpected-vm-behavior",
             SubmitTxError::UnrealisticPubdataPriceLimit => "unrealistic-pubdata-price-limit",
             SubmitTxError::TooManyFactoryDependencies(_, _) => "too-many-factory-dependencies",
+            SubmitTxError::PubdataLimitExceeded(_, _) => "pubdata-limit-exceeded",
             SubmitTxError::FeePerGasTooHigh => "gas-price-limit-too-high",
             SubmitTxError::FeePerPubdataByteTooHigh => "pubdata-price-limit-too-high",
             SubmitTxError::InsufficientFundsForTransfer => "insufficient-funds-for-transfer",
@@ -98,6 +104,56 @@ impl SubmitTxError {
         }
     }
 
+    pub fn rejection_reason(&self) -> TransactionRejectionReason {
+        match self {
+            SubmitTxError::NonceIsTooHigh(_, _, _) => TransactionRejectionReason::NonceTooHigh,
+            SubmitTxError::NonceIsTooLow(_, _, _) => TransactionRejectionReason::NonceTooLow,
+            SubmitTxError::IncorrectTx(_) => TransactionRejectionReason::IncorrectTransaction,
+            SubmitTxError::NotEnoughBalanceForFeeValue(_, _, _) => {
+                TransactionRejectionReason::InsufficientFundsForFee
+            }
+            SubmitTxError::ExecutionReverted(_, _) => TransactionRejectionReason::ExecutionReverted,
+            SubmitTxError::GasLimitIsTooBig => TransactionRejectionReason::GasLimitTooBig,
+            SubmitTxError::Unexecutable(_) => TransactionRejectionReason::Unexecutable,
+            SubmitTxError::RateLimitExceeded => TransactionRejectionReason::RateLimitExceeded,
+            SubmitTxError::BootloaderFailure(_) => TransactionRejectionReason::BootloaderFailure,
+            SubmitTxError::ValidationFailed(_) => TransactionRejectionReason::ValidationFailed,
+            SubmitTxError::FailedToChargeFee(_) => TransactionRejectionReason::FailedToChargeFee,
+            SubmitTxError::PaymasterValidationFailed(_) => {
+                TransactionRejectionReason::PaymasterValidationFailed
+            }
+            SubmitTxError::PrePaymasterPreparationFailed(_) => {
+                TransactionRejectionReason::PrePaymasterPreparationFailed
+            }
+            SubmitTxError::FromIsNotAnAccount => TransactionRejectionReason::FromIsNotAnAccount,
+            SubmitTxError::MaxFeePerGasTooLow => TransactionRejectionReason::MaxFeePerGasTooLow,
+            SubmitTxError::MaxPriorityFeeGreaterThanMaxFee => {
+                TransactionRejectionReason::MaxPriorityFeeGreaterThanMaxFee
+            }
+            SubmitTxError::UnexpectedVMBehavior(_) => {
+                TransactionRejectionReason::UnexpectedVmBehavior
+            }
+            SubmitTxError::UnrealisticPubdataPriceLimit => {
+                TransactionRejectionReason::UnrealisticPubdataPriceLimit
+            }
+            SubmitTxError::TooManyFactoryDependencies(_, _) => {
+                TransactionRejectionReason::TooManyFactoryDependencies
+            }
+            SubmitTxError::PubdataLimitExceeded(_, _) => {
+                TransactionRejectionReason::PubdataLimitExceeded
+            }
+            SubmitTxError::FeePerGasTooHigh => TransactionRejectionReason::FeePerGasTooHigh,
+            SubmitTxError::FeePerPubdataByteTooHigh => {
+                TransactionRejectionReason::FeePerPubdataByteTooHigh
+            }
+            SubmitTxError::InsufficientFundsForTransfer => {
+                TransactionRejectionReason::InsufficientFundsForTransfer
+            }
+            SubmitTxError::IntrinsicGas => TransactionRejectionReason::IntrinsicGasTooLow,
+            SubmitTxError::ProxyError(_) => TransactionRejectionReason::ProxyError,
+        }
+    }
+
     pub fn data(&self) -> Vec<u8> {
         if let SubmitTxError::ExecutionReverted(_, data) = self {
             data.clone()
@@ -26,7 +26,7 @@ use zksync_contracts::{
 use zksync_dal::{transactions_dal::L2TxSubmissionResult, ConnectionPool};
 use zksync_state::FactoryDepsCache;
 use zksync_types::{
-    fee::{Fee, TransactionExecutionMetrics},
+    fee::{DetailedFee, Fee, TransactionExecutionMetrics},
     get_code_key, get_intrinsic_constants,
     l2::error::TxCheckError::TxDuplication,
     l2::L2Tx,
@@ -38,9 +38,9 @@ use zksync_utils::{bytes_to_be_words, h256_to_u256};
 
 // Local uses
 use crate::api_server::execution_sandbox::{
-    adjust_l1_gas_price_for_tx, execute_tx_eth_call, execute_tx_with_pending_state,
-    get_pubdata_for_factory_deps, BlockArgs, SandboxExecutionError, TxExecutionArgs, TxSharedArgs,
-    VmConcurrencyLimiter, VmPermit,
+    adjust_l1_gas_price_for_tx, execute_tx_eth_call, execute_tx_with_block_state,
+    execute_tx_with_pending_state, get_pubdata_for_factory_deps, BlockArgs, SandboxExecutionError,
+    TxExecutionArgs, TxSharedArgs, VmConcurrencyLimiter, VmPermit,
 };
 use crate::l1_gas_price::L1GasPriceProvider;
 use crate::state_keeper::seal_criteria::{ConditionalSealer, SealData};
@@ -179,6 +179,10 @@ pub struct TxSenderConfig {
     pub validation_computational_gas_limit: u32,
     pub default_aa: H256,
     pub bootloader: H256,
+    /// Timeout after which the state keeper seals an L1 batch. Only known on the main node.
+    pub block_commit_deadline_ms: Option<u64>,
+    /// Number of transactions that fit into an L1 batch. Only known on the main node.
+    pub transaction_slots: Option<usize>,
 }
 
 impl TxSenderConfig {
@@ -197,10 +201,22 @@ impl TxSenderConfig {
                 .validation_computational_gas_limit,
             default_aa: state_keeper_config.default_aa_hash,
             bootloader: state_keeper_config.bootloader_hash,
+            block_commit_deadline_ms: Some(state_keeper_config.block_commit_deadline_ms),
+            transaction_slots: Some(state_keeper_config.transaction_slots),
         }
     }
 }
 
+/// Inputs pinning fee estimation to a sealed miniblock, so that the estimate is reproducible.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct FixedFeeInputs {
+    /// Miniblock to execute the transaction on top of; its timestamp is used as the block timestamp.
+    pub block_args: BlockArgs,
+    /// L1 gas price used instead of the current one, e.g. the price stored for the miniblock.
+    /// It is scaled by `gas_price_scale_factor` in the same way as the current price.
+    pub l1_gas_price: u64,
+}
+
 pub struct TxSenderInner<G> {
     pub(super) sender_config: TxSenderConfig,
     pub master_connection_pool: Option<ConnectionPool>,
@@ -537,6 +553,7 @@ impl<G: L1GasPriceProvider> TxSender<G> {
         tx_gas_limit: u32,
         l1_gas_price: u64,
         base_fee: u64,
+        block_args: Option<BlockArgs>,
         storage_read_cache: &mut HashMap<StorageKey, H256>,
     ) -> Result<VmExecutionResult, SandboxExecutionError> {
         let gas_limit_with_overhead = tx_gas_limit
@@ -563,15 +580,29 @@ impl<G: L1GasPriceProvider> TxSender<G> {
         let vm_execution_cache_misses_limit = self.0.sender_config.vm_execution_cache_misses_limit;
         let execution_args =
             TxExecutionArgs::for_gas_estimate(vm_execution_cache_misses_limit, &tx, base_fee);
-        let (exec_result, tx_metrics) = execute_tx_with_pending_state(
-            vm_permit,
-            shared_args,
-            execution_args,
-            self.0.replica_connection_pool.clone(),
-            tx.clone(),
-            storage_read_cache,
-        )
-        .await;
+        let connection_pool = self.0.replica_connection_pool.clone();
+        let (exec_result, tx_metrics) = if let Some(block_args) = block_args {
+            execute_tx_with_block_state(
+                vm_permit,
+                shared_args,
+                execution_args,
+                connection_pool,
+                tx.clone(),
+                block_args,
+                storage_read_cache,
+            )
+            .await
+        } else {
+            execute_tx_with_pending_state(
+                vm_permit,
+                shared_args,
+                execution_args,
+                connection_pool,
+                tx.clone(),
+                storage_read_cache,
+            )
+            .await
+        };
 
         if let Err(err) = self.ensure_tx_executable(tx, &tx_metrics, false) {
             let SubmitTxError::Unexecutable(message) = err else { unreachable!() };
@@ -590,15 +621,46 @@ impl<G: L1GasPriceProvider> TxSender<G> {
         }
     }
 
-    pub async fn get_txs_fee_in_wei(
+    /// Estimates the fee for the transaction.
+    ///
+    /// If `fixed_inputs` are set, the estimation doesn't depend on the current L1 gas price
+    /// and wall-clock time: the transaction is executed on top of the specified sealed miniblock
+    /// using its timestamp and the provided L1 gas price.
+    pub(crate) async fn get_txs_fee_in_wei(
         &self,
-        mut tx: Transaction,
+        tx: Transaction,
         estimated_fee_scale_factor: f64,
         acceptable_overestimation: u32,
+        fixed_inputs: Option<FixedFeeInputs>,
     ) -> Result<Fee, SubmitTxError> {
+        let detailed_fee = self
+            .get_txs_detailed_fee_in_wei(
+                tx,
+                estimated_fee_scale_factor,
+                acceptable_overestimation,
+                fixed_inputs,
+            )
+            .await?;
+        Ok(detailed_fee.fee)
+    }
+
+    /// Same as [`Self::get_txs_fee_in_wei()`], but also returns the components
+    /// the estimated gas limit consists of.
+    pub(crate) async fn get_txs_detailed_fee_in_wei(
+        &self,
+        mut tx: Transaction,
+        estimated_fee_scale_factor: f64,
+        acceptable_overestimation: u32,
+        fixed_inputs: Option<FixedFeeInputs>,
+    ) -> Result<DetailedFee, SubmitTxError> {
         let estimation_started_at = Instant::now();
+
         let l1_gas_price = {
-            let effective_gas_price = self.0.l1_gas_price_source.estimate_effective_gas_price();
+            let effective_gas_price = if let Some(inputs) = &fixed_inputs {
+                inputs.l1_gas_price
+            } else {
+                self.0.l1_gas_price_source.estimate_effective_gas_price()
+            };
             let current_l1_gas_price =
                 ((effective_gas_price as f64) * self.0.sender_config.gas_price_scale_factor) as u64;
 
@@ -675,13 +737,15 @@ impl<G: L1GasPriceProvider> TxSender<G> {
             )
             .await;
             if pubdata_for_factory_deps > MAX_PUBDATA_PER_BLOCK {
-                return Err(SubmitTxError::Unexecutable(
-                    "exceeds limit for published pubdata".to_string(),
+                return Err(SubmitTxError::PubdataLimitExceeded(
+                    pubdata_for_factory_deps,
+                    MAX_PUBDATA_PER_BLOCK,
                 ));
             }
             pubdata_for_factory_deps * (gas_per_pubdata_byte as u32)
         };
 
+        let block_args = fixed_inputs.map(|inputs| inputs.block_args);
         // Rolling cache with storage values that were read from the DB.
         let mut storage_read_cache = HashMap::new();
 
@@ -718,6 +782,7 @@ impl<G: L1GasPriceProvider> TxSender<G> {
                     try_gas_limit,
                     l1_gas_price,
                     base_fee,
+                    block_args,
                     &mut storage_read_cache,
                 )
                 .await;
@@ -756,6 +821,7 @@ impl<G: L1GasPriceProvider> TxSender<G> {
                 suggested_gas_limit,
                 l1_gas_price,
                 base_fee,
+                block_args,
                 &mut storage_read_cache,
             )
             .await;
@@ -782,11 +848,17 @@ impl<G: L1GasPriceProvider> TxSender<G> {
                         }
                     };
 
-                Ok(Fee {
+                let fee = Fee {
                     max_fee_per_gas: base_fee.into(),
                     max_priority_fee_per_gas: 0u32.into(),
                     gas_limit: full_gas_limit.into(),
                     gas_per_pubdata_limit: gas_per_pubdata_byte.into(),
+                };
+                Ok(DetailedFee {
+                    fee,
+                    computational_gas: tx_body_gas_limit.into(),
+                    pubdata_gas: gas_for_bytecodes_pubdata.into(),
+                    overhead_gas: overhead.into(),
                 })
             }
         }
@@ -1,4 +1,5 @@
 use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
 use tokio::sync::RwLock;
 
 use zksync_types::{
@@ -7,7 +8,11 @@ use zksync_types::{
     H256,
 };
 use zksync_web3_decl::{
-    jsonrpsee::http_client::{HttpClient, HttpClientBuilder},
+    jsonrpsee::{
+        core::Error as RpcError,
+        http_client::{HttpClient, HttpClientBuilder},
+        types::error::METHOD_NOT_FOUND_CODE,
+    },
     namespaces::{EthNamespaceClient, ZksNamespaceClient},
     RpcResult,
 };
@@ -18,6 +23,8 @@ use zksync_web3_decl::{
 pub struct TxProxy {
     tx_cache: RwLock<HashMap<H256, L2Tx>>,
     client: HttpClient,
+    /// Set to `false` once the main node turns out not to support `zks_getTransactionDetailsBatch`.
+    supports_batched_details: AtomicBool,
 }
 
 impl TxProxy {
@@ -26,6 +33,7 @@ impl TxProxy {
         Self {
             client,
             tx_cache: RwLock::new(HashMap::new()),
+            supports_batched_details: AtomicBool::new(true),
         }
     }
 
@@ -64,8 +72,33 @@ impl TxProxy {
         }
     }
 
-    pub async fn request_tx_details(&self, hash: H256) -> RpcResult<Option<TransactionDetails>> {
-        self.client.get_transaction_details(hash).await
+    /// Requests details of the specified transactions from the main node. If the main node doesn't support
+    /// `zks_getTransactionDetailsBatch` yet, details are requested for each transaction separately.
+    pub async fn request_txs_details(
+        &self,
+        hashes: Vec<H256>,
+    ) -> RpcResult<Vec<(H256, Option<TransactionDetails>)>> {
+        if self.supports_batched_details.load(Ordering::Relaxed) {
+            match self
+                .client
+                .get_transaction_details_batch(hashes.clone())
+                .await
+            {
+                Err(RpcError::Call(err)) if err.code() == METHOD_NOT_FOUND_CODE => {
+                    vlog::info!("Main node doesn't support batched transaction details");
+                    self.supports_batched_details
+                        .store(false, Ordering::Relaxed);
+                }
+                result => return result,
+            }
+        }
+
+        let mut details = Vec::with_capacity(hashes.len());
+        for hash in hashes {
+            let tx_details = self.client.get_transaction_details(hash, None).await?;
+            details.push((hash, tx_details));
+        }
+        Ok(details)
     }
 
     pub async fn request_tx_receipt(&self, hash: H256) -> RpcResult<Option<TransactionReceipt>> {
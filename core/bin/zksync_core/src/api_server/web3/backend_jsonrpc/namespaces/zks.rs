@@ -8,13 +8,24 @@ use jsonrpc_derive::rpc;
 
 // Workspace uses
 use zksync_types::{
-    api::{BridgeAddresses, L2ToL1LogProof, TransactionDetails},
-    explorer_api::{BlockDetails, L1BatchDetails},
-    fee::Fee,
+    api::{
+        AccountBalancesPage, AccountStorageKeysPage, AddressActivity, AddressTransactionCount,
+        BaseTokenPrice, BatchCommitmentInputs, BatchDiff, BatchL1CommitData,
+        BatchL2ToL1LogProofsPage, BatchTransactionsPage, BlockGasLimit, BlockId, BlockIdVariant,
+        BlockOrBatchNumber, BridgeAddresses, BridgehubContract, BytecodeDetails, ChainStats,
+        ConnectionPoolStats, ContractCreationCode, EncodedU256, ExtendedTransactionReceipt,
+        FeeModelParams, IntegerEncoding, L1BatchEvent, L1BatchFees, L1BatchProvingStatus,
+        L1BatchStage, L1BatchStats, L1ToL2GasLimitRecommendation, L2ToL1LogProof,
+        L2ToL1LogProofVerification, MiniblockId, NextL1BatchInfo, ProtocolVersion,
+        TimestampRounding, TokenLiquidityFilter, TokenSortOrder, TransactionConfirmationsNeeded,
+        TransactionDetails, TransactionReplay, WithdrawalParams,
+    },
+    explorer_api::{BlockDetails, BlockHeaderDetails, L1BatchDetails},
+    fee::{DetailedFee, FeeEstimate, FeeFormat},
+    tokens::TokenPrice,
     transaction_request::CallRequest,
     Address, Bytes, L1BatchNumber, MiniblockNumber, H256, U256, U64,
 };
-use zksync_web3_decl::error::Web3Error;
 use zksync_web3_decl::types::Token;
 
 // Local uses
@@ -24,14 +35,46 @@ use crate::{l1_gas_price::L1GasPriceProvider, web3::backend_jsonrpc::error::into
 #[rpc]
 pub trait ZksNamespaceT {
     #[rpc(name = "zks_estimateFee")]
-    fn estimate_fee(&self, req: CallRequest) -> BoxFuture<Result<Fee>>;
+    fn estimate_fee(
+        &self,
+        req: CallRequest,
+        deterministic: Option<bool>,
+        format: Option<FeeFormat>,
+    ) -> BoxFuture<Result<FeeEstimate>>;
+
+    #[rpc(name = "zks_estimateFeeDetailed")]
+    fn estimate_fee_detailed(&self, req: CallRequest) -> BoxFuture<Result<DetailedFee>>;
 
     #[rpc(name = "zks_estimateGasL1ToL2")]
     fn estimate_gas_l1_to_l2(&self, req: CallRequest) -> BoxFuture<Result<U256>>;
 
+    #[rpc(name = "zks_estimateGasL1ToL2Encoded")]
+    fn estimate_gas_l1_to_l2_encoded(
+        &self,
+        req: CallRequest,
+        encoding: Option<IntegerEncoding>,
+    ) -> BoxFuture<Result<EncodedU256>>;
+
+    #[rpc(name = "zks_estimateGasL1ToL2Detailed")]
+    fn estimate_gas_l1_to_l2_detailed(
+        &self,
+        req: CallRequest,
+        gas_per_pubdata: Option<U256>,
+        refund_recipient: Option<Address>,
+    ) -> BoxFuture<Result<U256>>;
+
+    #[rpc(name = "zks_getRecommendedL1ToL2GasLimit")]
+    fn get_recommended_l1_to_l2_gas_limit(
+        &self,
+        req: CallRequest,
+    ) -> BoxFuture<Result<L1ToL2GasLimitRecommendation>>;
+
     #[rpc(name = "zks_getMainContract")]
     fn get_main_contract(&self) -> BoxFuture<Result<Address>>;
 
+    #[rpc(name = "zks_getBridgehubContract")]
+    fn get_bridgehub_contract(&self) -> BoxFuture<Result<Option<BridgehubContract>>>;
+
     #[rpc(name = "zks_getTestnetPaymaster")]
     fn get_testnet_paymaster(&self) -> BoxFuture<Result<Option<Address>>>;
 
@@ -41,18 +84,75 @@ pub trait ZksNamespaceT {
     #[rpc(name = "zks_L1ChainId")]
     fn l1_chain_id(&self) -> BoxFuture<Result<U64>>;
 
+    #[rpc(name = "zks_getCurrentBlockGasLimit")]
+    fn get_current_block_gas_limit(&self) -> BoxFuture<Result<BlockGasLimit>>;
+
     #[rpc(name = "zks_getConfirmedTokens")]
-    fn get_confirmed_tokens(&self, from: u32, limit: u8) -> BoxFuture<Result<Vec<Token>>>;
+    fn get_confirmed_tokens(
+        &self,
+        from: u32,
+        limit: u8,
+        eth_first: Option<bool>,
+        verify_onchain: Option<bool>,
+        liquidity_filter: Option<TokenLiquidityFilter>,
+    ) -> BoxFuture<Result<Vec<Token>>>;
+
+    #[rpc(name = "zks_getConfirmedTokensIfModified")]
+    fn get_confirmed_tokens_if_modified(
+        &self,
+        if_modified_after: u64,
+        from: u32,
+        limit: u8,
+        eth_first: Option<bool>,
+        verify_onchain: Option<bool>,
+    ) -> BoxFuture<Result<Option<Vec<Token>>>>;
+
+    #[rpc(name = "zks_getConfirmedTokensFiltered")]
+    fn get_confirmed_tokens_filtered(
+        &self,
+        symbol: Option<String>,
+        sort: Option<TokenSortOrder>,
+        from: u32,
+        limit: u8,
+    ) -> BoxFuture<Result<Vec<Token>>>;
+
+    #[rpc(name = "zks_getTokenByL2Address")]
+    fn get_token_by_l2_address(&self, l2_address: Address) -> BoxFuture<Result<Option<Token>>>;
 
     #[rpc(name = "zks_getTokenPrice")]
     fn get_token_price(&self, token_address: Address) -> BoxFuture<Result<BigDecimal>>;
 
+    #[rpc(name = "zks_getTokenPriceAt")]
+    fn get_token_price_at(
+        &self,
+        token_address: Address,
+        at: BlockIdVariant,
+    ) -> BoxFuture<Result<TokenPrice>>;
+
+    #[rpc(name = "zks_getBaseTokenPrice")]
+    fn get_base_token_price(&self) -> BoxFuture<Result<Option<BaseTokenPrice>>>;
+
     #[rpc(name = "zks_getAllAccountBalances")]
     fn get_all_account_balances(
         &self,
         address: Address,
     ) -> BoxFuture<Result<HashMap<Address, U256>>>;
 
+    #[rpc(name = "zks_getAllAccountBalancesEncoded")]
+    fn get_all_account_balances_encoded(
+        &self,
+        address: Address,
+        encoding: Option<IntegerEncoding>,
+    ) -> BoxFuture<Result<HashMap<Address, EncodedU256>>>;
+
+    #[rpc(name = "zks_getAccountBalancesPaged")]
+    fn get_account_balances_paged(
+        &self,
+        address: Address,
+        after_token: Option<Address>,
+        limit: Option<u32>,
+    ) -> BoxFuture<Result<AccountBalancesPage>>;
+
     #[rpc(name = "zks_getL2ToL1MsgProof")]
     fn get_l2_to_l1_msg_proof(
         &self,
@@ -67,16 +167,67 @@ pub trait ZksNamespaceT {
         &self,
         tx_hash: H256,
         index: Option<usize>,
+        full_tree: Option<bool>,
+    ) -> BoxFuture<Result<Option<L2ToL1LogProof>>>;
+
+    #[rpc(name = "zks_getWithdrawalParams")]
+    fn get_withdrawal_params(
+        &self,
+        withdrawal_hash: H256,
+        index: Option<usize>,
+    ) -> BoxFuture<Result<Option<WithdrawalParams>>>;
+
+    #[rpc(name = "zks_getL2ToL1LogProofForBatch")]
+    fn get_l2_to_l1_log_proof_for_batch(
+        &self,
+        batch: L1BatchNumber,
+        index: usize,
     ) -> BoxFuture<Result<Option<L2ToL1LogProof>>>;
 
+    #[rpc(name = "zks_getBatchL2ToL1LogProofs")]
+    fn get_batch_l2_to_l1_log_proofs(
+        &self,
+        batch: L1BatchNumber,
+        cursor: Option<u32>,
+        limit: Option<u32>,
+    ) -> BoxFuture<Result<Option<BatchL2ToL1LogProofsPage>>>;
+
+    #[rpc(name = "zks_verifyL2ToL1LogProof")]
+    fn verify_l2_to_l1_log_proof(
+        &self,
+        proof: L2ToL1LogProof,
+        batch: L1BatchNumber,
+        leaf: H256,
+    ) -> BoxFuture<Result<Option<L2ToL1LogProofVerification>>>;
+
     #[rpc(name = "zks_L1BatchNumber")]
     fn get_l1_batch_number(&self) -> BoxFuture<Result<U64>>;
 
+    #[rpc(name = "zks_getNextBatchInfo")]
+    fn get_next_batch_info(&self) -> BoxFuture<Result<NextL1BatchInfo>>;
+
     #[rpc(name = "zks_getBlockDetails")]
-    fn get_block_details(
+    fn get_block_details(&self, block: MiniblockId) -> BoxFuture<Result<Option<BlockDetails>>>;
+
+    #[rpc(name = "zks_getBlockDetailsRange")]
+    fn get_block_details_range(
+        &self,
+        from: MiniblockNumber,
+        to: MiniblockNumber,
+    ) -> BoxFuture<Result<Vec<BlockDetails>>>;
+
+    #[rpc(name = "zks_getMiniblockHeader")]
+    fn get_miniblock_header(
         &self,
         block_number: MiniblockNumber,
-    ) -> BoxFuture<Result<Option<BlockDetails>>>;
+    ) -> BoxFuture<Result<Option<BlockHeaderDetails>>>;
+
+    #[rpc(name = "zks_getMiniblockByTimestamp")]
+    fn get_miniblock_by_timestamp(
+        &self,
+        timestamp: u64,
+        rounding: TimestampRounding,
+    ) -> BoxFuture<Result<Option<MiniblockNumber>>>;
 
     #[rpc(name = "zks_getL1BatchBlockRange")]
     fn get_miniblock_range(&self, batch: L1BatchNumber) -> BoxFuture<Result<Option<(U64, U64)>>>;
@@ -85,12 +236,26 @@ pub trait ZksNamespaceT {
     fn set_known_bytecode(&self, bytecode: Bytes) -> BoxFuture<Result<bool>>;
 
     #[rpc(name = "zks_getTransactionDetails")]
-    fn get_transaction_details(&self, hash: H256) -> BoxFuture<Result<Option<TransactionDetails>>>;
+    fn get_transaction_details(
+        &self,
+        hash: H256,
+        include_gas_estimate: Option<bool>,
+        include_raw: Option<bool>,
+    ) -> BoxFuture<Result<Option<TransactionDetails>>>;
+
+    #[rpc(name = "zks_getTransactionDetailsBatch")]
+    fn get_transaction_details_batch(
+        &self,
+        hashes: Vec<H256>,
+    ) -> BoxFuture<Result<Vec<(H256, Option<TransactionDetails>)>>>;
+
+    #[rpc(name = "zks_sendRawTransactionWithDetailedError")]
+    fn send_raw_transaction_with_detailed_error(&self, tx_bytes: Bytes) -> BoxFuture<Result<H256>>;
 
     #[rpc(name = "zks_getRawBlockTransactions")]
     fn get_raw_block_transactions(
         &self,
-        block_number: MiniblockNumber,
+        block: MiniblockId,
     ) -> BoxFuture<Result<Vec<zksync_types::Transaction>>>;
 
     #[rpc(name = "zks_getL1BatchDetails")]
@@ -99,17 +264,192 @@ pub trait ZksNamespaceT {
         batch: L1BatchNumber,
     ) -> BoxFuture<Result<Option<L1BatchDetails>>>;
 
+    #[rpc(name = "zks_getL1BatchStats")]
+    fn get_l1_batch_stats(&self, batch: L1BatchNumber) -> BoxFuture<Result<Option<L1BatchStats>>>;
+
+    #[rpc(name = "zks_getLatestL1BatchDetails")]
+    fn get_latest_l1_batch_details(
+        &self,
+        stage: Option<L1BatchStage>,
+    ) -> BoxFuture<Result<Option<L1BatchDetails>>>;
+
+    #[rpc(name = "zks_getBatchL1CommitData")]
+    fn get_batch_l1_commit_data(
+        &self,
+        batch: L1BatchNumber,
+    ) -> BoxFuture<Result<Option<BatchL1CommitData>>>;
+
+    #[rpc(name = "zks_getBatchCommitmentInputs")]
+    fn get_batch_commitment_inputs(
+        &self,
+        batch: L1BatchNumber,
+    ) -> BoxFuture<Result<Option<BatchCommitmentInputs>>>;
+
+    #[rpc(name = "zks_getL1BatchFees")]
+    fn get_l1_batch_fees(&self, batch: L1BatchNumber) -> BoxFuture<Result<Option<L1BatchFees>>>;
+
+    #[rpc(name = "zks_getEffectiveL1GasPriceForBatch")]
+    fn get_effective_l1_gas_price_for_batch(
+        &self,
+        batch: L1BatchNumber,
+    ) -> BoxFuture<Result<Option<U64>>>;
+
     #[rpc(name = "zks_getBytecodeByHash")]
     fn get_bytecode_by_hash(&self, hash: H256) -> BoxFuture<Result<Option<Vec<u8>>>>;
 
+    #[rpc(name = "zks_getBytecodeDetailsByHash")]
+    fn get_bytecode_details_by_hash(
+        &self,
+        hash: H256,
+    ) -> BoxFuture<Result<Option<BytecodeDetails>>>;
+
+    #[rpc(name = "zks_getContractCreationCode")]
+    fn get_contract_creation_code(
+        &self,
+        address: Address,
+    ) -> BoxFuture<Result<Option<ContractCreationCode>>>;
+
+    /// Deprecated: the `U64` return type cannot represent every possible gas price;
+    /// use `zks_getL1GasPriceWei` instead.
     #[rpc(name = "zks_getL1GasPrice")]
     fn get_l1_gas_price(&self) -> BoxFuture<Result<U64>>;
+
+    #[rpc(name = "zks_getL1GasPriceHistory")]
+    fn get_l1_gas_price_history(
+        &self,
+        from_miniblock: MiniblockNumber,
+        to_miniblock: MiniblockNumber,
+    ) -> BoxFuture<Result<Vec<(MiniblockNumber, U64)>>>;
+
+    #[rpc(name = "zks_getL1GasPriceWei")]
+    fn get_l1_gas_price_wei(&self) -> BoxFuture<Result<U256>>;
+
+    #[rpc(name = "zks_getFeeParams")]
+    fn get_fee_params(&self) -> BoxFuture<Result<FeeModelParams>>;
+
+    #[rpc(name = "zks_getNonceAt")]
+    fn get_nonce_at(
+        &self,
+        address: Address,
+        block_or_batch: BlockOrBatchNumber,
+    ) -> BoxFuture<Result<U256>>;
+
+    #[rpc(name = "zks_getTransactionConfirmationsNeeded")]
+    fn get_transaction_confirmations_needed(
+        &self,
+        hash: H256,
+    ) -> BoxFuture<Result<Option<TransactionConfirmationsNeeded>>>;
+
+    #[rpc(name = "zks_getBatchDiff")]
+    fn get_batch_diff(
+        &self,
+        from_batch: L1BatchNumber,
+        to_batch: L1BatchNumber,
+        after: Option<H256>,
+    ) -> BoxFuture<Result<BatchDiff>>;
+
+    #[rpc(name = "zks_getBatchProvingStatus")]
+    fn get_batch_proving_status(
+        &self,
+        batch: L1BatchNumber,
+    ) -> BoxFuture<Result<Option<L1BatchProvingStatus>>>;
+
+    #[rpc(name = "zks_getAddressActivity")]
+    fn get_address_activity(&self, address: Address) -> BoxFuture<Result<AddressActivity>>;
+
+    #[rpc(name = "zks_getAddressTransactionCount")]
+    fn get_address_transaction_count(
+        &self,
+        address: Address,
+    ) -> BoxFuture<Result<AddressTransactionCount>>;
+
+    #[rpc(name = "zks_getVerificationKeyHash")]
+    fn get_verification_key_hash(
+        &self,
+        batch: Option<L1BatchNumber>,
+    ) -> BoxFuture<Result<Option<H256>>>;
+
+    #[rpc(name = "zks_getProtocolVersion")]
+    fn get_protocol_version(
+        &self,
+        version_id: Option<u16>,
+    ) -> BoxFuture<Result<Option<ProtocolVersion>>>;
+
+    #[rpc(name = "zks_getTransactionsInBatch")]
+    fn get_transactions_in_batch(
+        &self,
+        batch: L1BatchNumber,
+        cursor: Option<u32>,
+        limit: Option<u32>,
+    ) -> BoxFuture<Result<BatchTransactionsPage>>;
+
+    #[rpc(name = "zks_getContractDeploymentBytecodeHash")]
+    fn get_contract_deployment_bytecode_hash(
+        &self,
+        address: Address,
+        block: Option<BlockId>,
+    ) -> BoxFuture<Result<Option<H256>>>;
+
+    #[rpc(name = "zks_getTransactionReceipt")]
+    fn get_transaction_receipt(
+        &self,
+        tx_hash: H256,
+    ) -> BoxFuture<Result<Option<ExtendedTransactionReceipt>>>;
+
+    #[rpc(name = "zks_getStorageAtBatch")]
+    fn get_storage_at_batch(
+        &self,
+        address: Address,
+        keys: Vec<H256>,
+        block: Option<MiniblockNumber>,
+    ) -> BoxFuture<Result<HashMap<H256, H256>>>;
+
+    #[rpc(name = "zks_getAccountStorageKeys")]
+    fn get_account_storage_keys(
+        &self,
+        address: Address,
+        block: Option<MiniblockNumber>,
+        cursor: Option<H256>,
+        limit: Option<u32>,
+    ) -> BoxFuture<Result<AccountStorageKeysPage>>;
+
+    #[rpc(name = "zks_replayTransaction")]
+    fn replay_transaction(&self, hash: H256) -> BoxFuture<Result<Option<TransactionReplay>>>;
+
+    #[rpc(name = "zks_getConnectionPoolStats")]
+    fn get_connection_pool_stats(&self) -> BoxFuture<Result<ConnectionPoolStats>>;
+
+    #[rpc(name = "zks_getChainStats")]
+    fn get_chain_stats(&self) -> BoxFuture<Result<ChainStats>>;
+
+    #[rpc(name = "zks_getRecentBatchEvents")]
+    fn get_recent_batch_events(&self, limit: u32) -> BoxFuture<Result<Vec<L1BatchEvent>>>;
 }
 
 impl<G: L1GasPriceProvider + Send + Sync + 'static> ZksNamespaceT for ZksNamespace<G> {
-    fn estimate_fee(&self, req: CallRequest) -> BoxFuture<Result<Fee>> {
+    fn estimate_fee(
+        &self,
+        req: CallRequest,
+        deterministic: Option<bool>,
+        format: Option<FeeFormat>,
+    ) -> BoxFuture<Result<FeeEstimate>> {
+        let self_ = self.clone();
+        Box::pin(async move {
+            self_
+                .estimate_fee_impl(req, deterministic, format)
+                .await
+                .map_err(into_jsrpc_error)
+        })
+    }
+
+    fn estimate_fee_detailed(&self, req: CallRequest) -> BoxFuture<Result<DetailedFee>> {
         let self_ = self.clone();
-        Box::pin(async move { self_.estimate_fee_impl(req).await.map_err(into_jsrpc_error) })
+        Box::pin(async move {
+            self_
+                .estimate_fee_detailed_impl(req)
+                .await
+                .map_err(into_jsrpc_error)
+        })
     }
 
     fn estimate_gas_l1_to_l2(&self, req: CallRequest) -> BoxFuture<Result<U256>> {
@@ -122,11 +462,58 @@ impl<G: L1GasPriceProvider + Send + Sync + 'static> ZksNamespaceT for ZksNamespa
         })
     }
 
+    fn estimate_gas_l1_to_l2_encoded(
+        &self,
+        req: CallRequest,
+        encoding: Option<IntegerEncoding>,
+    ) -> BoxFuture<Result<EncodedU256>> {
+        let self_ = self.clone();
+        Box::pin(async move {
+            self_
+                .estimate_l1_to_l2_gas_encoded_impl(req, encoding)
+                .await
+                .map_err(into_jsrpc_error)
+        })
+    }
+
+    fn estimate_gas_l1_to_l2_detailed(
+        &self,
+        req: CallRequest,
+        gas_per_pubdata: Option<U256>,
+        refund_recipient: Option<Address>,
+    ) -> BoxFuture<Result<U256>> {
+        let self_ = self.clone();
+        Box::pin(async move {
+            self_
+                .estimate_l1_to_l2_gas_detailed_impl(req, gas_per_pubdata, refund_recipient)
+                .await
+                .map_err(into_jsrpc_error)
+        })
+    }
+
+    fn get_recommended_l1_to_l2_gas_limit(
+        &self,
+        req: CallRequest,
+    ) -> BoxFuture<Result<L1ToL2GasLimitRecommendation>> {
+        let self_ = self.clone();
+        Box::pin(async move {
+            self_
+                .get_recommended_l1_to_l2_gas_limit_impl(req)
+                .await
+                .map_err(into_jsrpc_error)
+        })
+    }
+
     fn get_main_contract(&self) -> BoxFuture<Result<Address>> {
         let self_ = self.clone();
         Box::pin(async move { Ok(self_.get_main_contract_impl()) })
     }
 
+    fn get_bridgehub_contract(&self) -> BoxFuture<Result<Option<BridgehubContract>>> {
+        let self_ = self.clone();
+        Box::pin(async move { Ok(self_.get_bridgehub_contract_impl()) })
+    }
+
     fn get_miniblock_range(&self, batch: L1BatchNumber) -> BoxFuture<Result<Option<(U64, U64)>>> {
         let self_ = self.clone();
         Box::pin(async move {
@@ -152,11 +539,73 @@ impl<G: L1GasPriceProvider + Send + Sync + 'static> ZksNamespaceT for ZksNamespa
         Box::pin(async move { Ok(self_.l1_chain_id_impl()) })
     }
 
-    fn get_confirmed_tokens(&self, from: u32, limit: u8) -> BoxFuture<Result<Vec<Token>>> {
+    fn get_current_block_gas_limit(&self) -> BoxFuture<Result<BlockGasLimit>> {
+        let self_ = self.clone();
+        Box::pin(async move { Ok(self_.get_current_block_gas_limit_impl()) })
+    }
+
+    fn get_confirmed_tokens(
+        &self,
+        from: u32,
+        limit: u8,
+        eth_first: Option<bool>,
+        verify_onchain: Option<bool>,
+        liquidity_filter: Option<TokenLiquidityFilter>,
+    ) -> BoxFuture<Result<Vec<Token>>> {
+        let self_ = self.clone();
+        Box::pin(async move {
+            self_
+                .get_confirmed_tokens_impl(from, limit, eth_first, verify_onchain, liquidity_filter)
+                .await
+                .map_err(into_jsrpc_error)
+        })
+    }
+
+    fn get_confirmed_tokens_if_modified(
+        &self,
+        if_modified_after: u64,
+        from: u32,
+        limit: u8,
+        eth_first: Option<bool>,
+        verify_onchain: Option<bool>,
+    ) -> BoxFuture<Result<Option<Vec<Token>>>> {
+        let self_ = self.clone();
+        Box::pin(async move {
+            self_
+                .get_confirmed_tokens_if_modified_impl(
+                    if_modified_after,
+                    from,
+                    limit,
+                    eth_first,
+                    verify_onchain,
+                    liquidity_filter,
+                )
+                .await
+                .map_err(into_jsrpc_error)
+        })
+    }
+
+    fn get_confirmed_tokens_filtered(
+        &self,
+        symbol: Option<String>,
+        sort: Option<TokenSortOrder>,
+        from: u32,
+        limit: u8,
+    ) -> BoxFuture<Result<Vec<Token>>> {
+        let self_ = self.clone();
+        Box::pin(async move {
+            self_
+                .get_confirmed_tokens_filtered_impl(symbol, sort, from, limit)
+                .await
+                .map_err(into_jsrpc_error)
+        })
+    }
+
+    fn get_token_by_l2_address(&self, l2_address: Address) -> BoxFuture<Result<Option<Token>>> {
         let self_ = self.clone();
         Box::pin(async move {
             self_
-                .get_confirmed_tokens_impl(from, limit)
+                .get_token_by_l2_address_impl(l2_address)
                 .await
                 .map_err(into_jsrpc_error)
         })
@@ -172,6 +621,30 @@ impl<G: L1GasPriceProvider + Send + Sync + 'static> ZksNamespaceT for ZksNamespa
         })
     }
 
+    fn get_token_price_at(
+        &self,
+        token_address: Address,
+        at: BlockIdVariant,
+    ) -> BoxFuture<Result<TokenPrice>> {
+        let self_ = self.clone();
+        Box::pin(async move {
+            self_
+                .get_token_price_at_impl(token_address, at)
+                .await
+                .map_err(into_jsrpc_error)
+        })
+    }
+
+    fn get_base_token_price(&self) -> BoxFuture<Result<Option<BaseTokenPrice>>> {
+        let self_ = self.clone();
+        Box::pin(async move {
+            self_
+                .get_base_token_price_impl()
+                .await
+                .map_err(into_jsrpc_error)
+        })
+    }
+
     fn get_all_account_balances(
         &self,
         address: Address,
@@ -185,6 +658,35 @@ impl<G: L1GasPriceProvider + Send + Sync + 'static> ZksNamespaceT for ZksNamespa
         })
     }
 
+    fn get_all_account_balances_encoded(
+        &self,
+        address: Address,
+        encoding: Option<IntegerEncoding>,
+    ) -> BoxFuture<Result<HashMap<Address, EncodedU256>>> {
+        let self_ = self.clone();
+        Box::pin(async move {
+            self_
+                .get_all_account_balances_encoded_impl(address, encoding)
+                .await
+                .map_err(into_jsrpc_error)
+        })
+    }
+
+    fn get_account_balances_paged(
+        &self,
+        address: Address,
+        after_token: Option<Address>,
+        limit: Option<u32>,
+    ) -> BoxFuture<Result<AccountBalancesPage>> {
+        let self_ = self.clone();
+        Box::pin(async move {
+            self_
+                .get_account_balances_paged_impl(address, after_token, limit)
+                .await
+                .map_err(into_jsrpc_error)
+        })
+    }
+
     fn get_l2_to_l1_msg_proof(
         &self,
         block: MiniblockNumber,
@@ -205,94 +707,554 @@ impl<G: L1GasPriceProvider + Send + Sync + 'static> ZksNamespaceT for ZksNamespa
         &self,
         tx_hash: H256,
         index: Option<usize>,
+        full_tree: Option<bool>,
     ) -> BoxFuture<Result<Option<L2ToL1LogProof>>> {
         let self_ = self.clone();
         Box::pin(async move {
             self_
-                .get_l2_to_l1_log_proof_impl(tx_hash, index)
+                .get_l2_to_l1_log_proof_impl(tx_hash, index, full_tree)
                 .await
                 .map_err(into_jsrpc_error)
         })
     }
 
-    fn get_l1_batch_number(&self) -> BoxFuture<Result<U64>> {
+    fn get_withdrawal_params(
+        &self,
+        withdrawal_hash: H256,
+        index: Option<usize>,
+    ) -> BoxFuture<Result<Option<WithdrawalParams>>> {
         let self_ = self.clone();
         Box::pin(async move {
             self_
-                .get_l1_batch_number_impl()
+                .get_withdrawal_params_impl(withdrawal_hash, index)
                 .await
                 .map_err(into_jsrpc_error)
         })
     }
 
-    fn get_block_details(
+    fn get_l2_to_l1_log_proof_for_batch(
         &self,
-        block_number: MiniblockNumber,
-    ) -> BoxFuture<Result<Option<BlockDetails>>> {
+        batch: L1BatchNumber,
+        index: usize,
+    ) -> BoxFuture<Result<Option<L2ToL1LogProof>>> {
         let self_ = self.clone();
         Box::pin(async move {
             self_
-                .get_block_details_impl(block_number)
+                .get_l2_to_l1_log_proof_for_batch_impl(batch, index)
                 .await
                 .map_err(into_jsrpc_error)
         })
     }
 
-    fn get_transaction_details(&self, hash: H256) -> BoxFuture<Result<Option<TransactionDetails>>> {
+    fn get_batch_l2_to_l1_log_proofs(
+        &self,
+        batch: L1BatchNumber,
+        cursor: Option<u32>,
+        limit: Option<u32>,
+    ) -> BoxFuture<Result<Option<BatchL2ToL1LogProofsPage>>> {
         let self_ = self.clone();
         Box::pin(async move {
             self_
-                .get_transaction_details_impl(hash)
+                .get_batch_l2_to_l1_log_proofs_impl(batch, cursor, limit)
                 .await
                 .map_err(into_jsrpc_error)
         })
     }
 
-    fn set_known_bytecode(&self, _bytecode: Bytes) -> BoxFuture<Result<bool>> {
-        #[cfg(feature = "openzeppelin_tests")]
+    fn verify_l2_to_l1_log_proof(
+        &self,
+        proof: L2ToL1LogProof,
+        batch: L1BatchNumber,
+        leaf: H256,
+    ) -> BoxFuture<Result<Option<L2ToL1LogProofVerification>>> {
         let self_ = self.clone();
         Box::pin(async move {
-            #[cfg(feature = "openzeppelin_tests")]
-            return Ok(self_.set_known_bytecode_impl(_bytecode));
-
-            #[cfg(not(feature = "openzeppelin_tests"))]
-            Err(into_jsrpc_error(Web3Error::NotImplemented))
+            self_
+                .verify_l2_to_l1_log_proof_impl(proof, batch, leaf)
+                .await
+                .map_err(into_jsrpc_error)
         })
     }
 
-    fn get_raw_block_transactions(
-        &self,
-        block_number: MiniblockNumber,
-    ) -> BoxFuture<Result<Vec<zksync_types::Transaction>>> {
+    fn get_l1_batch_number(&self) -> BoxFuture<Result<U64>> {
         let self_ = self.clone();
         Box::pin(async move {
             self_
-                .get_raw_block_transactions_impl(block_number)
+                .get_l1_batch_number_impl()
                 .await
                 .map_err(into_jsrpc_error)
         })
     }
 
-    fn get_l1_batch_details(
-        &self,
-        batch: L1BatchNumber,
-    ) -> BoxFuture<Result<Option<L1BatchDetails>>> {
+    fn get_next_batch_info(&self) -> BoxFuture<Result<NextL1BatchInfo>> {
         let self_ = self.clone();
         Box::pin(async move {
             self_
-                .get_l1_batch_details_impl(batch)
+                .get_next_batch_info_impl()
                 .await
                 .map_err(into_jsrpc_error)
         })
     }
 
-    fn get_bytecode_by_hash(&self, hash: H256) -> BoxFuture<Result<Option<Vec<u8>>>> {
+    fn get_block_details(&self, block: MiniblockId) -> BoxFuture<Result<Option<BlockDetails>>> {
         let self_ = self.clone();
-        Box::pin(async move { Ok(self_.get_bytecode_by_hash_impl(hash).await) })
+        Box::pin(async move {
+            self_
+                .get_block_details_impl(block)
+                .await
+                .map_err(into_jsrpc_error)
+        })
     }
 
-    fn get_l1_gas_price(&self) -> BoxFuture<Result<U64>> {
+    fn get_block_details_range(
+        &self,
+        from: MiniblockNumber,
+        to: MiniblockNumber,
+    ) -> BoxFuture<Result<Vec<BlockDetails>>> {
         let self_ = self.clone();
-        Box::pin(async move { Ok(self_.get_l1_gas_price_impl()) })
+        Box::pin(async move {
+            self_
+                .get_block_details_range_impl(from, to)
+                .await
+                .map_err(into_jsrpc_error)
+        })
+    }
+
+    fn get_miniblock_header(
+        &self,
+        block_number: MiniblockNumber,
+    ) -> BoxFuture<Result<Option<BlockHeaderDetails>>> {
+        let self_ = self.clone();
+        Box::pin(async move {
+            self_
+                .get_miniblock_header_impl(block_number)
+                .await
+                .map_err(into_jsrpc_error)
+        })
+    }
+
+    fn get_miniblock_by_timestamp(
+        &self,
+        timestamp: u64,
+        rounding: TimestampRounding,
+    ) -> BoxFuture<Result<Option<MiniblockNumber>>> {
+        let self_ = self.clone();
+        Box::pin(async move {
+            self_
+                .get_miniblock_by_timestamp_impl(timestamp, rounding)
+                .await
+                .map_err(into_jsrpc_error)
+        })
+    }
+
+    fn get_transaction_details(
+        &self,
+        hash: H256,
+        include_gas_estimate: Option<bool>,
+        include_raw: Option<bool>,
+    ) -> BoxFuture<Result<Option<TransactionDetails>>> {
+        let self_ = self.clone();
+        Box::pin(async move {
+            self_
+                .get_transaction_details_impl(hash, include_gas_estimate, include_raw)
+                .await
+                .map_err(into_jsrpc_error)
+        })
+    }
+
+    fn get_transaction_details_batch(
+        &self,
+        hashes: Vec<H256>,
+    ) -> BoxFuture<Result<Vec<(H256, Option<TransactionDetails>)>>> {
+        let self_ = self.clone();
+        Box::pin(async move {
+            self_
+                .get_transaction_details_batch_impl(hashes)
+                .await
+                .map_err(into_jsrpc_error)
+        })
+    }
+
+    fn send_raw_transaction_with_detailed_error(&self, tx_bytes: Bytes) -> BoxFuture<Result<H256>> {
+        let self_ = self.clone();
+        Box::pin(async move {
+            self_
+                .send_raw_transaction_with_detailed_error_impl(tx_bytes)
+                .await
+                .map_err(into_jsrpc_error)
+        })
+    }
+
+    fn set_known_bytecode(&self, bytecode: Bytes) -> BoxFuture<Result<bool>> {
+        let self_ = self.clone();
+        Box::pin(async move {
+            self_
+                .set_known_bytecode_impl(bytecode)
+                .map_err(into_jsrpc_error)
+        })
+    }
+
+    fn get_raw_block_transactions(
+        &self,
+        block: MiniblockId,
+    ) -> BoxFuture<Result<Vec<zksync_types::Transaction>>> {
+        let self_ = self.clone();
+        Box::pin(async move {
+            self_
+                .get_raw_block_transactions_impl(block)
+                .await
+                .map_err(into_jsrpc_error)
+        })
+    }
+
+    fn get_l1_batch_details(
+        &self,
+        batch: L1BatchNumber,
+    ) -> BoxFuture<Result<Option<L1BatchDetails>>> {
+        let self_ = self.clone();
+        Box::pin(async move {
+            self_
+                .get_l1_batch_details_impl(batch)
+                .await
+                .map_err(into_jsrpc_error)
+        })
+    }
+
+    fn get_l1_batch_stats(&self, batch: L1BatchNumber) -> BoxFuture<Result<Option<L1BatchStats>>> {
+        let self_ = self.clone();
+        Box::pin(async move {
+            self_
+                .get_l1_batch_stats_impl(batch)
+                .await
+                .map_err(into_jsrpc_error)
+        })
+    }
+
+    fn get_latest_l1_batch_details(
+        &self,
+        stage: Option<L1BatchStage>,
+    ) -> BoxFuture<Result<Option<L1BatchDetails>>> {
+        let self_ = self.clone();
+        Box::pin(async move {
+            self_
+                .get_latest_l1_batch_details_impl(stage)
+                .await
+                .map_err(into_jsrpc_error)
+        })
+    }
+
+    fn get_batch_l1_commit_data(
+        &self,
+        batch: L1BatchNumber,
+    ) -> BoxFuture<Result<Option<BatchL1CommitData>>> {
+        let self_ = self.clone();
+        Box::pin(async move {
+            self_
+                .get_batch_l1_commit_data_impl(batch)
+                .await
+                .map_err(into_jsrpc_error)
+        })
+    }
+
+    fn get_batch_commitment_inputs(
+        &self,
+        batch: L1BatchNumber,
+    ) -> BoxFuture<Result<Option<BatchCommitmentInputs>>> {
+        let self_ = self.clone();
+        Box::pin(async move {
+            self_
+                .get_batch_commitment_inputs_impl(batch)
+                .await
+                .map_err(into_jsrpc_error)
+        })
+    }
+
+    fn get_l1_batch_fees(&self, batch: L1BatchNumber) -> BoxFuture<Result<Option<L1BatchFees>>> {
+        let self_ = self.clone();
+        Box::pin(async move {
+            self_
+                .get_l1_batch_fees_impl(batch)
+                .await
+                .map_err(into_jsrpc_error)
+        })
+    }
+
+    fn get_effective_l1_gas_price_for_batch(
+        &self,
+        batch: L1BatchNumber,
+    ) -> BoxFuture<Result<Option<U64>>> {
+        let self_ = self.clone();
+        Box::pin(async move { Ok(self_.get_effective_l1_gas_price_for_batch_impl(batch).await) })
+    }
+
+    fn get_bytecode_by_hash(&self, hash: H256) -> BoxFuture<Result<Option<Vec<u8>>>> {
+        let self_ = self.clone();
+        Box::pin(async move { Ok(self_.get_bytecode_by_hash_impl(hash).await) })
+    }
+
+    fn get_bytecode_details_by_hash(
+        &self,
+        hash: H256,
+    ) -> BoxFuture<Result<Option<BytecodeDetails>>> {
+        let self_ = self.clone();
+        Box::pin(async move {
+            self_
+                .get_bytecode_details_by_hash_impl(hash)
+                .await
+                .map_err(into_jsrpc_error)
+        })
+    }
+
+    fn get_contract_creation_code(
+        &self,
+        address: Address,
+    ) -> BoxFuture<Result<Option<ContractCreationCode>>> {
+        let self_ = self.clone();
+        Box::pin(async move {
+            self_
+                .get_contract_creation_code_impl(address)
+                .await
+                .map_err(into_jsrpc_error)
+        })
+    }
+
+    fn get_l1_gas_price(&self) -> BoxFuture<Result<U64>> {
+        let self_ = self.clone();
+        Box::pin(async move { Ok(self_.get_l1_gas_price_impl()) })
+    }
+
+    fn get_l1_gas_price_history(
+        &self,
+        from_miniblock: MiniblockNumber,
+        to_miniblock: MiniblockNumber,
+    ) -> BoxFuture<Result<Vec<(MiniblockNumber, U64)>>> {
+        let self_ = self.clone();
+        Box::pin(async move {
+            self_
+                .get_l1_gas_price_history_impl(from_miniblock, to_miniblock)
+                .await
+                .map_err(into_jsrpc_error)
+        })
+    }
+
+    fn get_l1_gas_price_wei(&self) -> BoxFuture<Result<U256>> {
+        let self_ = self.clone();
+        Box::pin(async move { Ok(self_.get_l1_gas_price_wei_impl()) })
+    }
+
+    fn get_fee_params(&self) -> BoxFuture<Result<FeeModelParams>> {
+        let self_ = self.clone();
+        Box::pin(async move { Ok(self_.get_fee_params_impl()) })
+    }
+
+    fn get_nonce_at(
+        &self,
+        address: Address,
+        block_or_batch: BlockOrBatchNumber,
+    ) -> BoxFuture<Result<U256>> {
+        let self_ = self.clone();
+        Box::pin(async move {
+            self_
+                .get_nonce_at_impl(address, block_or_batch)
+                .await
+                .map_err(into_jsrpc_error)
+        })
+    }
+
+    fn get_transaction_confirmations_needed(
+        &self,
+        hash: H256,
+    ) -> BoxFuture<Result<Option<TransactionConfirmationsNeeded>>> {
+        let self_ = self.clone();
+        Box::pin(async move {
+            self_
+                .get_transaction_confirmations_needed_impl(hash)
+                .await
+                .map_err(into_jsrpc_error)
+        })
+    }
+
+    fn get_batch_diff(
+        &self,
+        from_batch: L1BatchNumber,
+        to_batch: L1BatchNumber,
+        after: Option<H256>,
+    ) -> BoxFuture<Result<BatchDiff>> {
+        let self_ = self.clone();
+        Box::pin(async move {
+            self_
+                .get_batch_diff_impl(from_batch, to_batch, after)
+                .await
+                .map_err(into_jsrpc_error)
+        })
+    }
+
+    fn get_batch_proving_status(
+        &self,
+        batch: L1BatchNumber,
+    ) -> BoxFuture<Result<Option<L1BatchProvingStatus>>> {
+        let self_ = self.clone();
+        Box::pin(async move {
+            self_
+                .get_batch_proving_status_impl(batch)
+                .await
+                .map_err(into_jsrpc_error)
+        })
+    }
+
+    fn get_address_activity(&self, address: Address) -> BoxFuture<Result<AddressActivity>> {
+        let self_ = self.clone();
+        Box::pin(async move {
+            self_
+                .get_address_activity_impl(address)
+                .await
+                .map_err(into_jsrpc_error)
+        })
+    }
+
+    fn get_address_transaction_count(
+        &self,
+        address: Address,
+    ) -> BoxFuture<Result<AddressTransactionCount>> {
+        let self_ = self.clone();
+        Box::pin(async move {
+            self_
+                .get_address_transaction_count_impl(address)
+                .await
+                .map_err(into_jsrpc_error)
+        })
+    }
+
+    fn get_verification_key_hash(
+        &self,
+        batch: Option<L1BatchNumber>,
+    ) -> BoxFuture<Result<Option<H256>>> {
+        let self_ = self.clone();
+        Box::pin(async move {
+            self_
+                .get_verification_key_hash_impl(batch)
+                .await
+                .map_err(into_jsrpc_error)
+        })
+    }
+
+    fn get_protocol_version(
+        &self,
+        version_id: Option<u16>,
+    ) -> BoxFuture<Result<Option<ProtocolVersion>>> {
+        let self_ = self.clone();
+        Box::pin(async move {
+            self_
+                .get_protocol_version_impl(version_id)
+                .await
+                .map_err(into_jsrpc_error)
+        })
+    }
+
+    fn get_transactions_in_batch(
+        &self,
+        batch: L1BatchNumber,
+        cursor: Option<u32>,
+        limit: Option<u32>,
+    ) -> BoxFuture<Result<BatchTransactionsPage>> {
+        let self_ = self.clone();
+        Box::pin(async move {
+            self_
+                .get_transactions_in_batch_impl(batch, cursor, limit)
+                .await
+                .map_err(into_jsrpc_error)
+        })
+    }
+
+    fn get_contract_deployment_bytecode_hash(
+        &self,
+        address: Address,
+        block: Option<BlockId>,
+    ) -> BoxFuture<Result<Option<H256>>> {
+        let self_ = self.clone();
+        Box::pin(async move {
+            self_
+                .get_contract_deployment_bytecode_hash_impl(address, block)
+                .await
+                .map_err(into_jsrpc_error)
+        })
+    }
+
+    fn get_transaction_receipt(
+        &self,
+        tx_hash: H256,
+    ) -> BoxFuture<Result<Option<ExtendedTransactionReceipt>>> {
+        let self_ = self.clone();
+        Box::pin(async move {
+            self_
+                .get_transaction_receipt_impl(tx_hash)
+                .await
+                .map_err(into_jsrpc_error)
+        })
+    }
+
+    fn get_storage_at_batch(
+        &self,
+        address: Address,
+        keys: Vec<H256>,
+        block: Option<MiniblockNumber>,
+    ) -> BoxFuture<Result<HashMap<H256, H256>>> {
+        let self_ = self.clone();
+        Box::pin(async move {
+            self_
+                .get_storage_at_batch_impl(address, keys, block)
+                .await
+                .map_err(into_jsrpc_error)
+        })
+    }
+
+    fn get_account_storage_keys(
+        &self,
+        address: Address,
+        block: Option<MiniblockNumber>,
+        cursor: Option<H256>,
+        limit: Option<u32>,
+    ) -> BoxFuture<Result<AccountStorageKeysPage>> {
+        let self_ = self.clone();
+        Box::pin(async move {
+            self_
+                .get_account_storage_keys_impl(address, block, cursor, limit)
+                .await
+                .map_err(into_jsrpc_error)
+        })
+    }
+
+    fn replay_transaction(&self, hash: H256) -> BoxFuture<Result<Option<TransactionReplay>>> {
+        let self_ = self.clone();
+        Box::pin(async move {
+            self_
+                .replay_transaction_impl(hash)
+                .await
+                .map_err(into_jsrpc_error)
+        })
+    }
+
+    fn get_connection_pool_stats(&self) -> BoxFuture<Result<ConnectionPoolStats>> {
+        let self_ = self.clone();
+        Box::pin(async move {
+            self_
+                .get_connection_pool_stats_impl()
+                .map_err(into_jsrpc_error)
+        })
+    }
+
+    fn get_chain_stats(&self) -> BoxFuture<Result<ChainStats>> {
+        let self_ = self.clone();
+        Box::pin(async move { self_.get_chain_stats_impl().await.map_err(into_jsrpc_error) })
+    }
+
+    fn get_recent_batch_events(&self, limit: u32) -> BoxFuture<Result<Vec<L1BatchEvent>>> {
+        let self_ = self.clone();
+        Box::pin(async move {
+            self_
+                .get_recent_batch_events_impl(limit)
+                .await
+                .map_err(into_jsrpc_error)
+        })
     }
 }
@@ -5,6 +5,7 @@ use jsonrpc_derive::rpc;
 use jsonrpc_pubsub::typed;
 use jsonrpc_pubsub::{Session, SubscriptionId};
 
+use zksync_types::H256;
 use zksync_web3_decl::types::PubSubResult;
 
 use super::super::namespaces::EthSubscribe;
@@ -32,6 +33,43 @@ pub trait Web3PubSub {
         meta: Option<Self::Metadata>,
         subscription: SubscriptionId,
     ) -> BoxFuture<Result<bool>>;
+
+    #[pubsub(
+        subscription = "zks_transactionStage",
+        subscribe,
+        name = "zks_subscribeTransaction"
+    )]
+    fn subscribe_transaction(
+        &self,
+        meta: Self::Metadata,
+        subscriber: typed::Subscriber<PubSubResult>,
+        hash: H256,
+    );
+
+    #[pubsub(
+        subscription = "zks_transactionStage",
+        unsubscribe,
+        name = "zks_unsubscribeTransaction"
+    )]
+    fn unsubscribe_transaction(
+        &self,
+        meta: Option<Self::Metadata>,
+        subscription: SubscriptionId,
+    ) -> BoxFuture<Result<bool>>;
+
+    #[pubsub(subscription = "zks_l1Batch", subscribe, name = "zks_subscribeL1Batch")]
+    fn subscribe_l1_batch(&self, meta: Self::Metadata, subscriber: typed::Subscriber<PubSubResult>);
+
+    #[pubsub(
+        subscription = "zks_l1Batch",
+        unsubscribe,
+        name = "zks_unsubscribeL1Batch"
+    )]
+    fn unsubscribe_l1_batch(
+        &self,
+        meta: Option<Self::Metadata>,
+        subscription: SubscriptionId,
+    ) -> BoxFuture<Result<bool>>;
 }
 
 impl Web3PubSub for EthSubscribe {
@@ -58,4 +96,43 @@ impl Web3PubSub for EthSubscribe {
         let self_ = self.clone();
         Box::pin(async move { self_.unsub(id).await })
     }
+
+    fn subscribe_transaction(
+        &self,
+        _meta: Self::Metadata,
+        subscriber: typed::Subscriber<PubSubResult>,
+        hash: H256,
+    ) {
+        let self_ = self.clone();
+        self.runtime_handle
+            .spawn(async move { self_.sub_transaction(subscriber, hash).await });
+    }
+
+    fn unsubscribe_transaction(
+        &self,
+        _meta: Option<Self::Metadata>,
+        id: SubscriptionId,
+    ) -> BoxFuture<Result<bool>> {
+        let self_ = self.clone();
+        Box::pin(async move { self_.unsub(id).await })
+    }
+
+    fn subscribe_l1_batch(
+        &self,
+        _meta: Self::Metadata,
+        subscriber: typed::Subscriber<PubSubResult>,
+    ) {
+        let self_ = self.clone();
+        self.runtime_handle
+            .spawn(async move { self_.sub_l1_batch(subscriber).await });
+    }
+
+    fn unsubscribe_l1_batch(
+        &self,
+        _meta: Option<Self::Metadata>,
+        id: SubscriptionId,
+    ) -> BoxFuture<Result<bool>> {
+        let self_ = self.clone();
+        Box::pin(async move { self_.unsub(id).await })
+    }
 }
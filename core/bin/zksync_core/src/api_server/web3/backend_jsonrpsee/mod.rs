@@ -24,8 +24,19 @@ pub fn into_jsrpc_error(err: Web3Error) -> ErrorObjectOwned {
             | Web3Error::FilterNotFound
             | Web3Error::InvalidFeeParams(_)
             | Web3Error::InvalidFilterBlockHash
-            | Web3Error::LogsLimitExceeded(_, _, _) => ErrorCode::InvalidParams.code(),
-            Web3Error::SubmitTransactionError(_, _) | Web3Error::SerializationError(_) => 3,
+            | Web3Error::LogsLimitExceeded(_, _, _)
+            | Web3Error::RangeTooLarge { .. }
+            | Web3Error::InvalidRange { .. }
+            | Web3Error::PubdataLimitExceeded { .. }
+            | Web3Error::TooManyL2ToL1Logs(_, _)
+            | Web3Error::UnsupportedProofVersion(_)
+            | Web3Error::UnknownToken
+            | Web3Error::NoTokenPrice
+            | Web3Error::L1BatchNotProven(_)
+            | Web3Error::InvalidBytecode(_) => ErrorCode::InvalidParams.code(),
+            Web3Error::SubmitTransactionError(_, _)
+            | Web3Error::SerializationError(_)
+            | Web3Error::TransactionRejected(_) => 3,
             Web3Error::PubSubTimeout => 4,
             Web3Error::RequestTimeout => 5,
         },
@@ -34,7 +45,12 @@ pub fn into_jsrpc_error(err: Web3Error) -> ErrorObjectOwned {
             _ => err.to_string(),
         },
         match err {
-            Web3Error::SubmitTransactionError(_, data) => Some(format!("0x{}", hex::encode(data))),
+            Web3Error::SubmitTransactionError(_, data) => {
+                Some(format!("0x{}", hex::encode(data)).into())
+            }
+            Web3Error::TransactionRejected(rejection) => Some(
+                serde_json::to_value(rejection).expect("failed to serialize transaction rejection"),
+            ),
             _ => None,
         },
     )
@@ -3,11 +3,23 @@ use bigdecimal::BigDecimal;
 use std::collections::HashMap;
 
 use zksync_types::{
-    api::{BridgeAddresses, L2ToL1LogProof, TransactionDetails, U64},
-    explorer_api::{BlockDetails, L1BatchDetails},
-    fee::Fee,
+    api::{
+        AccountBalancesPage, AccountStorageKeysPage, AddressActivity, AddressTransactionCount,
+        BaseTokenPrice, BatchCommitmentInputs, BatchDiff, BatchL1CommitData,
+        BatchL2ToL1LogProofsPage, BatchTransactionsPage, BlockGasLimit, BlockId, BlockIdVariant,
+        BlockOrBatchNumber, BridgeAddresses, BridgehubContract, BytecodeDetails, ChainStats,
+        ConnectionPoolStats, ContractCreationCode, EncodedU256, ExtendedTransactionReceipt,
+        FeeModelParams, IntegerEncoding, L1BatchEvent, L1BatchFees, L1BatchProvingStatus,
+        L1BatchStage, L1BatchStats, L1ToL2GasLimitRecommendation, L2ToL1LogProof,
+        L2ToL1LogProofVerification, MiniblockId, NextL1BatchInfo, ProtocolVersion,
+        TimestampRounding, TokenLiquidityFilter, TokenSortOrder, TransactionConfirmationsNeeded,
+        TransactionDetails, TransactionReplay, WithdrawalParams, U64,
+    },
+    explorer_api::{BlockDetails, BlockHeaderDetails, L1BatchDetails},
+    fee::{DetailedFee, FeeEstimate, FeeFormat},
+    tokens::TokenPrice,
     transaction_request::CallRequest,
-    Address, L1BatchNumber, MiniblockNumber, H256, U256,
+    Address, Bytes, L1BatchNumber, MiniblockNumber, H256, U256,
 };
 use zksync_web3_decl::{
     jsonrpsee::core::{async_trait, RpcResult},
@@ -22,8 +34,21 @@ use crate::{
 
 #[async_trait]
 impl<G: L1GasPriceProvider + Send + Sync + 'static> ZksNamespaceServer for ZksNamespace<G> {
-    async fn estimate_fee(&self, req: CallRequest) -> RpcResult<Fee> {
-        self.estimate_fee_impl(req).await.map_err(into_jsrpc_error)
+    async fn estimate_fee(
+        &self,
+        req: CallRequest,
+        deterministic: Option<bool>,
+        format: Option<FeeFormat>,
+    ) -> RpcResult<FeeEstimate> {
+        self.estimate_fee_impl(req, deterministic, format)
+            .await
+            .map_err(into_jsrpc_error)
+    }
+
+    async fn estimate_fee_detailed(&self, req: CallRequest) -> RpcResult<DetailedFee> {
+        self.estimate_fee_detailed_impl(req)
+            .await
+            .map_err(into_jsrpc_error)
     }
 
     async fn estimate_gas_l1_to_l2(&self, req: CallRequest) -> RpcResult<U256> {
@@ -32,10 +57,44 @@ impl<G: L1GasPriceProvider + Send + Sync + 'static> ZksNamespaceServer for ZksNa
             .map_err(into_jsrpc_error)
     }
 
+    async fn estimate_gas_l1_to_l2_encoded(
+        &self,
+        req: CallRequest,
+        encoding: Option<IntegerEncoding>,
+    ) -> RpcResult<EncodedU256> {
+        self.estimate_l1_to_l2_gas_encoded_impl(req, encoding)
+            .await
+            .map_err(into_jsrpc_error)
+    }
+
+    async fn estimate_gas_l1_to_l2_detailed(
+        &self,
+        req: CallRequest,
+        gas_per_pubdata: Option<U256>,
+        refund_recipient: Option<Address>,
+    ) -> RpcResult<U256> {
+        self.estimate_l1_to_l2_gas_detailed_impl(req, gas_per_pubdata, refund_recipient)
+            .await
+            .map_err(into_jsrpc_error)
+    }
+
+    async fn get_recommended_l1_to_l2_gas_limit(
+        &self,
+        req: CallRequest,
+    ) -> RpcResult<L1ToL2GasLimitRecommendation> {
+        self.get_recommended_l1_to_l2_gas_limit_impl(req)
+            .await
+            .map_err(into_jsrpc_error)
+    }
+
     async fn get_main_contract(&self) -> RpcResult<Address> {
         Ok(self.get_main_contract_impl())
     }
 
+    async fn get_bridgehub_contract(&self) -> RpcResult<Option<BridgehubContract>> {
+        Ok(self.get_bridgehub_contract_impl())
+    }
+
     async fn get_testnet_paymaster(&self) -> RpcResult<Option<Address>> {
         Ok(self.get_testnet_paymaster_impl())
     }
@@ -48,8 +107,55 @@ impl<G: L1GasPriceProvider + Send + Sync + 'static> ZksNamespaceServer for ZksNa
         Ok(self.l1_chain_id_impl())
     }
 
-    async fn get_confirmed_tokens(&self, from: u32, limit: u8) -> RpcResult<Vec<Token>> {
-        self.get_confirmed_tokens_impl(from, limit)
+    async fn get_current_block_gas_limit(&self) -> RpcResult<BlockGasLimit> {
+        Ok(self.get_current_block_gas_limit_impl())
+    }
+
+    async fn get_confirmed_tokens(
+        &self,
+        from: u32,
+        limit: u8,
+        eth_first: Option<bool>,
+        verify_onchain: Option<bool>,
+    ) -> RpcResult<Vec<Token>> {
+        self.get_confirmed_tokens_impl(from, limit, eth_first, verify_onchain, liquidity_filter)
+            .await
+            .map_err(into_jsrpc_error)
+    }
+
+    async fn get_confirmed_tokens_if_modified(
+        &self,
+        if_modified_after: u64,
+        from: u32,
+        limit: u8,
+        eth_first: Option<bool>,
+        verify_onchain: Option<bool>,
+    ) -> RpcResult<Option<Vec<Token>>> {
+        self.get_confirmed_tokens_if_modified_impl(
+            if_modified_after,
+            from,
+            limit,
+            eth_first,
+            verify_onchain,
+        )
+        .await
+        .map_err(into_jsrpc_error)
+    }
+
+    async fn get_confirmed_tokens_filtered(
+        &self,
+        symbol: Option<String>,
+        sort: Option<TokenSortOrder>,
+        from: u32,
+        limit: u8,
+    ) -> RpcResult<Vec<Token>> {
+        self.get_confirmed_tokens_filtered_impl(symbol, sort, from, limit)
+            .await
+            .map_err(into_jsrpc_error)
+    }
+
+    async fn get_token_by_l2_address(&self, l2_address: Address) -> RpcResult<Option<Token>> {
+        self.get_token_by_l2_address_impl(l2_address)
             .await
             .map_err(into_jsrpc_error)
     }
@@ -60,6 +166,22 @@ impl<G: L1GasPriceProvider + Send + Sync + 'static> ZksNamespaceServer for ZksNa
             .map_err(into_jsrpc_error)
     }
 
+    async fn get_token_price_at(
+        &self,
+        token_address: Address,
+        at: BlockIdVariant,
+    ) -> RpcResult<TokenPrice> {
+        self.get_token_price_at_impl(token_address, at)
+            .await
+            .map_err(into_jsrpc_error)
+    }
+
+    async fn get_base_token_price(&self) -> RpcResult<Option<BaseTokenPrice>> {
+        self.get_base_token_price_impl()
+            .await
+            .map_err(into_jsrpc_error)
+    }
+
     async fn get_all_account_balances(
         &self,
         address: Address,
@@ -69,6 +191,27 @@ impl<G: L1GasPriceProvider + Send + Sync + 'static> ZksNamespaceServer for ZksNa
             .map_err(into_jsrpc_error)
     }
 
+    async fn get_all_account_balances_encoded(
+        &self,
+        address: Address,
+        encoding: Option<IntegerEncoding>,
+    ) -> RpcResult<HashMap<Address, EncodedU256>> {
+        self.get_all_account_balances_encoded_impl(address, encoding)
+            .await
+            .map_err(into_jsrpc_error)
+    }
+
+    async fn get_account_balances_paged(
+        &self,
+        address: Address,
+        after_token: Option<Address>,
+        limit: Option<u32>,
+    ) -> RpcResult<AccountBalancesPage> {
+        self.get_account_balances_paged_impl(address, after_token, limit)
+            .await
+            .map_err(into_jsrpc_error)
+    }
+
     async fn get_l2_to_l1_msg_proof(
         &self,
         block: MiniblockNumber,
@@ -85,8 +228,51 @@ impl<G: L1GasPriceProvider + Send + Sync + 'static> ZksNamespaceServer for ZksNa
         &self,
         tx_hash: H256,
         index: Option<usize>,
+        full_tree: Option<bool>,
+    ) -> RpcResult<Option<L2ToL1LogProof>> {
+        self.get_l2_to_l1_log_proof_impl(tx_hash, index, full_tree)
+            .await
+            .map_err(into_jsrpc_error)
+    }
+
+    async fn get_withdrawal_params(
+        &self,
+        withdrawal_hash: H256,
+        index: Option<usize>,
+    ) -> RpcResult<Option<WithdrawalParams>> {
+        self.get_withdrawal_params_impl(withdrawal_hash, index)
+            .await
+            .map_err(into_jsrpc_error)
+    }
+
+    async fn get_l2_to_l1_log_proof_for_batch(
+        &self,
+        batch: L1BatchNumber,
+        index: usize,
     ) -> RpcResult<Option<L2ToL1LogProof>> {
-        self.get_l2_to_l1_log_proof_impl(tx_hash, index)
+        self.get_l2_to_l1_log_proof_for_batch_impl(batch, index)
+            .await
+            .map_err(into_jsrpc_error)
+    }
+
+    async fn get_batch_l2_to_l1_log_proofs(
+        &self,
+        batch: L1BatchNumber,
+        cursor: Option<u32>,
+        limit: Option<u32>,
+    ) -> RpcResult<Option<BatchL2ToL1LogProofsPage>> {
+        self.get_batch_l2_to_l1_log_proofs_impl(batch, cursor, limit)
+            .await
+            .map_err(into_jsrpc_error)
+    }
+
+    async fn verify_l2_to_l1_log_proof(
+        &self,
+        proof: L2ToL1LogProof,
+        batch: L1BatchNumber,
+        leaf: H256,
+    ) -> RpcResult<Option<L2ToL1LogProofVerification>> {
+        self.verify_l2_to_l1_log_proof_impl(proof, batch, leaf)
             .await
             .map_err(into_jsrpc_error)
     }
@@ -97,32 +283,89 @@ impl<G: L1GasPriceProvider + Send + Sync + 'static> ZksNamespaceServer for ZksNa
             .map_err(into_jsrpc_error)
     }
 
+    async fn get_next_batch_info(&self) -> RpcResult<NextL1BatchInfo> {
+        self.get_next_batch_info_impl()
+            .await
+            .map_err(into_jsrpc_error)
+    }
+
     async fn get_miniblock_range(&self, batch: L1BatchNumber) -> RpcResult<Option<(U64, U64)>> {
         self.get_miniblock_range_impl(batch)
             .await
             .map_err(into_jsrpc_error)
     }
 
-    async fn get_block_details(
+    async fn get_block_details(&self, block: MiniblockId) -> RpcResult<Option<BlockDetails>> {
+        self.get_block_details_impl(block)
+            .await
+            .map_err(into_jsrpc_error)
+    }
+
+    async fn get_block_details_range(
+        &self,
+        from: MiniblockNumber,
+        to: MiniblockNumber,
+    ) -> RpcResult<Vec<BlockDetails>> {
+        self.get_block_details_range_impl(from, to)
+            .await
+            .map_err(into_jsrpc_error)
+    }
+
+    async fn get_miniblock_header(
         &self,
         block_number: MiniblockNumber,
-    ) -> RpcResult<Option<BlockDetails>> {
-        self.get_block_details_impl(block_number)
+    ) -> RpcResult<Option<BlockHeaderDetails>> {
+        self.get_miniblock_header_impl(block_number)
+            .await
+            .map_err(into_jsrpc_error)
+    }
+
+    async fn get_miniblock_by_timestamp(
+        &self,
+        timestamp: u64,
+        rounding: TimestampRounding,
+    ) -> RpcResult<Option<MiniblockNumber>> {
+        self.get_miniblock_by_timestamp_impl(timestamp, rounding)
+            .await
+            .map_err(into_jsrpc_error)
+    }
+
+    async fn get_transaction_details(
+        &self,
+        hash: H256,
+        include_gas_estimate: Option<bool>,
+        include_raw: Option<bool>,
+    ) -> RpcResult<Option<TransactionDetails>> {
+        self.get_transaction_details_impl(hash, include_gas_estimate, include_raw)
+            .await
+            .map_err(into_jsrpc_error)
+    }
+
+    async fn get_transaction_details_batch(
+        &self,
+        hashes: Vec<H256>,
+    ) -> RpcResult<Vec<(H256, Option<TransactionDetails>)>> {
+        self.get_transaction_details_batch_impl(hashes)
             .await
             .map_err(into_jsrpc_error)
     }
 
-    async fn get_transaction_details(&self, hash: H256) -> RpcResult<Option<TransactionDetails>> {
-        self.get_transaction_details_impl(hash)
+    async fn send_raw_transaction_with_detailed_error(&self, tx_bytes: Bytes) -> RpcResult<H256> {
+        self.send_raw_transaction_with_detailed_error_impl(tx_bytes)
             .await
             .map_err(into_jsrpc_error)
     }
 
+    async fn set_known_bytecode(&self, bytecode: Bytes) -> RpcResult<bool> {
+        self.set_known_bytecode_impl(bytecode)
+            .map_err(into_jsrpc_error)
+    }
+
     async fn get_raw_block_transactions(
         &self,
-        block_number: MiniblockNumber,
+        block: MiniblockId,
     ) -> RpcResult<Vec<zksync_types::Transaction>> {
-        self.get_raw_block_transactions_impl(block_number)
+        self.get_raw_block_transactions_impl(block)
             .await
             .map_err(into_jsrpc_error)
     }
@@ -136,11 +379,236 @@ impl<G: L1GasPriceProvider + Send + Sync + 'static> ZksNamespaceServer for ZksNa
             .map_err(into_jsrpc_error)
     }
 
+    async fn get_l1_batch_stats(&self, batch: L1BatchNumber) -> RpcResult<Option<L1BatchStats>> {
+        self.get_l1_batch_stats_impl(batch)
+            .await
+            .map_err(into_jsrpc_error)
+    }
+
+    async fn get_latest_l1_batch_details(
+        &self,
+        stage: Option<L1BatchStage>,
+    ) -> RpcResult<Option<L1BatchDetails>> {
+        self.get_latest_l1_batch_details_impl(stage)
+            .await
+            .map_err(into_jsrpc_error)
+    }
+
+    async fn get_batch_l1_commit_data(
+        &self,
+        batch: L1BatchNumber,
+    ) -> RpcResult<Option<BatchL1CommitData>> {
+        self.get_batch_l1_commit_data_impl(batch)
+            .await
+            .map_err(into_jsrpc_error)
+    }
+
+    async fn get_batch_commitment_inputs(
+        &self,
+        batch: L1BatchNumber,
+    ) -> RpcResult<Option<BatchCommitmentInputs>> {
+        self.get_batch_commitment_inputs_impl(batch)
+            .await
+            .map_err(into_jsrpc_error)
+    }
+
+    async fn get_l1_batch_fees(&self, batch: L1BatchNumber) -> RpcResult<Option<L1BatchFees>> {
+        self.get_l1_batch_fees_impl(batch)
+            .await
+            .map_err(into_jsrpc_error)
+    }
+
+    async fn get_effective_l1_gas_price_for_batch(
+        &self,
+        batch: L1BatchNumber,
+    ) -> RpcResult<Option<U64>> {
+        Ok(self.get_effective_l1_gas_price_for_batch_impl(batch).await)
+    }
+
     async fn get_bytecode_by_hash(&self, hash: H256) -> RpcResult<Option<Vec<u8>>> {
         Ok(self.get_bytecode_by_hash_impl(hash).await)
     }
 
+    async fn get_bytecode_details_by_hash(&self, hash: H256) -> RpcResult<Option<BytecodeDetails>> {
+        self.get_bytecode_details_by_hash_impl(hash)
+            .await
+            .map_err(into_jsrpc_error)
+    }
+
+    async fn get_contract_creation_code(
+        &self,
+        address: Address,
+    ) -> RpcResult<Option<ContractCreationCode>> {
+        self.get_contract_creation_code_impl(address)
+            .await
+            .map_err(into_jsrpc_error)
+    }
+
     async fn get_l1_gas_price(&self) -> RpcResult<U64> {
         Ok(self.get_l1_gas_price_impl())
     }
+
+    async fn get_l1_gas_price_history(
+        &self,
+        from_miniblock: MiniblockNumber,
+        to_miniblock: MiniblockNumber,
+    ) -> RpcResult<Vec<(MiniblockNumber, U64)>> {
+        self.get_l1_gas_price_history_impl(from_miniblock, to_miniblock)
+            .await
+            .map_err(into_jsrpc_error)
+    }
+
+    async fn get_l1_gas_price_wei(&self) -> RpcResult<U256> {
+        Ok(self.get_l1_gas_price_wei_impl())
+    }
+
+    async fn get_fee_params(&self) -> RpcResult<FeeModelParams> {
+        Ok(self.get_fee_params_impl())
+    }
+
+    async fn get_nonce_at(
+        &self,
+        address: Address,
+        block_or_batch: BlockOrBatchNumber,
+    ) -> RpcResult<U256> {
+        self.get_nonce_at_impl(address, block_or_batch)
+            .await
+            .map_err(into_jsrpc_error)
+    }
+
+    async fn get_transaction_confirmations_needed(
+        &self,
+        hash: H256,
+    ) -> RpcResult<Option<TransactionConfirmationsNeeded>> {
+        self.get_transaction_confirmations_needed_impl(hash)
+            .await
+            .map_err(into_jsrpc_error)
+    }
+
+    async fn get_batch_diff(
+        &self,
+        from_batch: L1BatchNumber,
+        to_batch: L1BatchNumber,
+        after: Option<H256>,
+    ) -> RpcResult<BatchDiff> {
+        self.get_batch_diff_impl(from_batch, to_batch, after)
+            .await
+            .map_err(into_jsrpc_error)
+    }
+
+    async fn get_batch_proving_status(
+        &self,
+        batch: L1BatchNumber,
+    ) -> RpcResult<Option<L1BatchProvingStatus>> {
+        self.get_batch_proving_status_impl(batch)
+            .await
+            .map_err(into_jsrpc_error)
+    }
+
+    async fn get_address_activity(&self, address: Address) -> RpcResult<AddressActivity> {
+        self.get_address_activity_impl(address)
+            .await
+            .map_err(into_jsrpc_error)
+    }
+
+    async fn get_address_transaction_count(
+        &self,
+        address: Address,
+    ) -> RpcResult<AddressTransactionCount> {
+        self.get_address_transaction_count_impl(address)
+            .await
+            .map_err(into_jsrpc_error)
+    }
+
+    async fn get_verification_key_hash(
+        &self,
+        batch: Option<L1BatchNumber>,
+    ) -> RpcResult<Option<H256>> {
+        self.get_verification_key_hash_impl(batch)
+            .await
+            .map_err(into_jsrpc_error)
+    }
+
+    async fn get_protocol_version(
+        &self,
+        version_id: Option<u16>,
+    ) -> RpcResult<Option<ProtocolVersion>> {
+        self.get_protocol_version_impl(version_id)
+            .await
+            .map_err(into_jsrpc_error)
+    }
+
+    async fn get_transactions_in_batch(
+        &self,
+        batch: L1BatchNumber,
+        cursor: Option<u32>,
+        limit: Option<u32>,
+    ) -> RpcResult<BatchTransactionsPage> {
+        self.get_transactions_in_batch_impl(batch, cursor, limit)
+            .await
+            .map_err(into_jsrpc_error)
+    }
+
+    async fn get_contract_deployment_bytecode_hash(
+        &self,
+        address: Address,
+        block: Option<BlockId>,
+    ) -> RpcResult<Option<H256>> {
+        self.get_contract_deployment_bytecode_hash_impl(address, block)
+            .await
+            .map_err(into_jsrpc_error)
+    }
+
+    async fn get_transaction_receipt(
+        &self,
+        tx_hash: H256,
+    ) -> RpcResult<Option<ExtendedTransactionReceipt>> {
+        self.get_transaction_receipt_impl(tx_hash)
+            .await
+            .map_err(into_jsrpc_error)
+    }
+
+    async fn get_storage_at_batch(
+        &self,
+        address: Address,
+        keys: Vec<H256>,
+        block: Option<MiniblockNumber>,
+    ) -> RpcResult<HashMap<H256, H256>> {
+        self.get_storage_at_batch_impl(address, keys, block)
+            .await
+            .map_err(into_jsrpc_error)
+    }
+
+    async fn get_account_storage_keys(
+        &self,
+        address: Address,
+        block: Option<MiniblockNumber>,
+        cursor: Option<H256>,
+        limit: Option<u32>,
+    ) -> RpcResult<AccountStorageKeysPage> {
+        self.get_account_storage_keys_impl(address, block, cursor, limit)
+            .await
+            .map_err(into_jsrpc_error)
+    }
+
+    async fn replay_transaction(&self, hash: H256) -> RpcResult<Option<TransactionReplay>> {
+        self.replay_transaction_impl(hash)
+            .await
+            .map_err(into_jsrpc_error)
+    }
+
+    async fn get_connection_pool_stats(&self) -> RpcResult<ConnectionPoolStats> {
+        self.get_connection_pool_stats_impl()
+            .map_err(into_jsrpc_error)
+    }
+
+    async fn get_chain_stats(&self) -> RpcResult<ChainStats> {
+        self.get_chain_stats_impl().await.map_err(into_jsrpc_error)
+    }
+
+    async fn get_recent_batch_events(&self, limit: u32) -> RpcResult<Vec<L1BatchEvent>> {
+        self.get_recent_batch_events_impl(limit)
+            .await
+            .map_err(into_jsrpc_error)
+    }
 }
@@ -43,10 +43,13 @@ use backend_jsonrpc::{
     pub_sub::Web3PubSub,
 };
 use namespaces::{
-    DebugNamespace, EnNamespace, EthNamespace, EthSubscribe, NetNamespace, Web3Namespace,
-    ZksNamespace,
+    AccountBalancesCache, DebugNamespace, EnNamespace, EthNamespace, EthSubscribe, NetNamespace,
+    Web3Namespace, ZksNamespace,
+};
+use pubsub_notifier::{
+    notify_blocks, notify_fee_params, notify_l1_batches, notify_logs, notify_transaction_stages,
+    notify_txs,
 };
-use pubsub_notifier::{notify_blocks, notify_logs, notify_txs};
 use state::{Filters, RpcState};
 use zksync_health_check::CheckHealthStatus;
 
@@ -211,11 +214,24 @@ impl<G: 'static + Send + Sync + L1GasPriceProvider> ApiBuilder<G> {
             sync_state: self.sync_state.clone(),
             api_config: self.config.clone(),
             accounts: self.accounts.clone(),
-            #[cfg(feature = "openzeppelin_tests")]
-            known_bytecodes: Arc::new(RwLock::new(Default::default())),
+            debug_namespace_enabled: self.debug_namespace_config.is_some(),
+            chain_stats: Arc::default(),
+            known_bytecodes: Arc::default(),
         }
     }
 
+    fn build_zks_namespace(&self, rpc_state: RpcState<G>) -> ZksNamespace<G> {
+        let account_balances_cache = AccountBalancesCache::new(
+            Duration::from_millis(self.config.account_balances_cache_ttl_ms),
+            self.config.account_balances_cache_capacity,
+        );
+        ZksNamespace::new(
+            rpc_state,
+            account_balances_cache,
+            self.config.unsafe_admin_methods_enabled,
+        )
+    }
+
     async fn build_rpc_module(&self) -> RpcModule<EthNamespace<G>> {
         let zksync_network_id = self.config.l2_chain_id;
         let rpc_app = self.build_rpc_state();
@@ -224,7 +240,7 @@ impl<G: 'static + Send + Sync + L1GasPriceProvider> ApiBuilder<G> {
         let eth = EthNamespace::new(rpc_app.clone());
         let net = NetNamespace::new(zksync_network_id);
         let web3 = Web3Namespace;
-        let zks = ZksNamespace::new(rpc_app.clone());
+        let zks = self.build_zks_namespace(rpc_app.clone());
         let en = EnNamespace::new(rpc_app.clone());
 
         // Collect all the methods into a single RPC module.
@@ -335,7 +351,7 @@ impl<G: 'static + Send + Sync + L1GasPriceProvider> ApiBuilder<G> {
             let rpc_state = self.build_rpc_state();
             let mut io = IoHandler::new();
             io.extend_with(EthNamespace::new(rpc_state.clone()).to_delegate());
-            io.extend_with(ZksNamespace::new(rpc_state.clone()).to_delegate());
+            io.extend_with(self.build_zks_namespace(rpc_state.clone()).to_delegate());
             io.extend_with(EnNamespace::new(rpc_state.clone()).to_delegate());
             io.extend_with(Web3Namespace.to_delegate());
             io.extend_with(NetNamespace::new(zksync_network_id).to_delegate());
@@ -545,6 +561,25 @@ impl<G: 'static + Send + Sync + L1GasPriceProvider> ApiBuilder<G> {
                 polling_interval,
                 stop_receiver.clone(),
             )),
+            tokio::spawn(notify_fee_params(
+                pub_sub.active_fee_params_subs.clone(),
+                pub_sub.current_fee_params.clone(),
+                self.pool.clone(),
+                polling_interval,
+                stop_receiver.clone(),
+            )),
+            tokio::spawn(notify_transaction_stages(
+                pub_sub.active_transaction_subs.clone(),
+                self.pool.clone(),
+                polling_interval,
+                stop_receiver.clone(),
+            )),
+            tokio::spawn(notify_l1_batches(
+                pub_sub.active_l1_batch_subs.clone(),
+                self.pool.clone(),
+                polling_interval,
+                stop_receiver.clone(),
+            )),
         ];
 
         let (sender, recv) = oneshot::channel::<()>();
@@ -554,7 +589,7 @@ impl<G: 'static + Send + Sync + L1GasPriceProvider> ApiBuilder<G> {
             let mut io = PubSubHandler::default();
             io.extend_with(pub_sub.to_delegate());
             io.extend_with(EthNamespace::new(rpc_state.clone()).to_delegate());
-            io.extend_with(ZksNamespace::new(rpc_state.clone()).to_delegate());
+            io.extend_with(self.build_zks_namespace(rpc_state.clone()).to_delegate());
             io.extend_with(EnNamespace::new(rpc_state).to_delegate());
             io.extend_with(Web3Namespace.to_delegate());
             io.extend_with(NetNamespace::new(zksync_network_id).to_delegate());
@@ -33,7 +33,10 @@ use {
 use crate::{
     api_server::{
         execution_sandbox::BlockArgs,
-        web3::{backend_jsonrpc::error::internal_error, resolve_block, state::RpcState},
+        web3::{
+            backend_jsonrpc::error::internal_error, namespaces::check_range_limit, resolve_block,
+            state::RpcState,
+        },
     },
     l1_gas_price::L1GasPriceProvider,
 };
@@ -186,7 +189,7 @@ impl<G: L1GasPriceProvider> EthNamespace<G> {
         let fee = self
             .state
             .tx_sender
-            .get_txs_fee_in_wei(tx.into(), scale_factor, acceptable_overestimation)
+            .get_txs_fee_in_wei(tx.into(), scale_factor, acceptable_overestimation, None)
             .await
             .map_err(|err| Web3Error::SubmitTransactionError(err.to_string(), err.data()))?;
 
@@ -239,6 +242,11 @@ impl<G: L1GasPriceProvider> EthNamespace<G> {
 
         self.state.resolve_filter_block_hash(&mut filter).await?;
         let (from_block, to_block) = self.state.resolve_filter_block_range(&filter).await?;
+        check_range_limit(
+            from_block.0,
+            to_block.0,
+            self.state.api_config.max_logs_block_range,
+        )?;
 
         filter.to_block = Some(BlockNumber::Number(to_block.0.into()));
         let changes = self
@@ -263,10 +271,12 @@ impl<G: L1GasPriceProvider> EthNamespace<G> {
         let maybe_filter = self.state.installed_filters.read().await.get(idx).cloned();
         let filter = match maybe_filter {
             Some(TypedFilter::Events(filter, _)) => {
-                let from_block = self
-                    .state
-                    .resolve_filter_block_number(filter.from_block)
-                    .await?;
+                let (from_block, to_block) = self.state.resolve_filter_block_range(&filter).await?;
+                check_range_limit(
+                    from_block.0,
+                    to_block.0,
+                    self.state.api_config.max_logs_block_range,
+                )?;
                 TypedFilter::Events(filter, from_block)
             }
             _ => return Err(Web3Error::FilterNotFound),
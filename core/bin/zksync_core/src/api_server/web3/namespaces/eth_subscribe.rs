@@ -6,7 +6,7 @@ use jsonrpc_pubsub::typed;
 use jsonrpc_pubsub::SubscriptionId;
 use tokio::sync::RwLock;
 
-use zksync_types::web3::types::H128;
+use zksync_types::{api::TransactionStage, fee::FeeParams, web3::types::H128, H256};
 use zksync_web3_decl::types::{PubSubFilter, PubSubResult};
 
 use super::eth::EVENT_TOPIC_NUMBER_LIMIT;
@@ -18,6 +18,9 @@ enum SubscriptionType {
     Blocks,
     Txs,
     Logs,
+    FeeParams,
+    Transaction,
+    L1Batch,
 }
 
 impl SubscriptionType {
@@ -26,10 +29,22 @@ impl SubscriptionType {
             Self::Blocks => "blocks",
             Self::Txs => "txs",
             Self::Logs => "logs",
+            Self::FeeParams => "fee_params",
+            Self::Transaction => "transaction",
+            Self::L1Batch => "l1_batch",
         }
     }
 }
 
+/// Subscription to lifecycle stages of a single transaction.
+#[derive(Debug)]
+pub struct TransactionSubscription {
+    pub sink: typed::Sink<PubSubResult>,
+    pub hash: H256,
+    /// Last stage sent to the subscriber.
+    pub last_stage: Option<TransactionStage>,
+}
+
 #[derive(Debug, Clone)]
 pub struct EthSubscribe {
     // `jsonrpc` backend executes task subscription on a separate thread that has no tokio context.
@@ -37,6 +52,12 @@ pub struct EthSubscribe {
     pub active_block_subs: SubscriptionMap<typed::Sink<PubSubResult>>,
     pub active_tx_subs: SubscriptionMap<typed::Sink<PubSubResult>>,
     pub active_log_subs: SubscriptionMap<(typed::Sink<PubSubResult>, PubSubFilter)>,
+    pub active_fee_params_subs: SubscriptionMap<typed::Sink<PubSubResult>>,
+    /// Latest fee params observed by the notifier, sent to new `feeParams` subscribers right away.
+    pub current_fee_params: Arc<RwLock<Option<FeeParams>>>,
+    /// Subscriptions are removed once the transaction is executed or the subscriber disconnects.
+    pub active_transaction_subs: SubscriptionMap<TransactionSubscription>,
+    pub active_l1_batch_subs: SubscriptionMap<typed::Sink<PubSubResult>>,
 }
 
 impl EthSubscribe {
@@ -46,6 +67,10 @@ impl EthSubscribe {
             active_block_subs: SubscriptionMap::default(),
             active_tx_subs: SubscriptionMap::default(),
             active_log_subs: SubscriptionMap::default(),
+            active_fee_params_subs: SubscriptionMap::default(),
+            current_fee_params: Arc::default(),
+            active_transaction_subs: SubscriptionMap::default(),
+            active_l1_batch_subs: SubscriptionMap::default(),
         }
     }
 
@@ -115,6 +140,15 @@ impl EthSubscribe {
                     }
                 }
             }
+            "feeParams" => {
+                let mut fee_params_subs = self.active_fee_params_subs.write().await;
+                let (sink, id) = Self::assign_id(subscriber);
+                if let Some(fee_params) = *self.current_fee_params.read().await {
+                    let _ = sink.notify(Ok(PubSubResult::FeeParams(fee_params)));
+                }
+                fee_params_subs.insert(id, sink);
+                Some(SubscriptionType::FeeParams)
+            }
             "syncing" => {
                 let (sink, _) = Self::assign_id(subscriber);
                 let _ = sink.notify(Ok(PubSubResult::Syncing(false)));
@@ -131,6 +165,29 @@ impl EthSubscribe {
         }
     }
 
+    #[tracing::instrument(skip(self, subscriber))]
+    pub async fn sub_transaction(&self, subscriber: typed::Subscriber<PubSubResult>, hash: H256) {
+        let mut transaction_subs = self.active_transaction_subs.write().await;
+        let (sink, id) = Self::assign_id(subscriber);
+        transaction_subs.insert(
+            id,
+            TransactionSubscription {
+                sink,
+                hash,
+                last_stage: None,
+            },
+        );
+        metrics::increment_gauge!("api.web3.pubsub.active_subscribers", 1f64, "subscription_type" => SubscriptionType::Transaction.as_str());
+    }
+
+    #[tracing::instrument(skip(self, subscriber))]
+    pub async fn sub_l1_batch(&self, subscriber: typed::Subscriber<PubSubResult>) {
+        let mut l1_batch_subs = self.active_l1_batch_subs.write().await;
+        let (sink, id) = Self::assign_id(subscriber);
+        l1_batch_subs.insert(id, sink);
+        metrics::increment_gauge!("api.web3.pubsub.active_subscribers", 1f64, "subscription_type" => SubscriptionType::L1Batch.as_str());
+    }
+
     #[tracing::instrument(skip(self))]
     pub async fn unsub(&self, id: SubscriptionId) -> Result<bool, Error> {
         let removed = if self.active_block_subs.write().await.remove(&id).is_some() {
@@ -139,6 +196,30 @@ impl EthSubscribe {
             Some(SubscriptionType::Txs)
         } else if self.active_log_subs.write().await.remove(&id).is_some() {
             Some(SubscriptionType::Logs)
+        } else if self
+            .active_fee_params_subs
+            .write()
+            .await
+            .remove(&id)
+            .is_some()
+        {
+            Some(SubscriptionType::FeeParams)
+        } else if self
+            .active_transaction_subs
+            .write()
+            .await
+            .remove(&id)
+            .is_some()
+        {
+            Some(SubscriptionType::Transaction)
+        } else if self
+            .active_l1_batch_subs
+            .write()
+            .await
+            .remove(&id)
+            .is_some()
+        {
+            Some(SubscriptionType::L1Batch)
         } else {
             None
         };
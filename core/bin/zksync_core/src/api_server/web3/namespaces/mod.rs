@@ -5,6 +5,7 @@ use num::{rational::Ratio, BigUint};
 
 use zksync_types::U256;
 use zksync_utils::{biguint_to_u256, u256_to_biguint};
+use zksync_web3_decl::error::Web3Error;
 
 mod debug;
 mod en;
@@ -18,10 +19,10 @@ pub use self::{
     debug::DebugNamespace,
     en::EnNamespace,
     eth::EthNamespace,
-    eth_subscribe::{EthSubscribe, SubscriptionMap},
+    eth_subscribe::{EthSubscribe, SubscriptionMap, TransactionSubscription},
     net::NetNamespace,
     web3::Web3Namespace,
-    zks::ZksNamespace,
+    zks::{AccountBalancesCache, ZksNamespace},
 };
 
 pub fn scale_u256(val: U256, scale_factor: &Ratio<BigUint>) -> U256 {
@@ -29,3 +30,60 @@ pub fn scale_u256(val: U256, scale_factor: &Ratio<BigUint>) -> U256 {
     let result = (val_as_ratio * scale_factor).ceil();
     biguint_to_u256(result.to_integer())
 }
+
+/// Checks that the inclusive range `[from, to]` is not reversed and doesn't contain more than `max` items.
+pub fn check_range_limit(from: u32, to: u32, max: u32) -> Result<(), Web3Error> {
+    if from > to {
+        return Err(Web3Error::InvalidRange { from, to });
+    }
+    check_limit(u64::from(to - from) + 1, max)
+}
+
+/// Checks that the number of items requested at once (a page size, a batch of keys, etc.)
+/// doesn't exceed `max`.
+pub fn check_limit(requested: impl TryInto<u64>, max: u32) -> Result<(), Web3Error> {
+    let requested = requested.try_into().unwrap_or(u64::MAX);
+    if requested > u64::from(max) {
+        return Err(Web3Error::RangeTooLarge { max });
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_matches::assert_matches;
+
+    use super::*;
+
+    fn is_too_large(result: Result<(), Web3Error>) -> bool {
+        matches!(result, Err(Web3Error::RangeTooLarge { max: 10 }))
+    }
+
+    #[test]
+    fn checking_range_limit() {
+        assert_matches!(
+            check_range_limit(5, 4, 10),
+            Err(Web3Error::InvalidRange { from: 5, to: 4 })
+        );
+        assert_matches!(
+            check_range_limit(u32::MAX, 0, 10),
+            Err(Web3Error::InvalidRange { .. })
+        );
+
+        check_range_limit(0, 9, 10).unwrap();
+        check_range_limit(100, 109, 10).unwrap();
+        check_range_limit(7, 7, 1).unwrap();
+        assert!(is_too_large(check_range_limit(0, 10, 10)));
+        assert!(is_too_large(check_range_limit(100, 110, 10)));
+        assert!(is_too_large(check_range_limit(0, u32::MAX, 10)));
+    }
+
+    #[test]
+    fn checking_limit() {
+        check_limit(0_u32, 10).unwrap();
+        check_limit(10_u32, 10).unwrap();
+        check_limit(10_usize, 10).unwrap();
+        assert!(is_too_large(check_limit(11_u32, 10)));
+        assert!(is_too_large(check_limit(usize::MAX, 10)));
+    }
+}
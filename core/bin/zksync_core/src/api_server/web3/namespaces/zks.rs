@@ -1,62 +1,238 @@
-use std::time::Instant;
+use std::time::{Duration, Instant};
 use std::{collections::HashMap, convert::TryInto};
 
 use bigdecimal::{BigDecimal, Zero};
+use once_cell::sync::Lazy;
 
-use zksync_mini_merkle_tree::MiniMerkleTree;
+use vm::vm_with_bootloader::{BLOCK_OVERHEAD_GAS, BLOCK_OVERHEAD_L1_GAS, BLOCK_OVERHEAD_PUBDATA};
+use zksync_dal::StorageProcessor;
+use zksync_mini_merkle_tree::{compute_root_from_path, MiniMerkleTree};
 
-#[cfg(feature = "openzeppelin_tests")]
-use zksync_types::Bytes;
 use zksync_types::{
-    api::{BridgeAddresses, GetLogsFilter, L2ToL1LogProof, TransactionDetails, U64},
+    api::{
+        self, AccountBalancesPage, AccountStorageKeysPage, AddressActivity,
+        AddressTransactionCount, BaseTokenPrice, BatchCommitmentInputs, BatchDiff,
+        BatchL1CommitData, BatchL2ToL1LogProofsPage, BatchTransactionsPage, BlockGasLimit,
+        BlockIdVariant, BlockOrBatchNumber, BridgeAddresses, BridgehubContract, BytecodeDetails,
+        ChainStats, ConnectionPoolStats, ContractCreationCode, EncodedU256,
+        ExtendedTransactionReceipt, FeeModelParams, GetLogsFilter, IntegerEncoding, L1BatchEvent,
+        L1BatchFees, L1BatchProvingStatus, L1BatchStage, L1BatchStats,
+        L1ToL2GasLimitRecommendation, L2ToL1LogIndex, L2ToL1LogProof, L2ToL1LogProofVerification,
+        MiniblockId, NextL1BatchInfo, ProtocolVersion, PubdataPricingMode, ReplayedLog,
+        StorageSlotDiff, TimestampRounding, TokenBalance, TokenLiquidityFilter, TokenSortOrder,
+        TransactionConfirmationsNeeded, TransactionDetails, TransactionRejection,
+        TransactionRejectionReason, TransactionReplay, WithdrawalParams, U64,
+    },
     commitment::SerializeCommitment,
-    explorer_api::{BlockDetails, L1BatchDetails},
-    fee::Fee,
+    ethabi,
+    event::L1_MESSAGE_EVENT_SIGNATURE,
+    explorer_api::{BlockDetails, BlockHeaderDetails, DeployContractCalldata, L1BatchDetails},
+    fee::{DetailedFee, Fee, FeeEstimate, FeeFormat},
+    get_known_code_key,
     l1::L1Tx,
+    l2::L2Tx,
     l2_to_l1_log::L2ToL1Log,
-    tokens::ETHEREUM_ADDRESS,
-    transaction_request::{l2_tx_from_call_req, CallRequest},
-    L1BatchNumber, MiniblockNumber, Transaction, L1_MESSENGER_ADDRESS, L2_ETH_TOKEN_ADDRESS,
-    MAX_GAS_PER_PUBDATA_BYTE, REQUIRED_L1_TO_L2_GAS_PER_PUBDATA_BYTE, U256,
+    tokens::{TokenPrice, ETHEREUM_ADDRESS},
+    transaction_request::{l2_tx_from_call_req, CallRequest, Eip712Meta, PaymasterParams},
+    tx::tx_execution_info::TxExecutionStatus,
+    web3::signing::keccak256,
+    zk_evm::zkevm_opcode_defs::system_params,
+    AccountTreeId, Bytes, ExecuteTransactionCommon, L1BatchNumber, MiniblockNumber, StorageKey,
+    StorageLog, Transaction, EIP_712_TX_TYPE, L1_GAS_PER_PUBDATA_BYTE, L1_MESSENGER_ADDRESS,
+    L2_ETH_TOKEN_ADDRESS, MAX_GAS_PER_PUBDATA_BYTE, REQUIRED_L1_TO_L2_GAS_PER_PUBDATA_BYTE, U256,
+};
+use zksync_utils::{
+    address_to_h256, bytecode::validate_bytecode, ratio_to_big_decimal_normalized,
+    time::seconds_since_epoch,
 };
-use zksync_utils::address_to_h256;
 use zksync_web3_decl::{
     error::Web3Error,
     types::{Address, Token, H256},
 };
 
-use crate::api_server::web3::{backend_jsonrpc::error::internal_error, RpcState};
+use crate::api_server::execution_sandbox::{replay_tx_in_sandbox, BlockArgs, TxSharedArgs};
+use crate::api_server::tx_sender::{FixedFeeInputs, SubmitTxError};
+use crate::api_server::web3::{
+    backend_jsonrpc::error::internal_error,
+    namespaces::{check_limit, check_range_limit},
+    resolve_block, RpcState,
+};
 use crate::fee_ticker::FeeTicker;
-use crate::fee_ticker::{error::TickerError, TokenPriceRequestType};
+use crate::fee_ticker::{error::TickerError, TokenPriceRequestType, MIN_PRECISION, USD_PRECISION};
 use crate::l1_gas_price::L1GasPriceProvider;
 
+static DEPLOYER_CONTRACT: Lazy<ethabi::Contract> = Lazy::new(zksync_contracts::deployer_contract);
+
+/// In-process cache for `zks_getAllAccountBalances` responses.
+///
+/// Entries are keyed by the account address and the latest sealed miniblock, so a cached response
+/// is never served once a new miniblock is sealed; stale entries are dropped after the TTL expires
+/// or when the cache runs out of capacity.
+#[derive(Debug, Clone)]
+pub struct AccountBalancesCache {
+    cache: mini_moka::sync::Cache<(Address, MiniblockNumber), HashMap<Address, U256>>,
+}
+
+impl AccountBalancesCache {
+    pub fn new(ttl: Duration, capacity: u64) -> Self {
+        let cache = mini_moka::sync::Cache::builder()
+            .time_to_live(ttl)
+            .max_capacity(capacity)
+            .build();
+        Self { cache }
+    }
+
+    fn get(&self, address: Address, miniblock: MiniblockNumber) -> Option<HashMap<Address, U256>> {
+        let entry = self.cache.get(&(address, miniblock));
+        metrics::increment_counter!(
+            "api.web3.account_balances_cache.requests",
+            "kind" => if entry.is_some() { "hit" } else { "miss" }
+        );
+        entry
+    }
+
+    fn insert(
+        &self,
+        address: Address,
+        miniblock: MiniblockNumber,
+        balances: HashMap<Address, U256>,
+    ) {
+        self.cache.insert((address, miniblock), balances);
+    }
+}
+
 #[derive(Debug)]
 pub struct ZksNamespace<G> {
     pub state: RpcState<G>,
+    account_balances_cache: AccountBalancesCache,
+    /// Whether unsafe administrative methods (e.g. `zks_setKnownBytecode`) may be called.
+    unsafe_admin_enabled: bool,
 }
 
 impl<G> Clone for ZksNamespace<G> {
     fn clone(&self) -> Self {
         Self {
             state: self.state.clone(),
+            account_balances_cache: self.account_balances_cache.clone(),
+            unsafe_admin_enabled: self.unsafe_admin_enabled,
         }
     }
 }
 
+/// Returns positions of the transaction logs among the L2 -> L1 logs of its L1 batch, in the order
+/// they were emitted. Logs of the transaction are matched the same way as in
+/// `ZksNamespace::get_l2_to_l1_log_proof_impl()`.
+fn l2_to_l1_log_indices_for_tx(
+    all_l1_logs_in_batch: &[L2ToL1Log],
+    l1_batch_tx_index: u64,
+) -> Vec<L2ToL1LogIndex> {
+    all_l1_logs_in_batch
+        .iter()
+        .enumerate()
+        .filter(|(_, log)| u64::from(log.tx_number_in_block) == l1_batch_tx_index)
+        .enumerate()
+        .map(
+            |(log_index_in_tx, (log_index_in_batch, log))| L2ToL1LogIndex {
+                log_index_in_tx: log_index_in_tx as u32,
+                log_index_in_batch: log_index_in_batch as u32,
+                sender: log.sender,
+                key: log.key,
+            },
+        )
+        .collect()
+}
+
 impl<G: L1GasPriceProvider> ZksNamespace<G> {
-    pub fn new(state: RpcState<G>) -> Self {
-        Self { state }
+    pub fn new(
+        state: RpcState<G>,
+        account_balances_cache: AccountBalancesCache,
+        unsafe_admin_enabled: bool,
+    ) -> Self {
+        Self {
+            state,
+            account_balances_cache,
+            unsafe_admin_enabled,
+        }
+    }
+
+    #[tracing::instrument(skip(self, request))]
+    pub async fn estimate_fee_impl(
+        &self,
+        request: CallRequest,
+        deterministic: Option<bool>,
+        format: Option<FeeFormat>,
+    ) -> Result<FeeEstimate, Web3Error> {
+        let start = Instant::now();
+        let tx = self.l2_tx_for_fee_estimation(request).await?;
+        let fixed_inputs = if deterministic.unwrap_or(false) {
+            // Pin both the state and the L1 gas price to the last sealed miniblock.
+            let mut storage = self
+                .state
+                .connection_pool
+                .access_storage_tagged("api")
+                .await;
+            let header = storage
+                .blocks_dal()
+                .get_last_sealed_miniblock_header()
+                .await
+                .ok_or(Web3Error::NoBlock)?;
+            let block_args = Self::fixed_block_args(&mut storage, header.number, "estimate_fee")
+                .await?
+                .ok_or(Web3Error::NoBlock)?;
+            Some(FixedFeeInputs {
+                block_args,
+                l1_gas_price: header.l1_gas_price,
+            })
+        } else {
+            None
+        };
+        let fee = self.estimate_fee(tx.into(), fixed_inputs).await?;
+
+        metrics::histogram!("api.web3.call", start.elapsed(), "method" => "estimate_fee");
+        Ok(FeeEstimate::new(fee, format))
     }
 
     #[tracing::instrument(skip(self, request))]
-    pub async fn estimate_fee_impl(&self, request: CallRequest) -> Result<Fee, Web3Error> {
+    pub async fn estimate_fee_detailed_impl(
+        &self,
+        request: CallRequest,
+    ) -> Result<DetailedFee, Web3Error> {
+        const METHOD_NAME: &str = "estimate_fee_detailed";
+
         let start = Instant::now();
+        let tx = self.l2_tx_for_fee_estimation(request).await?;
+        let fee = self.estimate_detailed_fee(tx.into(), None).await?;
+
+        metrics::histogram!("api.web3.call", start.elapsed(), "method" => METHOD_NAME);
+        Ok(fee)
+    }
+
+    /// Converts a call request into an L2 transaction suitable for fee estimation.
+    async fn l2_tx_for_fee_estimation(&self, request: CallRequest) -> Result<L2Tx, Web3Error> {
         let mut request_with_gas_per_pubdata_overridden = request;
 
         self.state
             .set_nonce_for_call_request(&mut request_with_gas_per_pubdata_overridden)
             .await?;
 
+        // Paymaster may be provided without the rest of EIP-712 metadata; the transaction is then
+        // estimated as an EIP-712 one, so that paymaster validation is run.
+        if let Some(paymaster) = request_with_gas_per_pubdata_overridden.paymaster.take() {
+            let paymaster_input = request_with_gas_per_pubdata_overridden
+                .paymaster_input
+                .take()
+                .unwrap_or_default();
+            request_with_gas_per_pubdata_overridden
+                .eip712_meta
+                .get_or_insert_with(Eip712Meta::default)
+                .paymaster_params
+                .get_or_insert(PaymasterParams {
+                    paymaster,
+                    paymaster_input: paymaster_input.0,
+                });
+            request_with_gas_per_pubdata_overridden.transaction_type = Some(EIP_712_TX_TYPE.into());
+        }
+
         if let Some(ref mut eip712_meta) = request_with_gas_per_pubdata_overridden.eip712_meta {
             eip712_meta.gas_per_pubdata = MAX_GAS_PER_PUBDATA_BYTE.into();
         }
@@ -70,39 +246,165 @@ impl<G: L1GasPriceProvider> ZksNamespace<G> {
         // not consider provided ones.
         tx.common_data.fee.max_priority_fee_per_gas = 0u64.into();
         tx.common_data.fee.gas_per_pubdata_limit = MAX_GAS_PER_PUBDATA_BYTE.into();
+        Ok(tx)
+    }
 
-        let fee = self.estimate_fee(tx.into()).await?;
+    #[tracing::instrument(skip(self, request))]
+    pub async fn estimate_l1_to_l2_gas_impl(
+        &self,
+        request: CallRequest,
+    ) -> Result<U256, Web3Error> {
+        let start = Instant::now();
+        let gas_limit = self.estimate_l1_to_l2_gas(request, None, None).await?;
 
-        metrics::histogram!("api.web3.call", start.elapsed(), "method" => "estimate_fee");
-        Ok(fee)
+        metrics::histogram!("api.web3.call", start.elapsed(), "method" => "estimate_gas_l1_to_l2");
+        Ok(gas_limit)
     }
 
     #[tracing::instrument(skip(self, request))]
-    pub async fn estimate_l1_to_l2_gas_impl(
+    pub async fn estimate_l1_to_l2_gas_encoded_impl(
+        &self,
+        request: CallRequest,
+        encoding: Option<IntegerEncoding>,
+    ) -> Result<EncodedU256, Web3Error> {
+        const METHOD_NAME: &str = "estimate_gas_l1_to_l2_encoded";
+
+        let start = Instant::now();
+        let gas_limit = self.estimate_l1_to_l2_gas(request, None, None).await?;
+
+        metrics::histogram!("api.web3.call", start.elapsed(), "method" => METHOD_NAME);
+        Ok(EncodedU256::new(gas_limit, encoding))
+    }
+
+    #[tracing::instrument(skip(self, request))]
+    pub async fn estimate_l1_to_l2_gas_detailed_impl(
         &self,
         request: CallRequest,
+        gas_per_pubdata: Option<U256>,
+        refund_recipient: Option<Address>,
     ) -> Result<U256, Web3Error> {
+        const METHOD_NAME: &str = "estimate_gas_l1_to_l2_detailed";
+
         let start = Instant::now();
-        let mut request_with_gas_per_pubdata_overridden = request;
+        if gas_per_pubdata == Some(U256::zero()) {
+            return Err(Web3Error::InvalidFeeParams(
+                "gas per pubdata byte must be positive".to_owned(),
+            ));
+        }
+        let gas_limit = self
+            .estimate_l1_to_l2_gas(request, gas_per_pubdata, refund_recipient)
+            .await?;
+
+        metrics::histogram!("api.web3.call", start.elapsed(), "method" => METHOD_NAME);
+        Ok(gas_limit)
+    }
+
+    /// Estimates the gas limit of an L1->L2 transaction. If `refund_recipient` is not specified,
+    /// it's left zero, so that the estimate accounts for the refund going to an arbitrary address.
+    async fn estimate_l1_to_l2_gas(
+        &self,
+        mut request: CallRequest,
+        gas_per_pubdata: Option<U256>,
+        refund_recipient: Option<Address>,
+    ) -> Result<U256, Web3Error> {
         // When we're estimating fee, we are trying to deduce values related to fee, so we should
         // not consider provided ones.
-        if let Some(ref mut eip712_meta) = request_with_gas_per_pubdata_overridden.eip712_meta {
+        if let Some(ref mut eip712_meta) = request.eip712_meta {
             if eip712_meta.gas_per_pubdata == U256::zero() {
                 eip712_meta.gas_per_pubdata = REQUIRED_L1_TO_L2_GAS_PER_PUBDATA_BYTE.into();
             }
         }
 
-        let tx: L1Tx = request_with_gas_per_pubdata_overridden
-            .try_into()
-            .map_err(Web3Error::SerializationError)?;
-
-        let fee = self.estimate_fee(tx.into()).await?;
+        let mut tx: L1Tx = request.try_into().map_err(Web3Error::SerializationError)?;
+        if let Some(gas_per_pubdata) = gas_per_pubdata {
+            tx.common_data.gas_per_pubdata_limit = gas_per_pubdata;
+        }
+        if let Some(refund_recipient) = refund_recipient {
+            tx.common_data.refund_recipient = refund_recipient;
+        }
 
-        metrics::histogram!("api.web3.call", start.elapsed(), "method" => "estimate_gas_l1_to_l2");
+        let fee = self.estimate_fee(tx.into(), None).await?;
         Ok(fee.gas_limit)
     }
 
-    async fn estimate_fee(&self, tx: Transaction) -> Result<Fee, Web3Error> {
+    #[tracing::instrument(skip(self, request))]
+    pub async fn get_recommended_l1_to_l2_gas_limit_impl(
+        &self,
+        request: CallRequest,
+    ) -> Result<L1ToL2GasLimitRecommendation, Web3Error> {
+        const METHOD_NAME: &str = "get_recommended_l1_to_l2_gas_limit";
+
+        let start = Instant::now();
+        let estimated_gas_limit = self.estimate_l1_to_l2_gas_impl(request).await?;
+        let buffer_percent = self.state.api_config.l1_to_l2_gas_limit_buffer_percent;
+        let recommended_gas_limit =
+            estimated_gas_limit + estimated_gas_limit * buffer_percent / 100;
+
+        metrics::histogram!("api.web3.call", start.elapsed(), "method" => METHOD_NAME);
+        Ok(L1ToL2GasLimitRecommendation {
+            estimated_gas_limit,
+            recommended_gas_limit,
+        })
+    }
+
+    async fn is_l1_batch_executed(
+        storage: &mut StorageProcessor<'_>,
+        l1_batch_number: L1BatchNumber,
+    ) -> bool {
+        storage
+            .blocks_dal()
+            .get_number_of_last_block_executed_on_eth()
+            .await
+            .map_or(false, |last_executed| l1_batch_number <= last_executed)
+    }
+
+    /// Returns the format of L2 -> L1 log proofs for the L1 batch, which is determined by
+    /// the protocol version the batch was sealed under rather than the latest one.
+    async fn l2_to_l1_log_proof_version(
+        storage: &mut StorageProcessor<'_>,
+        l1_batch_number: L1BatchNumber,
+        method_name: &'static str,
+    ) -> Result<u16, Web3Error> {
+        let protocol_version = storage
+            .blocks_web3_dal()
+            .get_l1_batch_protocol_version(l1_batch_number)
+            .await
+            .map_err(|err| internal_error(method_name, err))?;
+        let proof_version = L2ToL1LogProof::proof_version_for(protocol_version);
+        // Mini Merkle tree proofs are the only format this node can build.
+        if proof_version != L2ToL1LogProof::MINI_MERKLE_TREE_VERSION {
+            return Err(Web3Error::UnsupportedProofVersion(proof_version));
+        }
+        Ok(proof_version)
+    }
+
+    /// Loads arguments for executing a transaction on top of a sealed miniblock. `storage` should be the connection
+    /// the miniblock header was read from, so that a lagging replica cannot make the miniblock disappear.
+    async fn fixed_block_args(
+        storage: &mut StorageProcessor<'_>,
+        miniblock_number: MiniblockNumber,
+        method_name: &'static str,
+    ) -> Result<Option<BlockArgs>, Web3Error> {
+        let block_id = api::BlockId::Number(api::BlockNumber::Number(miniblock_number.0.into()));
+        BlockArgs::new(storage, block_id)
+            .await
+            .map_err(|err| internal_error(method_name, err))
+    }
+
+    async fn estimate_fee(
+        &self,
+        tx: Transaction,
+        fixed_inputs: Option<FixedFeeInputs>,
+    ) -> Result<Fee, Web3Error> {
+        let fee = self.estimate_detailed_fee(tx, fixed_inputs).await?;
+        Ok(fee.fee)
+    }
+
+    async fn estimate_detailed_fee(
+        &self,
+        tx: Transaction,
+        fixed_inputs: Option<FixedFeeInputs>,
+    ) -> Result<DetailedFee, Web3Error> {
         let scale_factor = self.state.api_config.estimate_gas_scale_factor;
         let acceptable_overestimation =
             self.state.api_config.estimate_gas_acceptable_overestimation;
@@ -110,9 +412,14 @@ impl<G: L1GasPriceProvider> ZksNamespace<G> {
         let fee = self
             .state
             .tx_sender
-            .get_txs_fee_in_wei(tx, scale_factor, acceptable_overestimation)
+            .get_txs_detailed_fee_in_wei(tx, scale_factor, acceptable_overestimation, fixed_inputs)
             .await
-            .map_err(|err| Web3Error::SubmitTransactionError(err.to_string(), err.data()))?;
+            .map_err(|err| match err {
+                SubmitTxError::PubdataLimitExceeded(pubdata, limit) => {
+                    Web3Error::PubdataLimitExceeded { pubdata, limit }
+                }
+                err => Web3Error::SubmitTransactionError(err.to_string(), err.data()),
+            })?;
 
         Ok(fee)
     }
@@ -122,6 +429,18 @@ impl<G: L1GasPriceProvider> ZksNamespace<G> {
         self.state.api_config.diamond_proxy_addr
     }
 
+    /// Returns `None` if the chain isn't registered on a Bridgehub.
+    #[tracing::instrument(skip(self))]
+    pub fn get_bridgehub_contract_impl(&self) -> Option<BridgehubContract> {
+        let api_config = &self.state.api_config;
+        api_config
+            .bridgehub_proxy_addr
+            .map(|bridgehub_proxy_addr| BridgehubContract {
+                bridgehub_proxy_addr,
+                chain_id: U64::from(api_config.l2_chain_id.0),
+            })
+    }
+
     #[tracing::instrument(skip(self))]
     pub fn get_testnet_paymaster_impl(&self) -> Option<Address> {
         self.state.api_config.l2_testnet_paymaster_addr
@@ -137,14 +456,138 @@ impl<G: L1GasPriceProvider> ZksNamespace<G> {
         U64::from(*self.state.api_config.l1_chain_id)
     }
 
+    #[tracing::instrument(skip(self))]
+    pub fn get_current_block_gas_limit_impl(&self) -> BlockGasLimit {
+        let sender_config = &self.state.tx_sender.0.sender_config;
+        BlockGasLimit {
+            block_gas_limit: system_params::VM_INITIAL_FRAME_ERGS.into(),
+            max_transaction_gas_limit: sender_config.max_allowed_l2_tx_gas_limit.into(),
+        }
+    }
+
     #[tracing::instrument(skip(self))]
     pub async fn get_confirmed_tokens_impl(
         &self,
         from: u32,
         limit: u8,
+        eth_first: Option<bool>,
+        verify_onchain: Option<bool>,
+        liquidity_filter: Option<TokenLiquidityFilter>,
     ) -> Result<Vec<Token>, Web3Error> {
         const METHOD_NAME: &str = "get_confirmed_tokens";
 
+        let verify_onchain = verify_onchain.unwrap_or(false);
+        if verify_onchain {
+            check_limit(limit, self.state.api_config.max_verified_tokens)?;
+        }
+
+        let start = Instant::now();
+        let mut storage = self
+            .state
+            .connection_pool
+            .access_storage_tagged("api")
+            .await;
+        let liquidity_filter = liquidity_filter.unwrap_or_default();
+        let tokens = if liquidity_filter.is_empty() {
+            storage.tokens_web3_dal().get_well_known_tokens().await
+        } else {
+            storage
+                .tokens_web3_dal()
+                .get_well_known_tokens_with_min_liquidity(
+                    liquidity_filter.min_holders,
+                    liquidity_filter.min_total_supply,
+                )
+                .await
+        };
+        let mut tokens = tokens.map_err(|err| internal_error(METHOD_NAME, err))?;
+        if eth_first.unwrap_or(false) {
+            // The sort is stable, so the other tokens keep their order.
+            tokens.sort_by_key(|token_info| token_info.l1_address != ETHEREUM_ADDRESS);
+        }
+        let mut tokens: Vec<_> = tokens
+            .into_iter()
+            .skip(from as usize)
+            .take(limit.into())
+            .map(|token_info| Token {
+                l1_address: token_info.l1_address,
+                l2_address: token_info.l2_address,
+                name: token_info.metadata.name,
+                symbol: token_info.metadata.symbol,
+                decimals: token_info.metadata.decimals,
+                deployed: None,
+                confirmed: None,
+            })
+            .collect();
+
+        if verify_onchain {
+            let block = api::BlockId::Number(api::BlockNumber::Latest);
+            let block_number = resolve_block(&mut storage, block, METHOD_NAME).await?;
+            for token in &mut tokens {
+                // ETH is not an ordinary contract, so there's nothing to check for it.
+                let deployed = token.l1_address == ETHEREUM_ADDRESS
+                    || storage
+                        .storage_web3_dal()
+                        .get_contract_bytecode_hash_unchecked(token.l2_address, block_number)
+                        .await
+                        .map_err(|err| internal_error(METHOD_NAME, err))?
+                        .is_some();
+                token.deployed = Some(deployed);
+            }
+        }
+
+        metrics::histogram!("api.web3.call", start.elapsed(), "method" => METHOD_NAME);
+        Ok(tokens)
+    }
+
+    /// Returns `None` if the token list wasn't modified after the `if_modified_after` timestamp
+    /// (in seconds), so that clients can keep using their cached list.
+    #[tracing::instrument(skip(self))]
+    pub async fn get_confirmed_tokens_if_modified_impl(
+        &self,
+        if_modified_after: u64,
+        from: u32,
+        limit: u8,
+        eth_first: Option<bool>,
+        verify_onchain: Option<bool>,
+    ) -> Result<Option<Vec<Token>>, Web3Error> {
+        const METHOD_NAME: &str = "get_confirmed_tokens_if_modified";
+
+        let start = Instant::now();
+        let last_updated_at = self
+            .state
+            .connection_pool
+            .access_storage_tagged("api")
+            .await
+            .tokens_web3_dal()
+            .get_well_known_tokens_last_updated_at()
+            .await
+            .map_err(|err| internal_error(METHOD_NAME, err))?;
+        let is_modified = last_updated_at.map_or(false, |updated_at| {
+            updated_at.timestamp() as u64 > if_modified_after
+        });
+        let tokens = if is_modified {
+            let tokens = self
+                .get_confirmed_tokens_impl(from, limit, eth_first, verify_onchain, None)
+                .await?;
+            Some(tokens)
+        } else {
+            None
+        };
+
+        metrics::histogram!("api.web3.call", start.elapsed(), "method" => METHOD_NAME);
+        Ok(tokens)
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub async fn get_confirmed_tokens_filtered_impl(
+        &self,
+        symbol: Option<String>,
+        sort: Option<TokenSortOrder>,
+        from: u32,
+        limit: u8,
+    ) -> Result<Vec<Token>, Web3Error> {
+        const METHOD_NAME: &str = "get_confirmed_tokens_filtered";
+
         let start = Instant::now();
         let tokens = self
             .state
@@ -152,18 +595,23 @@ impl<G: L1GasPriceProvider> ZksNamespace<G> {
             .access_storage_tagged("api")
             .await
             .tokens_web3_dal()
-            .get_well_known_tokens()
+            .get_well_known_tokens_filtered(
+                symbol.as_deref(),
+                sort.unwrap_or_default(),
+                from,
+                limit.into(),
+            )
             .await
             .map_err(|err| internal_error(METHOD_NAME, err))?
             .into_iter()
-            .skip(from as usize)
-            .take(limit.into())
             .map(|token_info| Token {
                 l1_address: token_info.l1_address,
                 l2_address: token_info.l2_address,
                 name: token_info.metadata.name,
                 symbol: token_info.metadata.symbol,
                 decimals: token_info.metadata.decimals,
+                deployed: None,
+                confirmed: None,
             })
             .collect();
 
@@ -171,6 +619,37 @@ impl<G: L1GasPriceProvider> ZksNamespace<G> {
         Ok(tokens)
     }
 
+    #[tracing::instrument(skip(self))]
+    pub async fn get_token_by_l2_address_impl(
+        &self,
+        l2_address: Address,
+    ) -> Result<Option<Token>, Web3Error> {
+        const METHOD_NAME: &str = "get_token_by_l2_address";
+
+        let start = Instant::now();
+        let token = self
+            .state
+            .connection_pool
+            .access_storage_tagged("api")
+            .await
+            .tokens_web3_dal()
+            .get_token_by_l2_address(&l2_address)
+            .await
+            .map_err(|err| internal_error(METHOD_NAME, err))?
+            .map(|(token_info, well_known)| Token {
+                l1_address: token_info.l1_address,
+                l2_address: token_info.l2_address,
+                name: token_info.metadata.name,
+                symbol: token_info.metadata.symbol,
+                decimals: token_info.metadata.decimals,
+                deployed: None,
+                confirmed: Some(well_known),
+            });
+
+        metrics::histogram!("api.web3.call", start.elapsed(), "method" => METHOD_NAME);
+        Ok(token)
+    }
+
     #[tracing::instrument(skip(self))]
     pub async fn get_token_price_impl(&self, l2_token: Address) -> Result<BigDecimal, Web3Error> {
         const METHOD_NAME: &str = "get_token_price";
@@ -201,6 +680,77 @@ impl<G: L1GasPriceProvider> ZksNamespace<G> {
         result
     }
 
+    #[tracing::instrument(skip(self))]
+    pub async fn get_token_price_at_impl(
+        &self,
+        l2_token: Address,
+        at: api::BlockIdVariant,
+    ) -> Result<TokenPrice, Web3Error> {
+        const METHOD_NAME: &str = "get_token_price_at";
+
+        let start = Instant::now();
+        let mut storage = self
+            .state
+            .connection_pool
+            .access_storage_tagged("api")
+            .await;
+        let token = storage
+            .tokens_web3_dal()
+            .get_token_by_l2_address(&l2_token)
+            .await
+            .map_err(|err| internal_error(METHOD_NAME, err))?;
+        if token.is_none() {
+            return Err(Web3Error::UnknownToken);
+        }
+
+        let block_number = resolve_block(&mut storage, at.into(), METHOD_NAME).await?;
+        // The timestamp is `None` for the pending miniblock, in which case the latest price is returned.
+        let timestamp = storage
+            .blocks_web3_dal()
+            .get_block_timestamp(block_number)
+            .await
+            .map_err(|err| internal_error(METHOD_NAME, err))?;
+        let price = storage
+            .tokens_web3_dal()
+            .get_token_price_at(&l2_token, timestamp)
+            .await
+            .map_err(|err| internal_error(METHOD_NAME, err))?
+            .ok_or(Web3Error::NoTokenPrice);
+
+        metrics::histogram!("api.web3.call", start.elapsed(), "method" => METHOD_NAME);
+        price
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub async fn get_base_token_price_impl(&self) -> Result<Option<BaseTokenPrice>, Web3Error> {
+        const METHOD_NAME: &str = "get_base_token_price";
+
+        let start = Instant::now();
+        // Fees are always paid in ETH in this version of the protocol.
+        let base_token = ETHEREUM_ADDRESS;
+        let token_price = self
+            .state
+            .connection_pool
+            .access_storage_tagged("api")
+            .await
+            .tokens_web3_dal()
+            .get_token_price(&base_token)
+            .await
+            .map_err(|err| internal_error(METHOD_NAME, err))?;
+        let base_token_price = token_price.map(|price| BaseTokenPrice {
+            l2_address: base_token,
+            usd_price: ratio_to_big_decimal_normalized(
+                &price.usd_price,
+                USD_PRECISION,
+                MIN_PRECISION,
+            ),
+            timestamp: price.last_updated.timestamp() as u64,
+        });
+
+        metrics::histogram!("api.web3.call", start.elapsed(), "method" => METHOD_NAME);
+        Ok(base_token_price)
+    }
+
     #[tracing::instrument(skip(self))]
     pub async fn get_all_account_balances_impl(
         &self,
@@ -209,22 +759,42 @@ impl<G: L1GasPriceProvider> ZksNamespace<G> {
         const METHOD_NAME: &str = "get_all_balances";
 
         let start = Instant::now();
-        let balances = self
+        let mut storage = self
             .state
             .connection_pool
             .access_storage_tagged("api")
+            .await;
+        let miniblock = storage
+            .blocks_web3_dal()
+            .get_sealed_miniblock_number()
             .await
-            .explorer()
-            .accounts_dal()
-            .get_balances_for_address(address)
-            .await
-            .map_err(|err| internal_error(METHOD_NAME, err))?
+            .map_err(|err| internal_error(METHOD_NAME, err))?;
+        let raw_balances = match self.account_balances_cache.get(address, miniblock) {
+            Some(balances) => balances,
+            None => {
+                let balances: HashMap<_, _> = storage
+                    .explorer()
+                    .accounts_dal()
+                    .get_balances_for_address(address)
+                    .await
+                    .map_err(|err| internal_error(METHOD_NAME, err))?
+                    .into_iter()
+                    .map(|(address, balance_item)| (address, balance_item.balance))
+                    .collect();
+                self.account_balances_cache
+                    .insert(address, miniblock, balances.clone());
+                balances
+            }
+        };
+        drop(storage);
+
+        let balances = raw_balances
             .into_iter()
-            .map(|(address, balance_item)| {
+            .map(|(address, balance)| {
                 if address == L2_ETH_TOKEN_ADDRESS {
-                    (ETHEREUM_ADDRESS, balance_item.balance)
+                    (ETHEREUM_ADDRESS, balance)
                 } else {
-                    (address, balance_item.balance)
+                    (address, balance)
                 }
             })
             .collect();
@@ -234,14 +804,80 @@ impl<G: L1GasPriceProvider> ZksNamespace<G> {
     }
 
     #[tracing::instrument(skip(self))]
-    pub async fn get_l2_to_l1_msg_proof_impl(
+    pub async fn get_all_account_balances_encoded_impl(
         &self,
-        block_number: MiniblockNumber,
-        sender: Address,
-        msg: H256,
-        l2_log_position: Option<usize>,
-    ) -> Result<Option<L2ToL1LogProof>, Web3Error> {
-        const METHOD_NAME: &str = "get_l2_to_l1_msg_proof";
+        address: Address,
+        encoding: Option<IntegerEncoding>,
+    ) -> Result<HashMap<Address, EncodedU256>, Web3Error> {
+        const METHOD_NAME: &str = "get_all_balances_encoded";
+
+        let start = Instant::now();
+        let balances = self
+            .get_all_account_balances_impl(address)
+            .await?
+            .into_iter()
+            .map(|(address, balance)| (address, EncodedU256::new(balance, encoding)))
+            .collect();
+
+        metrics::histogram!("api.web3.call", start.elapsed(), "method" => METHOD_NAME);
+        Ok(balances)
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub async fn get_account_balances_paged_impl(
+        &self,
+        address: Address,
+        after_token: Option<Address>,
+        limit: Option<u32>,
+    ) -> Result<AccountBalancesPage, Web3Error> {
+        const METHOD_NAME: &str = "get_account_balances_paged";
+
+        let max_page_size = self.state.api_config.max_account_balances_page_size;
+        let limit = limit.unwrap_or(max_page_size);
+        check_limit(limit, max_page_size)?;
+
+        let start = Instant::now();
+        // One extra balance is loaded to find out whether there is a next page.
+        let mut balances = self
+            .state
+            .connection_pool
+            .access_storage_tagged("api")
+            .await
+            .explorer()
+            .accounts_dal()
+            .get_balances_for_address_paged(address, after_token, limit as usize + 1)
+            .await
+            .map_err(|err| internal_error(METHOD_NAME, err))?;
+        let next_cursor = if balances.len() > limit as usize {
+            balances.truncate(limit as usize);
+            balances.last().map(|(token_address, _)| *token_address)
+        } else {
+            None
+        };
+        let balances = balances
+            .into_iter()
+            .map(|(token_address, balance)| TokenBalance {
+                token_address,
+                balance,
+            })
+            .collect();
+
+        metrics::histogram!("api.web3.call", start.elapsed(), "method" => METHOD_NAME);
+        Ok(AccountBalancesPage {
+            balances,
+            next_cursor,
+        })
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub async fn get_l2_to_l1_msg_proof_impl(
+        &self,
+        block_number: MiniblockNumber,
+        sender: Address,
+        msg: H256,
+        l2_log_position: Option<usize>,
+    ) -> Result<Option<L2ToL1LogProof>, Web3Error> {
+        const METHOD_NAME: &str = "get_l2_to_l1_msg_proof";
 
         let start = Instant::now();
         let mut storage = self
@@ -317,13 +953,20 @@ impl<G: L1GasPriceProvider> ZksNamespace<G> {
             }
         };
 
+        let proof_version =
+            Self::l2_to_l1_log_proof_version(&mut storage, l1_batch_number, METHOD_NAME).await?;
         let merkle_tree_leaves = all_l1_logs_in_block.iter().map(L2ToL1Log::to_bytes);
         let (root, proof) = MiniMerkleTree::new(merkle_tree_leaves, L2ToL1Log::LIMIT_PER_BLOCK)
             .merkle_root_and_path(l1_log_index);
+        let is_executed = Self::is_l1_batch_executed(&mut storage, l1_batch_number).await;
         let msg_proof = L2ToL1LogProof {
             proof,
             root,
             id: l1_log_index as u32,
+            leaves: None,
+            proof_version,
+            l1_batch_number: Some(l1_batch_number),
+            is_executed,
         };
         metrics::histogram!("api.web3.call", start.elapsed(), "method" => METHOD_NAME);
         Ok(Some(msg_proof))
@@ -334,6 +977,7 @@ impl<G: L1GasPriceProvider> ZksNamespace<G> {
         &self,
         tx_hash: H256,
         index: Option<usize>,
+        full_tree: Option<bool>,
     ) -> Result<Option<L2ToL1LogProof>, Web3Error> {
         const METHOD_NAME: &str = "get_l2_to_l1_msg_proof";
 
@@ -371,13 +1015,37 @@ impl<G: L1GasPriceProvider> ZksNamespace<G> {
             }
         };
 
+        let leaves = if full_tree.unwrap_or(false) {
+            let max_leaves = self.state.api_config.max_l2_to_l1_log_proof_leaves;
+            if all_l1_logs_in_block.len() > max_leaves {
+                return Err(Web3Error::TooManyL2ToL1Logs(
+                    all_l1_logs_in_block.len(),
+                    max_leaves,
+                ));
+            }
+            let leaves = all_l1_logs_in_block
+                .iter()
+                .map(|log| H256(keccak256(&log.to_bytes())))
+                .collect();
+            Some(leaves)
+        } else {
+            None
+        };
+
+        let proof_version =
+            Self::l2_to_l1_log_proof_version(&mut storage, l1_batch_number, METHOD_NAME).await?;
         let merkle_tree_leaves = all_l1_logs_in_block.iter().map(L2ToL1Log::to_bytes);
         let (root, proof) = MiniMerkleTree::new(merkle_tree_leaves, L2ToL1Log::LIMIT_PER_BLOCK)
             .merkle_root_and_path(l1_log_index);
+        let is_executed = Self::is_l1_batch_executed(&mut storage, l1_batch_number).await;
         let msg_proof = L2ToL1LogProof {
             proof,
             root,
             id: l1_log_index as u32,
+            leaves,
+            proof_version,
+            l1_batch_number: Some(l1_batch_number),
+            is_executed,
         };
 
         metrics::histogram!("api.web3.call", start.elapsed(), "method" => METHOD_NAME);
@@ -385,192 +1053,2102 @@ impl<G: L1GasPriceProvider> ZksNamespace<G> {
     }
 
     #[tracing::instrument(skip(self))]
-    pub async fn get_l1_batch_number_impl(&self) -> Result<U64, Web3Error> {
-        const METHOD_NAME: &str = "get_l1_batch_number";
+    pub async fn get_withdrawal_params_impl(
+        &self,
+        withdrawal_hash: H256,
+        index: Option<usize>,
+    ) -> Result<Option<WithdrawalParams>, Web3Error> {
+        const METHOD_NAME: &str = "get_withdrawal_params";
 
         let start = Instant::now();
-        let l1_batch_number = self
+        let receipt = self
             .state
             .connection_pool
             .access_storage_tagged("api")
             .await
-            .blocks_web3_dal()
-            .get_sealed_l1_batch_number()
+            .transactions_web3_dal()
+            .get_transaction_receipt(withdrawal_hash)
             .await
-            .map(|n| U64::from(n.0))
-            .map_err(|err| internal_error(METHOD_NAME, err));
+            .map_err(|err| internal_error(METHOD_NAME, err))?;
+        let Some(receipt) = receipt else {
+            return Ok(None);
+        };
+        let (Some(miniblock_number), Some(l1_batch_tx_index)) =
+            (receipt.block_number, receipt.l1_batch_tx_index)
+        else {
+            return Ok(None);
+        };
 
-        metrics::histogram!("api.web3.call", start.elapsed(), "endpoint" => METHOD_NAME);
-        l1_batch_number
+        let l1_message_event = receipt
+            .logs
+            .into_iter()
+            .filter(|log| {
+                log.address == L1_MESSENGER_ADDRESS
+                    && log.topics.len() == 3
+                    && log.topics[0] == *L1_MESSAGE_EVENT_SIGNATURE
+            })
+            .nth(index.unwrap_or(0));
+        let Some(l1_message_event) = l1_message_event else {
+            return Ok(None);
+        };
+        let sender = Address::from_slice(&l1_message_event.topics[1].as_bytes()[12..]);
+        let message = ethabi::decode(&[ethabi::ParamType::Bytes], &l1_message_event.data.0)
+            .ok()
+            .and_then(|tokens| tokens.into_iter().next()?.into_bytes());
+        let Some(message) = message else {
+            return Err(internal_error(METHOD_NAME, "malformed L1MessageSent event"));
+        };
+
+        let miniblock_number = MiniblockNumber(miniblock_number.as_u32());
+        let proof = self
+            .get_l2_to_l1_msg_proof_impl(
+                miniblock_number,
+                sender,
+                H256(keccak256(&message)),
+                l1_message_event
+                    .log_index
+                    .map(|log_index| log_index.as_usize()),
+            )
+            .await?;
+        let params = proof.and_then(|proof| {
+            Some(WithdrawalParams {
+                l1_batch_number: proof.l1_batch_number?,
+                l2_message_index: proof.id,
+                l2_tx_number_in_batch: l1_batch_tx_index.as_u32() as u16,
+                miniblock_number,
+                sender,
+                message: message.into(),
+                proof: proof.proof,
+                is_executed: proof.is_executed,
+            })
+        });
+
+        metrics::histogram!("api.web3.call", start.elapsed(), "method" => METHOD_NAME);
+        Ok(params)
     }
 
     #[tracing::instrument(skip(self))]
-    pub async fn get_miniblock_range_impl(
+    pub async fn get_l2_to_l1_log_proof_for_batch_impl(
         &self,
         batch: L1BatchNumber,
-    ) -> Result<Option<(U64, U64)>, Web3Error> {
-        const METHOD_NAME: &str = "get_miniblock_range";
+        index: usize,
+    ) -> Result<Option<L2ToL1LogProof>, Web3Error> {
+        const METHOD_NAME: &str = "get_l2_to_l1_log_proof_for_batch";
 
         let start = Instant::now();
-        let minmax = self
+        let mut storage = self
             .state
             .connection_pool
             .access_storage_tagged("api")
+            .await;
+        let last_sealed_l1_batch = storage
+            .blocks_web3_dal()
+            .get_sealed_l1_batch_number()
             .await
+            .map_err(|err| internal_error(METHOD_NAME, err))?;
+        if batch > last_sealed_l1_batch {
+            return Ok(None);
+        }
+        // Logs are returned in the order they were emitted in the batch, which is the leaf order
+        // of the tree committed to L1.
+        let all_l1_logs_in_batch = storage
             .blocks_web3_dal()
-            .get_miniblock_range_of_l1_batch(batch)
+            .get_l2_to_l1_logs(batch)
             .await
-            .map(|minmax| minmax.map(|(min, max)| (U64::from(min.0), U64::from(max.0))))
-            .map_err(|err| internal_error(METHOD_NAME, err));
+            .map_err(|err| internal_error(METHOD_NAME, err))?;
+        if index >= all_l1_logs_in_batch.len() {
+            return Ok(None);
+        }
 
-        metrics::histogram!("api.web3.call", start.elapsed(), "endpoint" => METHOD_NAME);
-        minmax
+        let proof_version =
+            Self::l2_to_l1_log_proof_version(&mut storage, batch, METHOD_NAME).await?;
+        let merkle_tree_leaves = all_l1_logs_in_batch.iter().map(L2ToL1Log::to_bytes);
+        let (root, proof) = MiniMerkleTree::new(merkle_tree_leaves, L2ToL1Log::LIMIT_PER_BLOCK)
+            .merkle_root_and_path(index);
+        let is_executed = Self::is_l1_batch_executed(&mut storage, batch).await;
+        let log_proof = L2ToL1LogProof {
+            proof,
+            root,
+            id: index as u32,
+            leaves: None,
+            proof_version,
+            l1_batch_number: Some(batch),
+            is_executed,
+        };
+
+        metrics::histogram!("api.web3.call", start.elapsed(), "method" => METHOD_NAME);
+        Ok(Some(log_proof))
     }
 
     #[tracing::instrument(skip(self))]
-    pub async fn get_block_details_impl(
+    pub async fn get_batch_l2_to_l1_log_proofs_impl(
         &self,
-        block_number: MiniblockNumber,
-    ) -> Result<Option<BlockDetails>, Web3Error> {
-        const METHOD_NAME: &str = "get_block_details";
+        batch: L1BatchNumber,
+        cursor: Option<u32>,
+        limit: Option<u32>,
+    ) -> Result<Option<BatchL2ToL1LogProofsPage>, Web3Error> {
+        const METHOD_NAME: &str = "get_batch_l2_to_l1_log_proofs";
 
         let start = Instant::now();
-        let block_details = self
+        let max_page_size = self
+            .state
+            .api_config
+            .max_batch_l2_to_l1_log_proofs_page_size;
+        let limit = limit.unwrap_or(max_page_size);
+        check_limit(limit, max_page_size)?;
+
+        let mut storage = self
             .state
             .connection_pool
             .access_storage_tagged("api")
+            .await;
+        let last_sealed_l1_batch = storage
+            .blocks_web3_dal()
+            .get_sealed_l1_batch_number()
             .await
-            .explorer()
-            .blocks_dal()
-            .get_block_details(
-                block_number,
-                self.state.tx_sender.0.sender_config.fee_account_addr,
-            )
+            .map_err(|err| internal_error(METHOD_NAME, err))?;
+        if batch > last_sealed_l1_batch {
+            return Ok(None);
+        }
+        let all_l1_logs_in_batch = storage
+            .blocks_web3_dal()
+            .get_l2_to_l1_logs(batch)
             .await
-            .map_err(|err| internal_error(METHOD_NAME, err));
+            .map_err(|err| internal_error(METHOD_NAME, err))?;
+
+        let log_count = all_l1_logs_in_batch.len();
+        let from = (cursor.unwrap_or(0) as usize).min(log_count);
+        let to = (from + limit as usize).min(log_count);
+        let proof_version =
+            Self::l2_to_l1_log_proof_version(&mut storage, batch, METHOD_NAME).await?;
+        let merkle_tree_leaves = all_l1_logs_in_batch.iter().map(L2ToL1Log::to_bytes);
+        let tree = MiniMerkleTree::new(merkle_tree_leaves, L2ToL1Log::LIMIT_PER_BLOCK);
+        let (root, paths) = tree.merkle_root_and_paths(from..to);
+        let is_executed = Self::is_l1_batch_executed(&mut storage, batch).await;
+        let proofs = (from..to)
+            .zip(paths)
+            .map(|(id, proof)| L2ToL1LogProof {
+                proof,
+                root,
+                id: id as u32,
+                leaves: None,
+                proof_version,
+                l1_batch_number: Some(batch),
+                is_executed,
+            })
+            .collect();
+        let next_cursor = (to < log_count).then_some(to as u32);
 
         metrics::histogram!("api.web3.call", start.elapsed(), "method" => METHOD_NAME);
-        block_details
+        Ok(Some(BatchL2ToL1LogProofsPage {
+            l1_batch_number: batch,
+            root,
+            proofs,
+            next_cursor,
+        }))
     }
 
     #[tracing::instrument(skip(self))]
-    pub async fn get_raw_block_transactions_impl(
+    pub async fn verify_l2_to_l1_log_proof_impl(
         &self,
-        block_number: MiniblockNumber,
-    ) -> Result<Vec<Transaction>, Web3Error> {
-        const METHOD_NAME: &str = "get_raw_block_transactions";
+        proof: L2ToL1LogProof,
+        batch: L1BatchNumber,
+        leaf: H256,
+    ) -> Result<Option<L2ToL1LogProofVerification>, Web3Error> {
+        const METHOD_NAME: &str = "verify_l2_to_l1_log_proof";
+
+        if proof.proof_version != L2ToL1LogProof::MINI_MERKLE_TREE_VERSION {
+            return Err(Web3Error::UnsupportedProofVersion(proof.proof_version));
+        }
 
         let start = Instant::now();
-        let transactions = self
+        let mut storage = self
             .state
             .connection_pool
             .access_storage_tagged("api")
+            .await;
+        let last_sealed_l1_batch = storage
+            .blocks_web3_dal()
+            .get_sealed_l1_batch_number()
             .await
-            .transactions_web3_dal()
-            .get_raw_miniblock_transactions(block_number)
+            .map_err(|err| internal_error(METHOD_NAME, err))?;
+        if batch > last_sealed_l1_batch {
+            return Ok(None);
+        }
+        let all_l1_logs_in_batch = storage
+            .blocks_web3_dal()
+            .get_l2_to_l1_logs(batch)
             .await
-            .map_err(|err| internal_error(METHOD_NAME, err));
+            .map_err(|err| internal_error(METHOD_NAME, err))?;
+
+        // The expected root is rebuilt the same way as when the proofs are constructed,
+        // so that the check doesn't depend on the batch metadata being computed.
+        let proof_version =
+            Self::l2_to_l1_log_proof_version(&mut storage, batch, METHOD_NAME).await?;
+        let merkle_tree_leaves = all_l1_logs_in_batch.iter().map(L2ToL1Log::to_bytes);
+        let expected_root =
+            MiniMerkleTree::new(merkle_tree_leaves, L2ToL1Log::LIMIT_PER_BLOCK).merkle_root();
+        let computed_root = compute_root_from_path(leaf, proof.id as usize, &proof.proof);
+        let tree_depth = L2ToL1Log::LIMIT_PER_BLOCK.trailing_zeros() as usize;
+        let valid = proof.proof_version == proof_version
+            && (proof.id as usize) < L2ToL1Log::LIMIT_PER_BLOCK
+            && proof.proof.len() == tree_depth
+            && computed_root == proof.root
+            && computed_root == expected_root;
 
         metrics::histogram!("api.web3.call", start.elapsed(), "method" => METHOD_NAME);
-        transactions
+        Ok(Some(L2ToL1LogProofVerification {
+            valid,
+            computed_root,
+            expected_root,
+        }))
     }
 
     #[tracing::instrument(skip(self))]
-    pub async fn get_transaction_details_impl(
+    pub async fn get_transaction_receipt_impl(
         &self,
-        hash: H256,
-    ) -> Result<Option<TransactionDetails>, Web3Error> {
-        const METHOD_NAME: &str = "get_transaction_details";
+        tx_hash: H256,
+    ) -> Result<Option<ExtendedTransactionReceipt>, Web3Error> {
+        const METHOD_NAME: &str = "get_transaction_receipt";
 
         let start = Instant::now();
-        let mut tx_details = self
+        let mut storage = self
             .state
             .connection_pool
             .access_storage_tagged("api")
-            .await
+            .await;
+        let Some(receipt) = storage
             .transactions_web3_dal()
-            .get_transaction_details(hash)
+            .get_transaction_receipt(tx_hash)
             .await
-            .map_err(|err| internal_error(METHOD_NAME, err));
+            .map_err(|err| internal_error(METHOD_NAME, err))?
+        else {
+            metrics::histogram!("api.web3.call", start.elapsed(), "method" => METHOD_NAME);
+            return Ok(None);
+        };
 
-        if let Some(proxy) = &self.state.tx_sender.0.proxy {
-            // We're running an external node - we should query the main node directly
-            // in case the transaction was proxied but not yet synced back to us
-            if matches!(tx_details, Ok(None)) {
-                // If the transaction is not in the db, query main node for details
-                tx_details = proxy
-                    .request_tx_details(hash)
-                    .await
-                    .map_err(|err| internal_error(METHOD_NAME, err));
-            }
+        let mut l2_to_l1_log_indices = vec![];
+        if let (Some(l1_batch_number), Some(l1_batch_tx_index)) =
+            (receipt.l1_batch_number, receipt.l1_batch_tx_index)
+        {
+            let all_l1_logs_in_batch = storage
+                .blocks_web3_dal()
+                .get_l2_to_l1_logs(L1BatchNumber(l1_batch_number.as_u32()))
+                .await
+                .map_err(|err| internal_error(METHOD_NAME, err))?;
+            l2_to_l1_log_indices =
+                l2_to_l1_log_indices_for_tx(&all_l1_logs_in_batch, l1_batch_tx_index.as_u64());
         }
 
         metrics::histogram!("api.web3.call", start.elapsed(), "method" => METHOD_NAME);
-        tx_details
+        Ok(Some(ExtendedTransactionReceipt {
+            receipt,
+            l2_to_l1_log_indices,
+        }))
     }
 
     #[tracing::instrument(skip(self))]
-    pub async fn get_l1_batch_details_impl(
-        &self,
-        batch_number: L1BatchNumber,
-    ) -> Result<Option<L1BatchDetails>, Web3Error> {
-        const METHOD_NAME: &str = "get_l1_batch";
+    pub async fn get_next_batch_info_impl(&self) -> Result<NextL1BatchInfo, Web3Error> {
+        const METHOD_NAME: &str = "get_next_batch_info";
 
         let start = Instant::now();
-        let l1_batch = self
+        let mut storage = self
             .state
             .connection_pool
             .access_storage_tagged("api")
+            .await;
+        let last_sealed_l1_batch = storage
+            .blocks_web3_dal()
+            .get_sealed_l1_batch_number()
             .await
-            .explorer()
-            .blocks_dal()
-            .get_l1_batch_details(batch_number)
+            .map_err(|err| internal_error(METHOD_NAME, err))?;
+        let pending_stats = storage
+            .blocks_web3_dal()
+            .get_pending_l1_batch_stats()
             .await
-            .map_err(|err| internal_error(METHOD_NAME, err));
+            .map_err(|err| internal_error(METHOD_NAME, err))?;
+        drop(storage);
+
+        let (opened_at, tx_count) = match pending_stats {
+            Some((opened_at, tx_count)) => (Some(opened_at), tx_count),
+            None => (None, 0),
+        };
+        let sender_config = &self.state.tx_sender.0.sender_config;
+        let estimated_seal_timestamp = sender_config
+            .block_commit_deadline_ms
+            .zip(sender_config.transaction_slots)
+            .map(|(deadline_ms, transaction_slots)| {
+                let now = seconds_since_epoch();
+                let opened_at = opened_at.unwrap_or(now);
+                let deadline = opened_at + deadline_ms / 1_000;
+                let elapsed = now.saturating_sub(opened_at);
+                if elapsed == 0 || tx_count == 0 {
+                    return deadline;
+                }
+                // Extrapolate the current fill rate to estimate when the transaction slots run out.
+                let remaining_slots = (transaction_slots as u64).saturating_sub(tx_count);
+                let filled_at = now + remaining_slots * elapsed / tx_count;
+                deadline.min(filled_at)
+            });
 
         metrics::histogram!("api.web3.call", start.elapsed(), "method" => METHOD_NAME);
-        l1_batch
+        Ok(NextL1BatchInfo {
+            number: last_sealed_l1_batch + 1,
+            opened_at,
+            tx_count,
+            estimated_seal_timestamp,
+        })
     }
 
     #[tracing::instrument(skip(self))]
-    pub async fn get_bytecode_by_hash_impl(&self, hash: H256) -> Option<Vec<u8>> {
-        const METHOD_NAME: &str = "get_bytecode_by_hash";
+    pub async fn get_l1_batch_number_impl(&self) -> Result<U64, Web3Error> {
+        const METHOD_NAME: &str = "get_l1_batch_number";
 
         let start = Instant::now();
-        let bytecode = self
+        let l1_batch_number = self
             .state
             .connection_pool
             .access_storage_tagged("api")
             .await
-            .storage_dal()
-            .get_factory_dep(hash)
-            .await;
+            .blocks_web3_dal()
+            .get_sealed_l1_batch_number()
+            .await
+            .map(|n| U64::from(n.0))
+            .map_err(|err| internal_error(METHOD_NAME, err));
 
-        metrics::histogram!("api.web3.call", start.elapsed(), "method" => METHOD_NAME);
-        bytecode
+        metrics::histogram!("api.web3.call", start.elapsed(), "endpoint" => METHOD_NAME);
+        l1_batch_number
     }
 
     #[tracing::instrument(skip(self))]
-    pub fn get_l1_gas_price_impl(&self) -> U64 {
-        const METHOD_NAME: &str = "get_l1_gas_price";
+    pub async fn get_miniblock_range_impl(
+        &self,
+        batch: L1BatchNumber,
+    ) -> Result<Option<(U64, U64)>, Web3Error> {
+        const METHOD_NAME: &str = "get_miniblock_range";
 
         let start = Instant::now();
-        let gas_price = self
+        let minmax = self
             .state
-            .tx_sender
-            .0
-            .l1_gas_price_source
-            .estimate_effective_gas_price();
+            .connection_pool
+            .access_storage_tagged("api")
+            .await
+            .blocks_web3_dal()
+            .get_miniblock_range_of_l1_batch(batch)
+            .await
+            .map(|minmax| minmax.map(|(min, max)| (U64::from(min.0), U64::from(max.0))))
+            .map_err(|err| internal_error(METHOD_NAME, err));
+
+        metrics::histogram!("api.web3.call", start.elapsed(), "endpoint" => METHOD_NAME);
+        minmax
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub async fn get_block_details_impl(
+        &self,
+        block: MiniblockId,
+    ) -> Result<Option<BlockDetails>, Web3Error> {
+        const METHOD_NAME: &str = "get_block_details";
+
+        let start = Instant::now();
+        let mut storage = self
+            .state
+            .connection_pool
+            .access_storage_tagged("api")
+            .await;
+        let block_number = storage
+            .blocks_web3_dal()
+            .resolve_block_id(block.into())
+            .await;
+        let block_details = match block_number {
+            Ok(Some(block_number)) => storage
+                .explorer()
+                .blocks_dal()
+                .get_block_details(
+                    block_number,
+                    self.state.tx_sender.0.sender_config.fee_account_addr,
+                )
+                .await
+                .map_err(|err| internal_error(METHOD_NAME, err)),
+            Ok(None) => Ok(None),
+            Err(err) => Err(internal_error(METHOD_NAME, err)),
+        };
 
         metrics::histogram!("api.web3.call", start.elapsed(), "method" => METHOD_NAME);
-        gas_price.into()
+        block_details
     }
 
-    #[cfg(feature = "openzeppelin_tests")]
-    /// Saves contract bytecode to memory.
-    pub fn set_known_bytecode_impl(&self, bytecode: Bytes) -> bool {
-        let mut lock = self.state.known_bytecodes.write().unwrap();
-        lock.insert(bytecode.0.clone());
-        true
+    #[tracing::instrument(skip(self))]
+    pub async fn get_block_details_range_impl(
+        &self,
+        from: MiniblockNumber,
+        to: MiniblockNumber,
+    ) -> Result<Vec<BlockDetails>, Web3Error> {
+        const METHOD_NAME: &str = "get_block_details_range";
+
+        let start = Instant::now();
+        check_range_limit(from.0, to.0, self.state.api_config.max_block_details_range)?;
+
+        let block_details = self
+            .state
+            .connection_pool
+            .access_storage_tagged("api")
+            .await
+            .explorer()
+            .blocks_dal()
+            .get_block_details_range(
+                from,
+                to,
+                self.state.tx_sender.0.sender_config.fee_account_addr,
+            )
+            .await
+            .map_err(|err| internal_error(METHOD_NAME, err));
+
+        metrics::histogram!("api.web3.call", start.elapsed(), "method" => METHOD_NAME);
+        block_details
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub async fn get_miniblock_header_impl(
+        &self,
+        block_number: MiniblockNumber,
+    ) -> Result<Option<BlockHeaderDetails>, Web3Error> {
+        const METHOD_NAME: &str = "get_miniblock_header";
+
+        let start = Instant::now();
+        let header = self
+            .state
+            .connection_pool
+            .access_storage_tagged("api")
+            .await
+            .explorer()
+            .blocks_dal()
+            .get_block_header_details(block_number)
+            .await
+            .map_err(|err| internal_error(METHOD_NAME, err));
+
+        metrics::histogram!("api.web3.call", start.elapsed(), "method" => METHOD_NAME);
+        header
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub async fn get_raw_block_transactions_impl(
+        &self,
+        block: MiniblockId,
+    ) -> Result<Vec<Transaction>, Web3Error> {
+        const METHOD_NAME: &str = "get_raw_block_transactions";
+
+        let start = Instant::now();
+        let mut storage = self
+            .state
+            .connection_pool
+            .access_storage_tagged("api")
+            .await;
+        let block_number = storage
+            .blocks_web3_dal()
+            .resolve_block_id(block.into())
+            .await;
+        let transactions = match block_number {
+            Ok(Some(block_number)) => storage
+                .transactions_web3_dal()
+                .get_raw_miniblock_transactions(block_number)
+                .await
+                .map_err(|err| internal_error(METHOD_NAME, err)),
+            Ok(None) => Ok(Vec::new()),
+            Err(err) => Err(internal_error(METHOD_NAME, err)),
+        };
+
+        metrics::histogram!("api.web3.call", start.elapsed(), "method" => METHOD_NAME);
+        transactions
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub async fn get_transaction_details_impl(
+        &self,
+        hash: H256,
+        include_gas_estimate: Option<bool>,
+        include_raw: Option<bool>,
+    ) -> Result<Option<TransactionDetails>, Web3Error> {
+        const METHOD_NAME: &str = "get_transaction_details";
+
+        let start = Instant::now();
+        let mut tx_details = self
+            .load_transactions_details(&[hash], METHOD_NAME)
+            .await?
+            .remove(&hash);
+        if include_gas_estimate.unwrap_or(false) {
+            if let Some(details) = &mut tx_details {
+                details.estimated_gas_limit = self.estimate_executed_tx_gas_limit(hash).await?;
+            }
+        }
+        if include_raw.unwrap_or(false) {
+            if let Some(details) = &mut tx_details {
+                let tx = self
+                    .state
+                    .connection_pool
+                    .access_storage_tagged("api")
+                    .await
+                    .transactions_web3_dal()
+                    .get_raw_transaction(hash)
+                    .await
+                    .map_err(|err| internal_error(METHOD_NAME, err))?;
+                details.raw_transaction = tx.and_then(|tx| match tx.common_data {
+                    ExecuteTransactionCommon::L2(data) => data.input_data().map(Bytes::from),
+                    ExecuteTransactionCommon::L1(_) => None,
+                });
+            }
+        }
+
+        metrics::histogram!("api.web3.call", start.elapsed(), "method" => METHOD_NAME);
+        Ok(tx_details)
+    }
+
+    #[tracing::instrument(skip(self, hashes))]
+    pub async fn get_transaction_details_batch_impl(
+        &self,
+        hashes: Vec<H256>,
+    ) -> Result<Vec<(H256, Option<TransactionDetails>)>, Web3Error> {
+        const METHOD_NAME: &str = "get_transaction_details_batch";
+
+        check_limit(
+            hashes.len(),
+            self.state.api_config.max_transaction_details_batch_size,
+        )?;
+
+        let start = Instant::now();
+        let tx_details = self.load_transactions_details(&hashes, METHOD_NAME).await?;
+        let tx_details = hashes
+            .into_iter()
+            .map(|hash| (hash, tx_details.get(&hash).cloned()))
+            .collect();
+
+        metrics::histogram!("api.web3.call", start.elapsed(), "method" => METHOD_NAME);
+        Ok(tx_details)
+    }
+
+    /// Loads details for the specified transactions from the storage using a single query.
+    async fn load_transactions_details(
+        &self,
+        hashes: &[H256],
+        method_name: &'static str,
+    ) -> Result<HashMap<H256, TransactionDetails>, Web3Error> {
+        let mut tx_details = self
+            .state
+            .connection_pool
+            .access_storage_tagged("api")
+            .await
+            .transactions_web3_dal()
+            .get_transactions_details(hashes)
+            .await
+            .map_err(|err| internal_error(method_name, err))?;
+
+        if let Some(proxy) = &self.state.tx_sender.0.proxy {
+            // We're running an external node - we should query the main node directly
+            // in case the transactions were proxied but not yet synced back to us
+            let missing_hashes: Vec<_> = hashes
+                .iter()
+                .filter(|hash| !tx_details.contains_key(hash))
+                .copied()
+                .collect();
+            if !missing_hashes.is_empty() {
+                let main_node_details = proxy
+                    .request_txs_details(missing_hashes)
+                    .await
+                    .map_err(|err| internal_error(method_name, err))?;
+                tx_details.extend(
+                    main_node_details
+                        .into_iter()
+                        .filter_map(|(hash, details)| Some((hash, details?))),
+                );
+            }
+        }
+        Ok(tx_details)
+    }
+
+    /// Estimates the gas limit for an executed transaction on top of the miniblock preceding
+    /// the one it was included in. Returns `None` if the transaction is not executed yet or
+    /// if the estimation fails, e.g. because the transaction depends on the preceding transactions
+    /// in its miniblock.
+    async fn estimate_executed_tx_gas_limit(&self, hash: H256) -> Result<Option<U256>, Web3Error> {
+        const METHOD_NAME: &str = "estimate_executed_tx_gas_limit";
+
+        let mut storage = self
+            .state
+            .connection_pool
+            .access_storage_tagged("api")
+            .await;
+        let inclusion = storage
+            .transactions_web3_dal()
+            .get_transaction_inclusion(hash)
+            .await
+            .map_err(|err| internal_error(METHOD_NAME, err))?;
+        let Some((Some(miniblock_number), _)) = inclusion else {
+            return Ok(None);
+        };
+        if miniblock_number.0 == 0 {
+            return Ok(None);
+        }
+        let Some(previous_miniblock) = storage
+            .blocks_dal()
+            .get_miniblock_header(miniblock_number - 1)
+            .await
+        else {
+            return Ok(None);
+        };
+        let Some(block_args) =
+            Self::fixed_block_args(&mut storage, previous_miniblock.number, METHOD_NAME).await?
+        else {
+            return Ok(None);
+        };
+        let tx = storage
+            .transactions_web3_dal()
+            .get_raw_transaction(hash)
+            .await
+            .map_err(|err| internal_error(METHOD_NAME, err))?;
+        drop(storage);
+        let Some(tx) = tx else {
+            return Ok(None);
+        };
+
+        let fee = self
+            .estimate_fee(
+                tx,
+                Some(FixedFeeInputs {
+                    block_args,
+                    l1_gas_price: previous_miniblock.l1_gas_price,
+                }),
+            )
+            .await
+            .map_err(|err| {
+                vlog::debug!("Failed estimating gas for executed transaction {hash:?}: {err}");
+            })
+            .ok();
+        Ok(fee.map(|fee| fee.gas_limit))
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub async fn replay_transaction_impl(
+        &self,
+        hash: H256,
+    ) -> Result<Option<TransactionReplay>, Web3Error> {
+        const METHOD_NAME: &str = "replay_transaction";
+
+        if !self.state.debug_namespace_enabled {
+            return Err(Web3Error::NotImplemented);
+        }
+        let start = Instant::now();
+        let mut storage = self
+            .state
+            .connection_pool
+            .access_storage_tagged("api")
+            .await;
+        let receipt = storage
+            .transactions_web3_dal()
+            .get_transaction_receipt(hash)
+            .await
+            .map_err(|err| internal_error(METHOD_NAME, err))?;
+        let Some(receipt) = receipt else {
+            return Ok(None);
+        };
+        let Some(miniblock_number) = receipt.block_number else {
+            // The transaction is not executed yet.
+            return Ok(None);
+        };
+        let miniblock_number = MiniblockNumber(miniblock_number.as_u32());
+        let miniblock = storage
+            .blocks_dal()
+            .get_miniblock_header(miniblock_number)
+            .await
+            .ok_or_else(|| internal_error(METHOD_NAME, "miniblock header is missing"))?;
+        let mut preceding_txs = storage
+            .transactions_web3_dal()
+            .get_raw_miniblock_transactions(miniblock_number)
+            .await
+            .map_err(|err| internal_error(METHOD_NAME, err))?;
+        let tx_position = preceding_txs
+            .iter()
+            .position(|tx| tx.hash() == hash)
+            .ok_or_else(|| {
+                internal_error(METHOD_NAME, "transaction is missing in its miniblock")
+            })?;
+        let tx = preceding_txs.remove(tx_position);
+        preceding_txs.truncate(tx_position);
+
+        let fee_account = match receipt.l1_batch_number {
+            Some(l1_batch_number) => storage
+                .blocks_dal()
+                .get_block_header(L1BatchNumber(l1_batch_number.as_u32()))
+                .await
+                .map(|header| header.fee_account_address),
+            None => None,
+        };
+        let fee_account =
+            fee_account.unwrap_or(self.state.tx_sender.0.sender_config.fee_account_addr);
+        let base_system_contracts = storage
+            .storage_dal()
+            .get_base_system_contracts(
+                miniblock.base_system_contracts_hashes.bootloader,
+                miniblock.base_system_contracts_hashes.default_aa,
+            )
+            .await;
+        let recorded_writes = storage
+            .storage_web3_dal()
+            .get_transaction_writes(hash)
+            .await
+            .map_err(|err| internal_error(METHOD_NAME, err))?;
+        drop(storage);
+
+        let gas_limit = tx.gas_limit();
+        let shared_args = TxSharedArgs {
+            operator_account: AccountTreeId::new(fee_account),
+            l1_gas_price: miniblock.l1_gas_price,
+            fair_l2_gas_price: miniblock.l2_fair_gas_price,
+            base_system_contracts,
+            factory_deps_cache: self.state.tx_sender.0.factory_deps_cache.clone(),
+        };
+        let tx_sender = &self.state.tx_sender.0;
+        let vm_permit = tx_sender.vm_concurrency_limiter.acquire().await;
+        let result = replay_tx_in_sandbox(
+            &vm_permit,
+            shared_args,
+            self.state.connection_pool.clone(),
+            preceding_txs,
+            tx,
+            BlockArgs::for_replay(&miniblock),
+            miniblock.base_fee_per_gas,
+            tx_sender.sender_config.validation_computational_gas_limit,
+        )
+        .await
+        .map_err(|err| {
+            let submit_tx_error = SubmitTxError::from(err);
+            Web3Error::SubmitTransactionError(submit_tx_error.to_string(), submit_tx_error.data())
+        })?;
+        drop(vm_permit); // Unblock other VMs to enter.
+
+        let success = result.status == TxExecutionStatus::Success;
+        let gas_used = gas_limit - U256::from(result.gas_refunded);
+        let logs: Vec<_> = result
+            .result
+            .logs
+            .events
+            .into_iter()
+            .map(|event| ReplayedLog {
+                address: event.address,
+                topics: event.indexed_topics,
+                data: Bytes(event.value),
+            })
+            .collect();
+        let mut written_slots = HashMap::new();
+        for log in &result.result.logs.storage_logs {
+            if log.log_query.rw_flag {
+                let log = StorageLog::from_log_query(log);
+                written_slots.insert(log.key.hashed_key(), (log.key, log.value));
+            }
+        }
+        let mut state_diff: Vec<_> = written_slots
+            .into_iter()
+            .map(|(hashed_key, (key, value))| StorageSlotDiff {
+                address: *key.address(),
+                key: *key.key(),
+                hashed_key,
+                value,
+            })
+            .collect();
+        state_diff.sort_unstable_by_key(|diff| diff.hashed_key);
+
+        let mut discrepancies = vec![];
+        let recorded_success = receipt.status == Some(U64::one());
+        if success != recorded_success {
+            discrepancies.push(format!(
+                "status: recorded success = {recorded_success}, replayed success = {success}"
+            ));
+        }
+        if receipt.gas_used != Some(gas_used) {
+            discrepancies.push(format!(
+                "gas used: recorded {:?}, replayed {gas_used}",
+                receipt.gas_used
+            ));
+        }
+        let recorded_logs: Vec<_> = receipt
+            .logs
+            .into_iter()
+            .map(|log| ReplayedLog {
+                address: log.address,
+                topics: log.topics,
+                data: log.data,
+            })
+            .collect();
+        if recorded_logs.len() != logs.len() {
+            discrepancies.push(format!(
+                "logs: recorded {} logs, replayed {}",
+                recorded_logs.len(),
+                logs.len()
+            ));
+        } else if let Some(idx) = (0..logs.len()).find(|&i| recorded_logs[i] != logs[i]) {
+            discrepancies.push(format!("logs: log #{idx} differs"));
+        }
+        let recorded_writes: HashMap<_, _> = recorded_writes
+            .into_iter()
+            .map(|(key, value)| (key.hashed_key(), value))
+            .collect();
+        for diff in &state_diff {
+            match recorded_writes.get(&diff.hashed_key) {
+                Some(value) if *value == diff.value => {}
+                recorded => discrepancies.push(format!(
+                    "storage: slot {:?} of {:?} recorded as {recorded:?}, replayed as {:?}",
+                    diff.key, diff.address, diff.value
+                )),
+            }
+        }
+        let replayed_slots_count = state_diff.len();
+        if recorded_writes.len() > replayed_slots_count {
+            discrepancies.push(format!(
+                "storage: {} recorded writes, replayed {replayed_slots_count}",
+                recorded_writes.len()
+            ));
+        }
+
+        metrics::histogram!("api.web3.call", start.elapsed(), "method" => METHOD_NAME);
+        Ok(Some(TransactionReplay {
+            transaction_hash: hash,
+            miniblock_number,
+            success,
+            revert_reason: result.result.revert_reason.map(|reason| reason.to_string()),
+            gas_used,
+            logs,
+            state_diff,
+            discrepancies,
+        }))
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub fn get_connection_pool_stats_impl(&self) -> Result<ConnectionPoolStats, Web3Error> {
+        if !self.state.api_config.connection_pool_stats_enabled {
+            return Err(Web3Error::NotImplemented);
+        }
+        let stats = self.state.connection_pool.stats();
+        Ok(ConnectionPoolStats {
+            size: stats.size,
+            idle: stats.idle,
+            active: stats.size.saturating_sub(stats.idle),
+            acquired_count: stats.acquired_count,
+            mean_acquire_time_us: stats.mean_acquire_time.as_micros() as u64,
+            max_acquire_time_us: stats.max_acquire_time.as_micros() as u64,
+        })
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub async fn get_chain_stats_impl(&self) -> Result<ChainStats, Web3Error> {
+        const METHOD_NAME: &str = "get_chain_stats";
+
+        let start = Instant::now();
+        let refresh_interval =
+            Duration::from_secs(self.state.api_config.chain_stats_refresh_interval_sec);
+        // The lock only guards the cached value and isn't held during the scans, so that a slow refresh
+        // doesn't block other callers.
+        let cached = *self.state.chain_stats.lock().await;
+        if let Some((computed_at, stats)) = cached {
+            if computed_at.elapsed() < refresh_interval {
+                metrics::histogram!("api.web3.call", start.elapsed(), "method" => METHOD_NAME);
+                return Ok(stats);
+            }
+        }
+
+        let mut storage = self
+            .state
+            .connection_pool
+            .access_storage_tagged("api")
+            .await;
+        let mut blocks_web3_dal = storage.blocks_web3_dal();
+        let sealed_l1_batch_number = blocks_web3_dal
+            .get_sealed_l1_batch_number()
+            .await
+            .map_err(|err| internal_error(METHOD_NAME, err))?;
+        let sealed_miniblock_number = blocks_web3_dal
+            .get_sealed_miniblock_number()
+            .await
+            .map_err(|err| internal_error(METHOD_NAME, err))?;
+        let (transaction_count, account_count) = blocks_web3_dal
+            .get_transaction_and_account_counts()
+            .await
+            .map_err(|err| internal_error(METHOD_NAME, err))?;
+        let stats = ChainStats {
+            l1_batch_count: u64::from(sealed_l1_batch_number.0) + 1,
+            miniblock_count: u64::from(sealed_miniblock_number.0) + 1,
+            transaction_count,
+            account_count,
+            updated_at: seconds_since_epoch(),
+        };
+        *self.state.chain_stats.lock().await = Some((Instant::now(), stats));
+
+        metrics::histogram!("api.web3.call", start.elapsed(), "method" => METHOD_NAME);
+        Ok(stats)
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub async fn get_recent_batch_events_impl(
+        &self,
+        limit: u32,
+    ) -> Result<Vec<L1BatchEvent>, Web3Error> {
+        const METHOD_NAME: &str = "get_recent_batch_events";
+
+        check_limit(limit, self.state.api_config.req_entities_limit as u32)?;
+        let start = Instant::now();
+        let events = self
+            .state
+            .connection_pool
+            .access_storage_tagged("api")
+            .await
+            .blocks_web3_dal()
+            .get_recent_l1_batch_events(limit as usize)
+            .await
+            .map_err(|err| internal_error(METHOD_NAME, err));
+
+        metrics::histogram!("api.web3.call", start.elapsed(), "method" => METHOD_NAME);
+        events
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub async fn get_l1_batch_stats_impl(
+        &self,
+        batch: L1BatchNumber,
+    ) -> Result<Option<L1BatchStats>, Web3Error> {
+        const METHOD_NAME: &str = "get_l1_batch_stats";
+
+        let start = Instant::now();
+        let mut storage = self
+            .state
+            .connection_pool
+            .access_storage_tagged("api")
+            .await;
+        let last_sealed_l1_batch = storage
+            .blocks_web3_dal()
+            .get_sealed_l1_batch_number()
+            .await
+            .map_err(|err| internal_error(METHOD_NAME, err))?;
+        if batch > last_sealed_l1_batch {
+            return Ok(None);
+        }
+        let Some(mut stats) = storage
+            .blocks_web3_dal()
+            .get_l1_batch_stats(batch)
+            .await
+            .map_err(|err| internal_error(METHOD_NAME, err))?
+        else {
+            return Ok(None);
+        };
+        stats.pubdata_bytes = storage
+            .blocks_dal()
+            .get_block_metadata(batch)
+            .await
+            .map(|block| block.pubdata_size() as u64);
+
+        metrics::histogram!("api.web3.call", start.elapsed(), "method" => METHOD_NAME);
+        Ok(Some(stats))
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub async fn get_l1_batch_details_impl(
+        &self,
+        batch_number: L1BatchNumber,
+    ) -> Result<Option<L1BatchDetails>, Web3Error> {
+        const METHOD_NAME: &str = "get_l1_batch";
+
+        let start = Instant::now();
+        let l1_batch = self
+            .state
+            .connection_pool
+            .access_storage_tagged("api")
+            .await
+            .explorer()
+            .blocks_dal()
+            .get_l1_batch_details(batch_number)
+            .await
+            .map_err(|err| internal_error(METHOD_NAME, err));
+
+        metrics::histogram!("api.web3.call", start.elapsed(), "method" => METHOD_NAME);
+        l1_batch
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub async fn get_latest_l1_batch_details_impl(
+        &self,
+        stage: Option<L1BatchStage>,
+    ) -> Result<Option<L1BatchDetails>, Web3Error> {
+        const METHOD_NAME: &str = "get_latest_l1_batch_details";
+
+        let start = Instant::now();
+        let mut storage = self
+            .state
+            .connection_pool
+            .access_storage_tagged("api")
+            .await;
+        let batch_number = match stage.unwrap_or_default() {
+            L1BatchStage::Sealed => Some(
+                storage
+                    .blocks_web3_dal()
+                    .get_sealed_l1_batch_number()
+                    .await
+                    .map_err(|err| internal_error(METHOD_NAME, err))?,
+            ),
+            L1BatchStage::Committed => {
+                storage
+                    .blocks_dal()
+                    .get_number_of_last_block_committed_on_eth()
+                    .await
+            }
+            L1BatchStage::Proven => {
+                storage
+                    .blocks_dal()
+                    .get_number_of_last_block_proven_on_eth()
+                    .await
+            }
+            L1BatchStage::Executed => {
+                storage
+                    .blocks_dal()
+                    .get_number_of_last_block_executed_on_eth()
+                    .await
+            }
+        };
+        let Some(batch_number) = batch_number else {
+            metrics::histogram!("api.web3.call", start.elapsed(), "method" => METHOD_NAME);
+            return Ok(None);
+        };
+
+        let l1_batch = storage
+            .explorer()
+            .blocks_dal()
+            .get_l1_batch_details(batch_number)
+            .await
+            .map_err(|err| internal_error(METHOD_NAME, err));
+
+        metrics::histogram!("api.web3.call", start.elapsed(), "method" => METHOD_NAME);
+        l1_batch
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub async fn get_batch_l1_commit_data_impl(
+        &self,
+        batch_number: L1BatchNumber,
+    ) -> Result<Option<BatchL1CommitData>, Web3Error> {
+        const METHOD_NAME: &str = "get_batch_l1_commit_data";
+
+        if !self.state.api_config.l1_commit_data_enabled {
+            return Err(Web3Error::NotImplemented);
+        }
+
+        let start = Instant::now();
+        let mut storage = self
+            .state
+            .connection_pool
+            .access_storage_tagged("api")
+            .await;
+        let commit_tx_hash = storage
+            .explorer()
+            .blocks_dal()
+            .get_l1_batch_details(batch_number)
+            .await
+            .map_err(|err| internal_error(METHOD_NAME, err))?
+            .and_then(|details| details.commit_tx_hash);
+        let Some(commit_tx_hash) = commit_tx_hash else {
+            metrics::histogram!("api.web3.call", start.elapsed(), "method" => METHOD_NAME);
+            return Ok(None);
+        };
+
+        // Committed batches always have metadata, so `None` here means that the batch was reverted.
+        let commit_data = storage
+            .blocks_dal()
+            .get_block_metadata(batch_number)
+            .await
+            .map(|block| BatchL1CommitData {
+                l1_batch_number: batch_number,
+                commit_tx_hash,
+                commit_data: Bytes(ethabi::encode(&[block.l1_commit_data()])),
+            });
+
+        metrics::histogram!("api.web3.call", start.elapsed(), "method" => METHOD_NAME);
+        Ok(commit_data)
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub async fn get_batch_commitment_inputs_impl(
+        &self,
+        batch_number: L1BatchNumber,
+    ) -> Result<Option<BatchCommitmentInputs>, Web3Error> {
+        const METHOD_NAME: &str = "get_batch_commitment_inputs";
+
+        if !self.state.api_config.commitment_inputs_enabled {
+            return Err(Web3Error::NotImplemented);
+        }
+
+        let start = Instant::now();
+        let mut storage = self
+            .state
+            .connection_pool
+            .access_storage_tagged("api")
+            .await;
+        let inputs = storage
+            .blocks_dal()
+            .get_block_metadata(batch_number)
+            .await
+            .map(|block| {
+                let metadata = block.metadata;
+                let hash_of = |data: &[u8]| H256(keccak256(data));
+                BatchCommitmentInputs {
+                    l1_batch_number: batch_number,
+                    commitment: metadata.commitment,
+                    pass_through_data_hash: metadata.pass_through_data_hash,
+                    meta_parameters_hash: metadata.meta_parameters_hash,
+                    aux_data_hash: metadata.aux_data_hash,
+                    root_hash: metadata.root_hash,
+                    rollup_last_leaf_index: metadata.rollup_last_leaf_index,
+                    zkporter_is_available: metadata.block_meta_params.zkporter_is_available,
+                    bootloader_code_hash: metadata.block_meta_params.bootloader_code_hash,
+                    default_aa_code_hash: metadata.block_meta_params.default_aa_code_hash,
+                    l2_l1_logs_linear_hash: hash_of(&metadata.l2_l1_messages_compressed),
+                    l2_l1_logs: Bytes(metadata.l2_l1_messages_compressed),
+                    l2_l1_logs_merkle_root: metadata.l2_l1_merkle_root,
+                    initial_writes_hash: hash_of(&metadata.initial_writes_compressed),
+                    initial_writes: Bytes(metadata.initial_writes_compressed),
+                    repeated_writes_hash: hash_of(&metadata.repeated_writes_compressed),
+                    repeated_writes: Bytes(metadata.repeated_writes_compressed),
+                }
+            });
+
+        metrics::histogram!("api.web3.call", start.elapsed(), "method" => METHOD_NAME);
+        Ok(inputs)
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub async fn get_l1_batch_fees_impl(
+        &self,
+        batch_number: L1BatchNumber,
+    ) -> Result<Option<L1BatchFees>, Web3Error> {
+        const METHOD_NAME: &str = "get_l1_batch_fees";
+
+        let start = Instant::now();
+        let fees = self
+            .state
+            .connection_pool
+            .access_storage_tagged("api")
+            .await
+            .blocks_web3_dal()
+            .get_l1_batch_fees(batch_number)
+            .await
+            .map_err(|err| internal_error(METHOD_NAME, err));
+
+        metrics::histogram!("api.web3.call", start.elapsed(), "method" => METHOD_NAME);
+        fees
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub async fn get_effective_l1_gas_price_for_batch_impl(
+        &self,
+        batch_number: L1BatchNumber,
+    ) -> Option<U64> {
+        const METHOD_NAME: &str = "get_effective_l1_gas_price_for_batch";
+
+        let start = Instant::now();
+        let l1_gas_price = self
+            .state
+            .connection_pool
+            .access_storage_tagged("api")
+            .await
+            .blocks_dal()
+            .get_block_header(batch_number)
+            .await
+            .map(|header| header.l1_gas_price.into());
+
+        metrics::histogram!("api.web3.call", start.elapsed(), "method" => METHOD_NAME);
+        l1_gas_price
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub async fn get_bytecode_by_hash_impl(&self, hash: H256) -> Option<Vec<u8>> {
+        const METHOD_NAME: &str = "get_bytecode_by_hash";
+
+        let start = Instant::now();
+        let bytecode = self
+            .state
+            .connection_pool
+            .access_storage_tagged("api")
+            .await
+            .storage_dal()
+            .get_factory_dep(hash)
+            .await;
+
+        metrics::histogram!("api.web3.call", start.elapsed(), "method" => METHOD_NAME);
+        bytecode
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub async fn get_bytecode_details_by_hash_impl(
+        &self,
+        hash: H256,
+    ) -> Result<Option<BytecodeDetails>, Web3Error> {
+        const METHOD_NAME: &str = "get_bytecode_details_by_hash";
+
+        let start = Instant::now();
+        let mut storage = self
+            .state
+            .connection_pool
+            .access_storage_tagged("api")
+            .await;
+        let (bytecode, first_seen_miniblock) = match storage
+            .storage_web3_dal()
+            .get_factory_dep_with_miniblock(hash)
+            .await
+            .map_err(|err| internal_error(METHOD_NAME, err))?
+        {
+            Some(dep) => dep,
+            None => return Ok(None),
+        };
+        let known = storage
+            .storage_dal()
+            .get_by_key(&get_known_code_key(&hash))
+            .await
+            .map_or(false, |value| !value.is_zero());
+
+        metrics::histogram!("api.web3.call", start.elapsed(), "method" => METHOD_NAME);
+        Ok(Some(BytecodeDetails {
+            length_in_words: (bytecode.len() / 32) as u32,
+            bytecode: bytecode.into(),
+            known,
+            first_seen_miniblock,
+        }))
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub async fn get_contract_creation_code_impl(
+        &self,
+        address: Address,
+    ) -> Result<Option<ContractCreationCode>, Web3Error> {
+        const METHOD_NAME: &str = "get_contract_creation_code";
+
+        let start = Instant::now();
+        let creation_code = self
+            .state
+            .connection_pool
+            .access_storage_tagged("api")
+            .await
+            .explorer()
+            .contract_verification_dal()
+            .get_contract_info_for_verification(address)
+            .await
+            .map_err(|err| internal_error(METHOD_NAME, err))?
+            .map(|(bytecode, calldata)| {
+                let constructor_args = match calldata {
+                    DeployContractCalldata::Deploy(calldata) => {
+                        decode_constructor_args(&calldata, address)
+                    }
+                    DeployContractCalldata::Ignore => None,
+                };
+                ContractCreationCode {
+                    bytecode: Bytes(bytecode),
+                    constructor_args: constructor_args.map(Bytes),
+                }
+            });
+
+        metrics::histogram!("api.web3.call", start.elapsed(), "method" => METHOD_NAME);
+        Ok(creation_code)
+    }
+
+    /// Returns the L1 gas price in wei as `U64`. Kept for backward compatibility only,
+    /// since the return type cannot represent prices above `u64::MAX` wei.
+    #[tracing::instrument(skip(self))]
+    pub fn get_l1_gas_price_impl(&self) -> U64 {
+        const METHOD_NAME: &str = "get_l1_gas_price";
+
+        let start = Instant::now();
+        let gas_price = self
+            .state
+            .tx_sender
+            .0
+            .l1_gas_price_source
+            .estimate_effective_gas_price();
+
+        metrics::histogram!("api.web3.call", start.elapsed(), "method" => METHOD_NAME);
+        gas_price.into()
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub async fn get_l1_gas_price_history_impl(
+        &self,
+        from_miniblock: MiniblockNumber,
+        to_miniblock: MiniblockNumber,
+    ) -> Result<Vec<(MiniblockNumber, U64)>, Web3Error> {
+        const METHOD_NAME: &str = "get_l1_gas_price_history";
+
+        let start = Instant::now();
+        check_range_limit(
+            from_miniblock.0,
+            to_miniblock.0,
+            self.state.api_config.max_l1_gas_price_history_range,
+        )?;
+
+        let history = self
+            .state
+            .connection_pool
+            .access_storage_tagged("api")
+            .await
+            .blocks_web3_dal()
+            .get_l1_gas_price_history(from_miniblock, to_miniblock)
+            .await
+            .map_err(|err| internal_error(METHOD_NAME, err))?
+            .into_iter()
+            .map(|(miniblock, gas_price)| (miniblock, gas_price.into()))
+            .collect();
+
+        metrics::histogram!("api.web3.call", start.elapsed(), "method" => METHOD_NAME);
+        Ok(history)
+    }
+
+    /// Returns the L1 gas price in wei.
+    #[tracing::instrument(skip(self))]
+    pub fn get_l1_gas_price_wei_impl(&self) -> U256 {
+        const METHOD_NAME: &str = "get_l1_gas_price_wei";
+
+        let start = Instant::now();
+        let gas_price = self
+            .state
+            .tx_sender
+            .0
+            .l1_gas_price_source
+            .estimate_effective_gas_price();
+
+        metrics::histogram!("api.web3.call", start.elapsed(), "method" => METHOD_NAME);
+        gas_price.into()
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub fn get_fee_params_impl(&self) -> FeeModelParams {
+        const METHOD_NAME: &str = "get_fee_params";
+
+        let start = Instant::now();
+        let tx_sender = &self.state.tx_sender.0;
+        let gas_price_scale_factor = tx_sender.sender_config.gas_price_scale_factor;
+        let l1_gas_price = tx_sender.l1_gas_price_source.estimate_effective_gas_price();
+        // Scaled in the same way as during fee estimation, so that deriving the base fee and gas per pubdata
+        // from the returned params yields the same values as `zks_estimateFee`.
+        let l1_gas_price = (l1_gas_price as f64 * gas_price_scale_factor) as u64;
+        let fee_params = FeeModelParams {
+            l1_gas_price: l1_gas_price.into(),
+            fair_l2_gas_price: tx_sender.sender_config.fair_l2_gas_price.into(),
+            gas_price_scale_factor,
+            pubdata_pricing_mode: PubdataPricingMode::Rollup,
+            l1_gas_per_pubdata_byte: L1_GAS_PER_PUBDATA_BYTE,
+            batch_overhead_l2_gas: BLOCK_OVERHEAD_GAS,
+            batch_overhead_l1_gas: BLOCK_OVERHEAD_L1_GAS,
+            batch_overhead_pubdata: BLOCK_OVERHEAD_PUBDATA,
+        };
+
+        metrics::histogram!("api.web3.call", start.elapsed(), "method" => METHOD_NAME);
+        fee_params
+    }
+
+    #[tracing::instrument(skip(self, keys))]
+    pub async fn get_storage_at_batch_impl(
+        &self,
+        address: Address,
+        keys: Vec<H256>,
+        block: Option<MiniblockNumber>,
+    ) -> Result<HashMap<H256, H256>, Web3Error> {
+        const METHOD_NAME: &str = "get_storage_at_batch";
+
+        check_limit(
+            keys.len(),
+            self.state.api_config.max_storage_keys_per_request,
+        )?;
+
+        let start = Instant::now();
+        let mut storage = self
+            .state
+            .connection_pool
+            .access_storage_tagged("api")
+            .await;
+        let block_id = match block {
+            Some(number) => api::BlockId::Number(api::BlockNumber::Number(number.0.into())),
+            None => api::BlockId::Number(api::BlockNumber::Latest),
+        };
+        let block_number = resolve_block(&mut storage, block_id, METHOD_NAME).await?;
+
+        let storage_keys: Vec<_> = keys
+            .iter()
+            .map(|&key| StorageKey::new(AccountTreeId::new(address), key))
+            .collect();
+        let hashed_keys: Vec<_> = storage_keys.iter().map(StorageKey::hashed_key).collect();
+        let values = storage
+            .storage_web3_dal()
+            .get_historical_values_unchecked(&hashed_keys, block_number)
+            .await
+            .map_err(|err| internal_error(METHOD_NAME, err))?;
+        let result = storage_keys
+            .iter()
+            .zip(hashed_keys)
+            .map(|(key, hashed_key)| {
+                let value = values.get(&hashed_key).copied().unwrap_or_default();
+                (*key.key(), value)
+            })
+            .collect();
+
+        metrics::histogram!("api.web3.call", start.elapsed(), "method" => METHOD_NAME);
+        Ok(result)
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub async fn get_account_storage_keys_impl(
+        &self,
+        address: Address,
+        block: Option<MiniblockNumber>,
+        cursor: Option<H256>,
+        limit: Option<u32>,
+    ) -> Result<AccountStorageKeysPage, Web3Error> {
+        const METHOD_NAME: &str = "get_account_storage_keys";
+
+        let max_page_size = self.state.api_config.max_account_storage_keys_page_size;
+        let limit = limit.unwrap_or(max_page_size);
+        check_limit(limit, max_page_size)?;
+
+        let start = Instant::now();
+        let mut storage = self
+            .state
+            .connection_pool
+            .access_storage_tagged("api")
+            .await;
+        let block_id = match block {
+            Some(number) => api::BlockId::Number(api::BlockNumber::Number(number.0.into())),
+            None => api::BlockId::Number(api::BlockNumber::Latest),
+        };
+        let block_number = resolve_block(&mut storage, block_id, METHOD_NAME).await?;
+
+        let keys = storage
+            .storage_web3_dal()
+            .get_account_storage_keys(address, block_number, cursor, limit as usize)
+            .await
+            .map_err(|err| internal_error(METHOD_NAME, err))?;
+        let next_cursor = if limit > 0 && keys.len() == limit as usize {
+            keys.last().copied()
+        } else {
+            None
+        };
+
+        metrics::histogram!("api.web3.call", start.elapsed(), "method" => METHOD_NAME);
+        Ok(AccountStorageKeysPage { keys, next_cursor })
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub async fn get_nonce_at_impl(
+        &self,
+        address: Address,
+        block_or_batch: BlockOrBatchNumber,
+    ) -> Result<U256, Web3Error> {
+        const METHOD_NAME: &str = "get_nonce_at";
+
+        let start = Instant::now();
+        let mut storage = self
+            .state
+            .connection_pool
+            .access_storage_tagged("api")
+            .await;
+        let block_number = match block_or_batch {
+            BlockOrBatchNumber::Miniblock(number) => {
+                let block_id = api::BlockId::Number(api::BlockNumber::Number(number.0.into()));
+                resolve_block(&mut storage, block_id, METHOD_NAME).await?
+            }
+            BlockOrBatchNumber::L1Batch(number) => {
+                // Miniblocks of the pending batch are not assigned to it yet,
+                // so only sealed batches can be resolved.
+                let (_, last_miniblock) = storage
+                    .blocks_web3_dal()
+                    .get_miniblock_range_of_l1_batch(number)
+                    .await
+                    .map_err(|err| internal_error(METHOD_NAME, err))?
+                    .ok_or(Web3Error::NoBlock)?;
+                last_miniblock
+            }
+        };
+        let nonce = storage
+            .storage_web3_dal()
+            .get_address_historical_nonce(address, block_number)
+            .await
+            .map_err(|err| internal_error(METHOD_NAME, err));
+
+        metrics::histogram!("api.web3.call", start.elapsed(), "method" => METHOD_NAME);
+        nonce
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub async fn get_transaction_confirmations_needed_impl(
+        &self,
+        hash: H256,
+    ) -> Result<Option<TransactionConfirmationsNeeded>, Web3Error> {
+        const METHOD_NAME: &str = "get_transaction_confirmations_needed";
+
+        let start = Instant::now();
+        let mut storage = self
+            .state
+            .connection_pool
+            .access_storage_tagged("api")
+            .await;
+        let (miniblock_number, l1_batch_number) = match storage
+            .transactions_web3_dal()
+            .get_transaction_inclusion(hash)
+            .await
+            .map_err(|err| internal_error(METHOD_NAME, err))?
+        {
+            Some(inclusion) => inclusion,
+            None => return Ok(None),
+        };
+
+        let soft_confirmation_blocks = self.state.api_config.soft_confirmation_blocks;
+        let blocks_to_soft_confirmation = match miniblock_number {
+            Some(miniblock_number) => {
+                let sealed_miniblock_number = storage
+                    .blocks_web3_dal()
+                    .get_sealed_miniblock_number()
+                    .await
+                    .map_err(|err| internal_error(METHOD_NAME, err))?;
+                (miniblock_number.0 + soft_confirmation_blocks)
+                    .saturating_sub(sealed_miniblock_number.0)
+            }
+            // The transaction has to be included into a miniblock first.
+            None => soft_confirmation_blocks + 1,
+        };
+
+        // Transactions that are not in a sealed batch yet will end up in the pending one.
+        let l1_batch_number = match l1_batch_number {
+            Some(number) => number,
+            None => {
+                storage
+                    .blocks_web3_dal()
+                    .get_sealed_l1_batch_number()
+                    .await
+                    .map_err(|err| internal_error(METHOD_NAME, err))?
+                    + 1
+            }
+        };
+        let last_executed_l1_batch = storage
+            .blocks_dal()
+            .get_number_of_last_block_executed_on_eth()
+            .await
+            .unwrap_or(L1BatchNumber(0));
+        let batches_to_finality = l1_batch_number.0.saturating_sub(last_executed_l1_batch.0);
+
+        metrics::histogram!("api.web3.call", start.elapsed(), "method" => METHOD_NAME);
+        Ok(Some(TransactionConfirmationsNeeded {
+            blocks_to_soft_confirmation,
+            batches_to_finality,
+        }))
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub async fn get_batch_diff_impl(
+        &self,
+        from_batch: L1BatchNumber,
+        to_batch: L1BatchNumber,
+        after: Option<H256>,
+    ) -> Result<BatchDiff, Web3Error> {
+        const METHOD_NAME: &str = "get_batch_diff";
+
+        let start = Instant::now();
+        // Changes are diffed for batches in the `(from_batch, to_batch]` range, so `from_batch == to_batch`
+        // is a valid (empty) diff.
+        if from_batch > to_batch {
+            return Err(Web3Error::InvalidRange {
+                from: from_batch.0,
+                to: to_batch.0,
+            });
+        }
+        check_limit(
+            to_batch.0 - from_batch.0,
+            self.state.api_config.max_batch_diff_range,
+        )?;
+
+        let mut storage = self
+            .state
+            .connection_pool
+            .access_storage_tagged("api")
+            .await;
+        let mut last_miniblocks = Vec::with_capacity(2);
+        for batch in [from_batch, to_batch] {
+            let (_, last_miniblock) = storage
+                .blocks_web3_dal()
+                .get_miniblock_range_of_l1_batch(batch)
+                .await
+                .map_err(|err| internal_error(METHOD_NAME, err))?
+                .ok_or(Web3Error::NoBlock)?;
+            last_miniblocks.push(last_miniblock);
+        }
+
+        let limit = self.state.api_config.req_entities_limit;
+        let changes: Vec<_> = if from_batch < to_batch {
+            storage
+                .storage_web3_dal()
+                .get_modified_slots(last_miniblocks[0] + 1, last_miniblocks[1], after, limit)
+                .await
+                .map_err(|err| internal_error(METHOD_NAME, err))?
+                .into_iter()
+                .map(|(key, value)| StorageSlotDiff {
+                    address: *key.address(),
+                    key: *key.key(),
+                    hashed_key: key.hashed_key(),
+                    value,
+                })
+                .collect()
+        } else {
+            vec![]
+        };
+        let next_after = if changes.len() == limit {
+            changes.last().map(|change| change.hashed_key)
+        } else {
+            None
+        };
+
+        metrics::histogram!("api.web3.call", start.elapsed(), "method" => METHOD_NAME);
+        Ok(BatchDiff {
+            from_batch,
+            to_batch,
+            changes,
+            next_after,
+        })
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub async fn get_transactions_in_batch_impl(
+        &self,
+        batch: L1BatchNumber,
+        cursor: Option<u32>,
+        limit: Option<u32>,
+    ) -> Result<BatchTransactionsPage, Web3Error> {
+        const METHOD_NAME: &str = "get_transactions_in_batch";
+
+        let start = Instant::now();
+        let max_page_size = self.state.api_config.max_batch_transactions_page_size;
+        let limit = limit.unwrap_or(max_page_size);
+        check_limit(limit, max_page_size)?;
+
+        let transactions = self
+            .state
+            .connection_pool
+            .access_storage_tagged("api")
+            .await
+            .transactions_web3_dal()
+            .get_l1_batch_transactions_page(
+                batch,
+                cursor.unwrap_or(0),
+                limit,
+                self.state.api_config.l2_chain_id,
+            )
+            .await
+            .map_err(|err| internal_error(METHOD_NAME, err))?;
+        let next_cursor = if limit > 0 && transactions.len() == limit as usize {
+            transactions
+                .last()
+                .and_then(|tx| tx.l1_batch_tx_index)
+                .map(|index| index.as_u32() + 1)
+        } else {
+            None
+        };
+
+        metrics::histogram!("api.web3.call", start.elapsed(), "method" => METHOD_NAME);
+        Ok(BatchTransactionsPage {
+            l1_batch_number: batch,
+            transactions,
+            next_cursor,
+        })
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub async fn get_batch_proving_status_impl(
+        &self,
+        batch: L1BatchNumber,
+    ) -> Result<Option<L1BatchProvingStatus>, Web3Error> {
+        const METHOD_NAME: &str = "get_batch_proving_status";
+
+        let start = Instant::now();
+        let mut storage = self
+            .state
+            .connection_pool
+            .access_storage_tagged("api")
+            .await;
+        let sealed_l1_batch_number = storage
+            .blocks_web3_dal()
+            .get_sealed_l1_batch_number()
+            .await
+            .map_err(|err| internal_error(METHOD_NAME, err))?;
+        let last_proven_l1_batch_number = storage
+            .blocks_dal()
+            .get_number_of_last_block_proven_on_eth()
+            .await;
+        if batch > sealed_l1_batch_number || Some(batch) <= last_proven_l1_batch_number {
+            return Ok(None);
+        }
+
+        let stats = storage
+            .prover_dal()
+            .get_prover_jobs_stats_for_l1_batch(batch)
+            .await;
+
+        metrics::histogram!("api.web3.call", start.elapsed(), "method" => METHOD_NAME);
+        Ok(Some(L1BatchProvingStatus {
+            l1_batch_number: batch,
+            completed_jobs: stats.successful as u32,
+            pending_jobs: (stats.queued + stats.in_progress) as u32,
+            failed_jobs: stats.failed as u32,
+        }))
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub async fn get_miniblock_by_timestamp_impl(
+        &self,
+        timestamp: u64,
+        rounding: TimestampRounding,
+    ) -> Result<Option<MiniblockNumber>, Web3Error> {
+        const METHOD_NAME: &str = "get_miniblock_by_timestamp";
+
+        let start = Instant::now();
+        let miniblock_number = self
+            .state
+            .connection_pool
+            .access_storage_tagged("api")
+            .await
+            .blocks_web3_dal()
+            .get_miniblock_by_timestamp(timestamp, rounding)
+            .await
+            .map_err(|err| internal_error(METHOD_NAME, err));
+
+        metrics::histogram!("api.web3.call", start.elapsed(), "method" => METHOD_NAME);
+        miniblock_number
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub async fn get_address_activity_impl(
+        &self,
+        address: Address,
+    ) -> Result<AddressActivity, Web3Error> {
+        const METHOD_NAME: &str = "get_address_activity";
+
+        let start = Instant::now();
+        let activity = self
+            .state
+            .connection_pool
+            .access_storage_tagged("api")
+            .await
+            .explorer()
+            .accounts_dal()
+            .get_address_activity(address)
+            .await
+            .map_err(|err| internal_error(METHOD_NAME, err));
+
+        metrics::histogram!("api.web3.call", start.elapsed(), "method" => METHOD_NAME);
+        activity
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub async fn get_address_transaction_count_impl(
+        &self,
+        address: Address,
+    ) -> Result<AddressTransactionCount, Web3Error> {
+        const METHOD_NAME: &str = "get_address_transaction_count";
+
+        let start = Instant::now();
+        let count = self
+            .state
+            .connection_pool
+            .access_storage_tagged("api")
+            .await
+            .explorer()
+            .accounts_dal()
+            .get_address_transaction_count(address)
+            .await
+            .map_err(|err| internal_error(METHOD_NAME, err));
+
+        metrics::histogram!("api.web3.call", start.elapsed(), "method" => METHOD_NAME);
+        count
+    }
+
+    /// Returns the hash of the verification key `batch` was proven with, or the one the node currently
+    /// proves batches with if `batch` is not specified. Errors if `batch` is not proven yet; `None` is returned
+    /// if the hash is not known (e.g., it wasn't recorded for the batch or isn't configured).
+    #[tracing::instrument(skip(self))]
+    pub async fn get_verification_key_hash_impl(
+        &self,
+        batch: Option<L1BatchNumber>,
+    ) -> Result<Option<H256>, Web3Error> {
+        const METHOD_NAME: &str = "get_verification_key_hash";
+
+        let start = Instant::now();
+        let vk_hash = if let Some(batch) = batch {
+            self.state
+                .connection_pool
+                .access_storage_tagged("api")
+                .await
+                .blocks_web3_dal()
+                .get_l1_batch_verification_key_hash(batch)
+                .await
+                .map_err(|err| internal_error(METHOD_NAME, err))?
+                .ok_or(Web3Error::L1BatchNotProven(batch))?
+        } else {
+            self.state.api_config.scheduler_vk_hash
+        };
+
+        metrics::histogram!("api.web3.call", start.elapsed(), "method" => METHOD_NAME);
+        Ok(vk_hash)
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub async fn get_protocol_version_impl(
+        &self,
+        version_id: Option<u16>,
+    ) -> Result<Option<ProtocolVersion>, Web3Error> {
+        const METHOD_NAME: &str = "get_protocol_version";
+
+        let start = Instant::now();
+        let mut storage = self
+            .state
+            .connection_pool
+            .access_storage_tagged("api")
+            .await;
+        let latest_version_id = storage
+            .blocks_web3_dal()
+            .get_latest_protocol_version()
+            .await
+            .map_err(|err| internal_error(METHOD_NAME, err))?;
+        let Some(version_id) = version_id.or(latest_version_id) else {
+            return Ok(None);
+        };
+        let activation = storage
+            .blocks_web3_dal()
+            .get_protocol_version_activation(version_id)
+            .await
+            .map_err(|err| internal_error(METHOD_NAME, err))?;
+        let Some((timestamp, base_system_contracts)) = activation else {
+            return Ok(None);
+        };
+        // The node only knows the verification key it currently proves batches with.
+        let verification_keys_hash = if Some(version_id) == latest_version_id {
+            self.state.api_config.scheduler_vk_hash
+        } else {
+            None
+        };
+
+        metrics::histogram!("api.web3.call", start.elapsed(), "method" => METHOD_NAME);
+        Ok(Some(ProtocolVersion {
+            version_id,
+            timestamp,
+            verification_keys_hash,
+            base_system_contracts,
+        }))
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub async fn get_contract_deployment_bytecode_hash_impl(
+        &self,
+        address: Address,
+        block: Option<api::BlockId>,
+    ) -> Result<Option<H256>, Web3Error> {
+        const METHOD_NAME: &str = "get_contract_deployment_bytecode_hash";
+
+        let start = Instant::now();
+        let mut storage = self
+            .state
+            .connection_pool
+            .access_storage_tagged("api")
+            .await;
+        let block = block.unwrap_or(api::BlockId::Number(api::BlockNumber::Pending));
+        let block_number = resolve_block(&mut storage, block, METHOD_NAME).await?;
+        let bytecode_hash = storage
+            .storage_web3_dal()
+            .get_contract_bytecode_hash_unchecked(address, block_number)
+            .await
+            .map_err(|err| internal_error(METHOD_NAME, err));
+
+        metrics::histogram!("api.web3.call", start.elapsed(), "method" => METHOD_NAME);
+        bytecode_hash
+    }
+
+    /// Saves contract bytecode to memory. Only available if unsafe admin methods are enabled;
+    /// the bytecode must be well-formed so that its code hash can be computed.
+    #[tracing::instrument(skip(self, bytecode))]
+    pub fn set_known_bytecode_impl(&self, bytecode: Bytes) -> Result<bool, Web3Error> {
+        if !self.unsafe_admin_enabled {
+            return Err(Web3Error::NotImplemented);
+        }
+        validate_bytecode(&bytecode.0)
+            .map_err(|err| Web3Error::InvalidBytecode(err.to_string()))?;
+
+        let mut lock = self.state.known_bytecodes.write().unwrap();
+        lock.insert(bytecode.0);
+        Ok(true)
+    }
+
+    /// Same as `eth_sendRawTransaction`, but reports rejected transactions with a structured
+    /// [`TransactionRejection`] instead of a flattened error message.
+    #[tracing::instrument(skip(self, tx_bytes))]
+    pub async fn send_raw_transaction_with_detailed_error_impl(
+        &self,
+        tx_bytes: Bytes,
+    ) -> Result<H256, Web3Error> {
+        const METHOD_NAME: &str = "send_raw_transaction_with_detailed_error";
+
+        let start = Instant::now();
+        let (mut tx, hash) = self
+            .state
+            .parse_transaction_bytes(&tx_bytes.0)
+            .map_err(|err| {
+                Web3Error::TransactionRejected(TransactionRejection {
+                    reason: rejection_reason(&err),
+                    message: err.to_string(),
+                    revert_data: None,
+                })
+            })?;
+        tx.set_input(tx_bytes.0, hash);
+
+        let submit_result = self.state.tx_sender.submit_tx(tx).await;
+        let submit_result = submit_result.map(|_| hash).map_err(|err| {
+            vlog::debug!("Send raw transaction error: {err}");
+            metrics::counter!(
+                "api.submit_tx_error",
+                1,
+                "reason" => err.grafana_error_code()
+            );
+            let revert_data = err.data();
+            Web3Error::TransactionRejected(TransactionRejection {
+                reason: err.rejection_reason(),
+                message: err.to_string(),
+                revert_data: (!revert_data.is_empty()).then(|| revert_data.into()),
+            })
+        });
+
+        metrics::histogram!("api.web3.call", start.elapsed(), "method" => METHOD_NAME);
+        submit_result
+    }
+}
+
+/// Maps errors occurring before a transaction is submitted to the rejection reason.
+fn rejection_reason(err: &Web3Error) -> TransactionRejectionReason {
+    match err {
+        Web3Error::RLPError(_)
+        | Web3Error::InvalidTransactionData(_)
+        | Web3Error::SerializationError(_)
+        | Web3Error::InvalidBytecode(_)
+        | Web3Error::NoSuchFunction => TransactionRejectionReason::MalformedTransaction,
+        Web3Error::InvalidFeeParams(_) => TransactionRejectionReason::IncorrectTransaction,
+        Web3Error::PubdataLimitExceeded { .. } => TransactionRejectionReason::PubdataLimitExceeded,
+        Web3Error::SubmitTransactionError(_, data) if !data.is_empty() => {
+            TransactionRejectionReason::ExecutionReverted
+        }
+        Web3Error::SubmitTransactionError(_, _) => TransactionRejectionReason::Unexecutable,
+        Web3Error::TransactionRejected(rejection) => rejection.reason,
+        Web3Error::NoBlock
+        | Web3Error::RequestTimeout
+        | Web3Error::InternalError
+        | Web3Error::TooManyTopics
+        | Web3Error::PubSubTimeout
+        | Web3Error::FilterNotFound
+        | Web3Error::NotImplemented
+        | Web3Error::LogsLimitExceeded(_, _, _)
+        | Web3Error::InvalidFilterBlockHash
+        | Web3Error::RangeTooLarge { .. }
+        | Web3Error::InvalidRange { .. }
+        | Web3Error::TooManyL2ToL1Logs(_, _)
+        | Web3Error::UnsupportedProofVersion(_)
+        | Web3Error::UnknownToken
+        | Web3Error::NoTokenPrice
+        | Web3Error::L1BatchNotProven(_) => TransactionRejectionReason::InternalError,
+    }
+}
+
+/// Extracts constructor arguments for the contract at `address` from the `ContractDeployer` calldata.
+/// Unlike the contract verifier, doesn't panic on malformed calldata and returns `None` instead.
+fn decode_constructor_args(calldata: &[u8], address: Address) -> Option<Vec<u8>> {
+    if calldata.len() < 4 {
+        return None;
+    }
+    let (selector, input) = calldata.split_at(4);
+    let create = DEPLOYER_CONTRACT.function("create").unwrap();
+    let create2 = DEPLOYER_CONTRACT.function("create2").unwrap();
+    let create_acc = DEPLOYER_CONTRACT.function("createAccount").unwrap();
+    let create2_acc = DEPLOYER_CONTRACT.function("create2Account").unwrap();
+    let force_deploy = DEPLOYER_CONTRACT
+        .function("forceDeployOnAddresses")
+        .unwrap();
+
+    if [create, create2, create_acc, create2_acc]
+        .iter()
+        .any(|function| selector == function.short_signature())
+    {
+        // All `create*` methods have constructor arguments as the third parameter.
+        let tokens = create.decode_input(input).ok()?;
+        tokens.into_iter().nth(2)?.into_bytes()
+    } else if selector == force_deploy.short_signature() {
+        let tokens = force_deploy.decode_input(input).ok()?;
+        let deployments = tokens.into_iter().next()?.into_array()?;
+        deployments.into_iter().find_map(|deployment| {
+            let ethabi::Token::Tuple(tokens) = deployment else { return None };
+            if tokens.get(1)?.clone().into_address()? != address {
+                return None;
+            }
+            let call_constructor = tokens.get(2)?.clone().into_bool()?;
+            if call_constructor {
+                tokens.get(4)?.clone().into_bytes()
+            } else {
+                None
+            }
+        })
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_l2_to_l1_log(tx_number_in_block: u16, index: u8) -> L2ToL1Log {
+        L2ToL1Log {
+            shard_id: 0,
+            is_service: false,
+            tx_number_in_block,
+            sender: L1_MESSENGER_ADDRESS,
+            key: H256::repeat_byte(index),
+            value: H256::repeat_byte(index),
+        }
+    }
+
+    #[test]
+    fn l2_to_l1_log_indices_for_multi_message_tx() {
+        // Logs of the transaction #1 are interleaved with logs of other transactions.
+        let all_logs = [
+            create_l2_to_l1_log(0, 0),
+            create_l2_to_l1_log(1, 1),
+            create_l2_to_l1_log(1, 2),
+            create_l2_to_l1_log(2, 3),
+            create_l2_to_l1_log(1, 4),
+        ];
+
+        let indices = l2_to_l1_log_indices_for_tx(&all_logs, 1);
+        let indices: Vec<_> = indices
+            .iter()
+            .map(|index| (index.log_index_in_tx, index.log_index_in_batch, index.key))
+            .collect();
+        assert_eq!(
+            indices,
+            [
+                (0, 1, H256::repeat_byte(1)),
+                (1, 2, H256::repeat_byte(2)),
+                (2, 4, H256::repeat_byte(4)),
+            ]
+        );
+        assert!(indices
+            .iter()
+            .all(|&(_, index_in_batch, key)| all_logs[index_in_batch as usize].key == key));
+
+        let indices = l2_to_l1_log_indices_for_tx(&all_logs, 2);
+        assert_eq!(indices.len(), 1);
+        assert_eq!(indices[0].log_index_in_batch, 3);
+        assert_eq!(indices[0].sender, L1_MESSENGER_ADDRESS);
+
+        assert!(l2_to_l1_log_indices_for_tx(&all_logs, 3).is_empty());
     }
 }
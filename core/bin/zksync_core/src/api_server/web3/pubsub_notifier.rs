@@ -1,12 +1,18 @@
+use std::sync::Arc;
+
 use jsonrpc_pubsub::typed;
-use tokio::sync::watch;
+use tokio::sync::{watch, RwLock};
 use tokio::time::{interval, Duration, Instant};
 
 use zksync_dal::ConnectionPool;
-use zksync_types::MiniblockNumber;
+use zksync_types::{
+    api::{TransactionStage, TransactionStageUpdate},
+    fee::FeeParams,
+    MiniblockNumber,
+};
 use zksync_web3_decl::types::{PubSubFilter, PubSubResult};
 
-use super::namespaces::SubscriptionMap;
+use super::namespaces::{SubscriptionMap, TransactionSubscription};
 
 pub async fn notify_blocks(
     subscribers: SubscriptionMap<typed::Sink<PubSubResult>>,
@@ -171,3 +177,173 @@ pub async fn notify_logs(
         }
     }
 }
+
+pub async fn notify_fee_params(
+    subscribers: SubscriptionMap<typed::Sink<PubSubResult>>,
+    current_fee_params: Arc<RwLock<Option<FeeParams>>>,
+    connection_pool: ConnectionPool,
+    polling_interval: Duration,
+    stop_receiver: watch::Receiver<bool>,
+) {
+    let mut timer = interval(polling_interval);
+    loop {
+        if *stop_receiver.borrow() {
+            vlog::info!("Stop signal received, pubsub_fee_params_notifier is shutting down");
+            break;
+        }
+
+        timer.tick().await;
+
+        let start = Instant::now();
+        let last_miniblock_header = connection_pool
+            .access_storage_tagged("api")
+            .await
+            .blocks_dal()
+            .get_last_sealed_miniblock_header()
+            .await;
+        metrics::histogram!("api.web3.pubsub.db_poll_latency", start.elapsed(), "subscription_type" => "fee_params");
+        let fee_params = match last_miniblock_header {
+            Some(header) => FeeParams::new(header.l1_gas_price, header.l2_fair_gas_price),
+            None => continue,
+        };
+
+        {
+            let mut current_fee_params = current_fee_params.write().await;
+            if *current_fee_params == Some(fee_params) {
+                continue;
+            }
+            *current_fee_params = Some(fee_params);
+        }
+
+        let start = Instant::now();
+        let subscribers = subscribers
+            .read()
+            .await
+            .values()
+            .cloned()
+            .collect::<Vec<_>>();
+        for sink in subscribers {
+            if sink.notify(Ok(PubSubResult::FeeParams(fee_params))).is_ok() {
+                metrics::counter!("api.web3.pubsub.notify", 1, "subscription_type" => "fee_params");
+            }
+        }
+        metrics::histogram!("api.web3.pubsub.notify_subscribers_latency", start.elapsed(), "subscription_type" => "fee_params");
+    }
+}
+
+pub async fn notify_transaction_stages(
+    subscribers: SubscriptionMap<TransactionSubscription>,
+    connection_pool: ConnectionPool,
+    polling_interval: Duration,
+    stop_receiver: watch::Receiver<bool>,
+) {
+    let mut timer = interval(polling_interval);
+    loop {
+        if *stop_receiver.borrow() {
+            vlog::info!("Stop signal received, pubsub_transaction_notifier is shutting down");
+            break;
+        }
+
+        timer.tick().await;
+
+        let hashes = subscribers
+            .read()
+            .await
+            .values()
+            .map(|subscription| subscription.hash)
+            .collect::<Vec<_>>();
+        if hashes.is_empty() {
+            continue;
+        }
+
+        let start = Instant::now();
+        let stages = connection_pool
+            .access_storage_tagged("api")
+            .await
+            .transactions_web3_dal()
+            .get_transaction_stages(&hashes)
+            .await
+            .unwrap();
+        metrics::histogram!("api.web3.pubsub.db_poll_latency", start.elapsed(), "subscription_type" => "transaction");
+
+        let start = Instant::now();
+        let mut completed_count = 0;
+        subscribers.write().await.retain(|_, subscription| {
+            let stage = match stages.get(&subscription.hash) {
+                Some(&stage) if subscription.last_stage < Some(stage) => stage,
+                _ => return true,
+            };
+            let update = TransactionStageUpdate {
+                transaction_hash: subscription.hash,
+                stage,
+            };
+            let is_completed = if subscription
+                .sink
+                .notify(Ok(PubSubResult::TransactionStage(update)))
+                .is_err()
+            {
+                // Subscriber disconnected.
+                true
+            } else {
+                metrics::counter!("api.web3.pubsub.notify", 1, "subscription_type" => "transaction");
+                subscription.last_stage = Some(stage);
+                stage == TransactionStage::Executed
+            };
+            completed_count += usize::from(is_completed);
+            !is_completed
+        });
+        if completed_count > 0 {
+            metrics::decrement_gauge!("api.web3.pubsub.active_subscribers", completed_count as f64, "subscription_type" => "transaction");
+        }
+        metrics::histogram!("api.web3.pubsub.notify_subscribers_latency", start.elapsed(), "subscription_type" => "transaction");
+    }
+}
+
+pub async fn notify_l1_batches(
+    subscribers: SubscriptionMap<typed::Sink<PubSubResult>>,
+    connection_pool: ConnectionPool,
+    polling_interval: Duration,
+    stop_receiver: watch::Receiver<bool>,
+) {
+    let mut last_time = chrono::Utc::now().naive_utc();
+    let mut timer = interval(polling_interval);
+    loop {
+        if *stop_receiver.borrow() {
+            vlog::info!("Stop signal received, pubsub_l1_batch_notifier is shutting down");
+            break;
+        }
+
+        timer.tick().await;
+
+        let start = Instant::now();
+        let (new_events, new_last_time) = connection_pool
+            .access_storage_tagged("api")
+            .await
+            .blocks_web3_dal()
+            .get_l1_batch_events_after(last_time)
+            .await
+            .unwrap();
+        metrics::histogram!("api.web3.pubsub.db_poll_latency", start.elapsed(), "subscription_type" => "l1_batch");
+        if let Some(new_last_time) = new_last_time {
+            last_time = new_last_time;
+            let start = Instant::now();
+
+            let subscribers = subscribers
+                .read()
+                .await
+                .values()
+                .cloned()
+                .collect::<Vec<_>>();
+            for sink in subscribers {
+                for event in new_events.iter().copied() {
+                    if sink.notify(Ok(PubSubResult::L1BatchEvent(event))).is_err() {
+                        // Subscriber disconnected.
+                        break;
+                    }
+                    metrics::counter!("api.web3.pubsub.notify", 1, "subscription_type" => "l1_batch");
+                }
+            }
+            metrics::histogram!("api.web3.pubsub.notify_subscribers_latency", start.elapsed(), "subscription_type" => "l1_batch");
+        }
+    }
+}
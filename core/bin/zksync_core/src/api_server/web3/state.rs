@@ -1,10 +1,9 @@
-use std::collections::HashMap;
-#[cfg(feature = "openzeppelin_tests")]
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::convert::TryInto;
 use std::sync::Arc;
+use std::time::Instant;
 
-use tokio::sync::RwLock;
+use tokio::sync::{Mutex, RwLock};
 use zksync_config::configs::{api::Web3JsonRpcConfig, chain::NetworkConfig, ContractsConfig};
 
 use crate::api_server::tx_sender::TxSender;
@@ -36,8 +35,31 @@ pub struct InternalApiConfig {
     pub estimate_gas_acceptable_overestimation: u32,
     pub bridge_addresses: api::BridgeAddresses,
     pub diamond_proxy_addr: Address,
+    pub bridgehub_proxy_addr: Option<Address>,
+    pub scheduler_vk_hash: Option<H256>,
     pub l2_testnet_paymaster_addr: Option<Address>,
     pub req_entities_limit: usize,
+    pub max_logs_block_range: u32,
+    pub soft_confirmation_blocks: u32,
+    pub max_batch_diff_range: u32,
+    pub max_l2_to_l1_log_proof_leaves: usize,
+    pub max_batch_transactions_page_size: u32,
+    pub l1_commit_data_enabled: bool,
+    pub max_verified_tokens: u32,
+    pub l1_to_l2_gas_limit_buffer_percent: u32,
+    pub commitment_inputs_enabled: bool,
+    pub max_storage_keys_per_request: u32,
+    pub max_batch_l2_to_l1_log_proofs_page_size: u32,
+    pub connection_pool_stats_enabled: bool,
+    pub max_transaction_details_batch_size: u32,
+    pub max_account_storage_keys_page_size: u32,
+    pub chain_stats_refresh_interval_sec: u64,
+    pub max_account_balances_page_size: u32,
+    pub account_balances_cache_ttl_ms: u64,
+    pub account_balances_cache_capacity: u64,
+    pub unsafe_admin_methods_enabled: bool,
+    pub max_block_details_range: u32,
+    pub max_l1_gas_price_history_range: u32,
 }
 
 impl InternalApiConfig {
@@ -60,8 +82,32 @@ impl InternalApiConfig {
                 l2_weth_bridge: contracts_config.l2_weth_bridge_addr,
             },
             diamond_proxy_addr: contracts_config.diamond_proxy_addr,
+            bridgehub_proxy_addr: contracts_config.bridgehub_proxy_addr,
+            scheduler_vk_hash: contracts_config.recursion_scheduler_level_vk_hash,
             l2_testnet_paymaster_addr: contracts_config.l2_testnet_paymaster_addr,
             req_entities_limit: web3_config.req_entities_limit(),
+            max_logs_block_range: web3_config.max_logs_block_range(),
+            soft_confirmation_blocks: web3_config.soft_confirmation_blocks(),
+            max_batch_diff_range: web3_config.max_batch_diff_range(),
+            max_l2_to_l1_log_proof_leaves: web3_config.max_l2_to_l1_log_proof_leaves(),
+            max_batch_transactions_page_size: web3_config.max_batch_transactions_page_size(),
+            l1_commit_data_enabled: web3_config.l1_commit_data_enabled(),
+            max_verified_tokens: web3_config.max_verified_tokens(),
+            l1_to_l2_gas_limit_buffer_percent: web3_config.l1_to_l2_gas_limit_buffer_percent(),
+            commitment_inputs_enabled: web3_config.commitment_inputs_enabled(),
+            max_storage_keys_per_request: web3_config.max_storage_keys_per_request(),
+            max_batch_l2_to_l1_log_proofs_page_size: web3_config
+                .max_batch_l2_to_l1_log_proofs_page_size(),
+            connection_pool_stats_enabled: web3_config.connection_pool_stats_enabled(),
+            max_transaction_details_batch_size: web3_config.max_transaction_details_batch_size(),
+            max_account_storage_keys_page_size: web3_config.max_account_storage_keys_page_size(),
+            chain_stats_refresh_interval_sec: web3_config.chain_stats_refresh_interval_sec(),
+            max_account_balances_page_size: web3_config.max_account_balances_page_size(),
+            account_balances_cache_ttl_ms: web3_config.account_balances_cache_ttl_ms(),
+            account_balances_cache_capacity: web3_config.account_balances_cache_capacity(),
+            unsafe_admin_methods_enabled: web3_config.unsafe_admin_methods_enabled(),
+            max_block_details_range: web3_config.max_block_details_range(),
+            max_l1_gas_price_history_range: web3_config.max_l1_gas_price_history_range(),
         }
     }
 }
@@ -75,8 +121,13 @@ pub struct RpcState<E> {
     pub sync_state: Option<SyncState>,
     pub(super) api_config: InternalApiConfig,
     pub accounts: HashMap<Address, PrivateKeySigner>,
-    #[cfg(feature = "openzeppelin_tests")]
-    pub known_bytecodes: Arc<RwLock<HashSet<Vec<u8>>>>,
+    /// Whether the `debug` namespace is enabled; debugging-oriented methods of other namespaces
+    /// are only available if it is.
+    pub debug_namespace_enabled: bool,
+    /// Cached `zks_getChainStats` values together with the moment they were computed.
+    pub chain_stats: Arc<Mutex<Option<(Instant, api::ChainStats)>>>,
+    /// Bytecodes registered via `zks_setKnownBytecode`.
+    pub known_bytecodes: Arc<std::sync::RwLock<HashSet<Vec<u8>>>>,
 }
 
 // Custom implementation is required due to generic param:
@@ -91,7 +142,8 @@ impl<E> Clone for RpcState<E> {
             sync_state: self.sync_state.clone(),
             api_config: self.api_config.clone(),
             accounts: self.accounts.clone(),
-            #[cfg(feature = "openzeppelin_tests")]
+            debug_namespace_enabled: self.debug_namespace_enabled,
+            chain_stats: self.chain_stats.clone(),
             known_bytecodes: self.known_bytecodes.clone(),
         }
     }
@@ -333,6 +333,12 @@ impl BlockReverter {
             if let Some(receipt) = web3.eth().transaction_receipt(hash).await.unwrap() {
                 assert_eq!(receipt.status, Some(1.into()), "revert transaction failed");
                 vlog::info!("revert transaction has completed");
+                self.connection_pool
+                    .access_storage()
+                    .await
+                    .blocks_dal()
+                    .insert_l1_batch_reverts(last_l1_batch_to_keep, hash)
+                    .await;
                 return;
             } else {
                 vlog::info!("waiting for L1 transaction confirmation...");
@@ -8,9 +8,14 @@ use zksync_eth_client::{
     BoundEthInterface,
 };
 use zksync_types::{
+    aggregated_operations::AggregatedActionType,
     eth_sender::EthTx,
-    web3::{contract::Options, error::Error as Web3Error},
-    L1BlockNumber, Nonce, H256, U256,
+    web3::{
+        contract::Options,
+        error::Error as Web3Error,
+        types::{BlockId, BlockNumber},
+    },
+    Address, L1BlockNumber, Nonce, H256, U256,
 };
 use zksync_utils::time::seconds_since_epoch;
 
@@ -47,6 +52,8 @@ pub struct EthTxManager<E, G> {
     ethereum_gateway: E,
     config: SenderConfig,
     gas_adjuster: Arc<G>,
+    /// Hash of the verification key that submitted proofs are generated for.
+    scheduler_vk_hash: Option<H256>,
 }
 
 impl<E, G> EthTxManager<E, G>
@@ -54,11 +61,17 @@ where
     E: BoundEthInterface + Sync,
     G: L1TxParamsProvider,
 {
-    pub fn new(config: SenderConfig, gas_adjuster: Arc<G>, ethereum_gateway: E) -> Self {
+    pub fn new(
+        config: SenderConfig,
+        gas_adjuster: Arc<G>,
+        ethereum_gateway: E,
+        scheduler_vk_hash: Option<H256>,
+    ) -> Self {
         Self {
             ethereum_gateway,
             config,
             gas_adjuster,
+            scheduler_vk_hash,
         }
     }
 
@@ -509,6 +522,10 @@ where
             .eth_sender_dal()
             .confirm_tx(tx_status.tx_hash, gas_used)
             .await;
+        if tx.tx_type == AggregatedActionType::PublishProofBlocksOnchain {
+            self.save_proof_verification_data(storage, tx, &tx_status)
+                .await;
+        }
 
         track_eth_tx_metrics(storage, "mined", tx).await;
 
@@ -551,6 +568,47 @@ where
         );
     }
 
+    /// Records how proofs of L1 batches in the confirmed `tx` were verified. Failing to fetch the verifier
+    /// address from L1 is logged, but doesn't prevent the sender from processing other transactions.
+    async fn save_proof_verification_data(
+        &self,
+        storage: &mut StorageProcessor<'_>,
+        tx: &EthTx,
+        tx_status: &ExecutedTxStatus,
+    ) {
+        if let Some(vk_hash) = self.scheduler_vk_hash {
+            storage
+                .blocks_dal()
+                .set_l1_batches_verification_key_hash(tx.id, vk_hash)
+                .await;
+        }
+
+        // Verifier contracts can be upgraded, so we record the one active in the block the proof was included in.
+        let block = tx_status
+            .receipt
+            .block_number
+            .map(|number| BlockId::Number(BlockNumber::Number(number)));
+        let verifier_address: Result<Address, _> = self
+            .ethereum_gateway
+            .call_main_contract_function("getVerifier", (), None, Options::default(), block)
+            .await;
+        match verifier_address {
+            Ok(verifier_address) => {
+                storage
+                    .blocks_dal()
+                    .set_l1_batches_verifier_address(tx.id, verifier_address)
+                    .await;
+            }
+            Err(err) => {
+                vlog::warn!(
+                    "Failed to fetch the verifier address for eth_tx {}: {}",
+                    tx.id,
+                    err
+                );
+            }
+        }
+    }
+
     pub async fn run(mut self, pool: ConnectionPool, stop_receiver: watch::Receiver<bool>) {
         {
             let l1_block_numbers = self.get_l1_block_numbers().await.unwrap();
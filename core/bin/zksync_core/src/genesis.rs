@@ -73,7 +73,7 @@ pub async fn ensure_genesis_state(
 
             let client = HttpClientBuilder::default().build(main_node_url).unwrap();
             let first_validator = client
-                .get_block_details(MiniblockNumber(0))
+                .get_block_details(MiniblockNumber(0).into())
                 .await
                 .ok()
                 .flatten()
@@ -440,6 +440,7 @@ pub async fn initialize_components(
             eth_sender.sender,
             gas_adjuster.get_or_init().await,
             eth_client,
+            contracts_config.recursion_scheduler_level_vk_hash,
         );
         task_futures.extend([tokio::spawn(
             eth_tx_manager_actor.run(eth_sender_storage, stop_receiver.clone()),
@@ -51,6 +51,7 @@ pub(crate) struct MempoolIO<G> {
     l1_gas_price_provider: Arc<G>,
     base_system_contracts: BaseSystemContracts,
     l2_erc20_bridge_addr: Address,
+    protocol_version: u16,
 }
 
 #[async_trait]
@@ -215,6 +216,7 @@ impl<G: L1GasPriceProvider + 'static + Send + Sync> StateKeeperIO for MempoolIO<
                 block_result,
                 block_context,
                 self.l2_erc20_bridge_addr,
+                Some(self.protocol_version),
             )
             .await;
         self.current_miniblock_number += 1; // Due to fictive miniblock being sealed.
@@ -286,8 +288,22 @@ impl<G: L1GasPriceProvider> MempoolIO<G> {
             .storage_dal()
             .get_base_system_contracts(config.bootloader_hash, config.default_aa_hash)
             .await;
+        let latest_protocol_version = storage
+            .blocks_web3_dal()
+            .get_latest_protocol_version()
+            .await
+            .unwrap();
         drop(storage);
 
+        if let Some(latest_protocol_version) = latest_protocol_version {
+            assert!(
+                config.protocol_version >= latest_protocol_version,
+                "Configured protocol version {} is lower than the version {} of the last sealed L1 batch",
+                config.protocol_version,
+                latest_protocol_version
+            );
+        }
+
         Self {
             mempool,
             pool,
@@ -302,6 +318,7 @@ impl<G: L1GasPriceProvider> MempoolIO<G> {
             l1_gas_price_provider,
             base_system_contracts,
             l2_erc20_bridge_addr,
+            protocol_version: config.protocol_version,
         }
     }
 
@@ -123,6 +123,8 @@ impl UpdatesManager {
     /// Persists an L1 batch in the storage.
     /// This action includes a creation of an empty "fictive" miniblock that contains
     /// the events generated during the bootloader "tip phase".
+    /// `protocol_version` is recorded for the batch if the sealing node knows it.
+    #[allow(clippy::too_many_arguments)]
     pub(crate) async fn seal_l1_batch(
         mut self,
         storage: &mut StorageProcessor<'_>,
@@ -131,6 +133,7 @@ impl UpdatesManager {
         block_result: VmBlockResult,
         block_context: DerivedBlockContext,
         l2_erc20_bridge_addr: Address,
+        protocol_version: Option<u16>,
     ) {
         let started_at = Instant::now();
         let mut progress = SealProgress::for_l1_batch();
@@ -222,6 +225,18 @@ impl UpdatesManager {
             .blocks_dal()
             .insert_l1_batch(&l1_batch, self.l1_batch.l1_gas_count)
             .await;
+        if let Some(reason) = self.seal_reason() {
+            transaction
+                .blocks_dal()
+                .set_l1_batch_seal_reason(current_l1_batch_number, reason)
+                .await;
+        }
+        if let Some(protocol_version) = protocol_version {
+            transaction
+                .blocks_dal()
+                .set_l1_batch_protocol_version(current_l1_batch_number, protocol_version)
+                .await;
+        }
         progress.end_stage("insert_l1_batch_header", None);
 
         transaction
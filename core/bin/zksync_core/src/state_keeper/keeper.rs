@@ -375,33 +375,39 @@ impl ZkSyncStateKeeper {
         tx: Transaction,
     ) -> (SealResolution, TxExecutionResult) {
         let exec_result = batch_executor.execute_tx(tx.clone()).await;
-        let resolution = match &exec_result {
+        let (resolution, seal_reason) = match &exec_result {
             TxExecutionResult::BootloaderOutOfGasForTx => {
+                const CRITERION: &str = "bootloader_tx_out_of_gas";
                 metrics::increment_counter!(
                     "server.tx_aggregation.reason",
-                    "criterion" => "bootloader_tx_out_of_gas",
+                    "criterion" => CRITERION,
                     "seal_resolution" => "exclude_and_seal",
                 );
-                SealResolution::ExcludeAndSeal
+                (SealResolution::ExcludeAndSeal, Some(CRITERION))
             }
             TxExecutionResult::BootloaderOutOfGasForBlockTip => {
+                const CRITERION: &str = "bootloader_block_tip_failed";
                 metrics::increment_counter!(
                     "server.tx_aggregation.reason",
-                    "criterion" => "bootloader_block_tip_failed",
+                    "criterion" => CRITERION,
                     "seal_resolution" => "exclude_and_seal",
                 );
-                SealResolution::ExcludeAndSeal
+                (SealResolution::ExcludeAndSeal, Some(CRITERION))
             }
             TxExecutionResult::RejectedByVm { rejection_reason } => match rejection_reason {
                 TxRevertReason::NotEnoughGasProvided => {
+                    const CRITERION: &str = "not_enough_gas_provided_to_start_tx";
                     metrics::increment_counter!(
                         "server.tx_aggregation.reason",
-                        "criterion" => "not_enough_gas_provided_to_start_tx",
+                        "criterion" => CRITERION,
                         "seal_resolution" => "exclude_and_seal",
                     );
-                    SealResolution::ExcludeAndSeal
+                    (SealResolution::ExcludeAndSeal, Some(CRITERION))
                 }
-                _ => SealResolution::Unexecutable(rejection_reason.to_string()),
+                _ => (
+                    SealResolution::Unexecutable(rejection_reason.to_string()),
+                    None,
+                ),
             },
             TxExecutionResult::Success {
                 tx_result,
@@ -479,6 +485,9 @@ impl ZkSyncStateKeeper {
                 )
             }
         };
+        if let (true, Some(seal_reason)) = (resolution.should_seal(), seal_reason) {
+            updates_manager.set_seal_reason(seal_reason);
+        }
         (resolution, exec_result)
     }
 }
@@ -52,7 +52,7 @@ impl ConditionalSealer {
         tx_count: usize,
         block_data: &SealData,
         tx_data: &SealData,
-    ) -> SealResolution {
+    ) -> (SealResolution, Option<&'static str>) {
         vlog::debug!(
             "Determining seal resolution for L1 batch #{l1_batch_number} with {tx_count} transactions \
              and metrics {:?}",
@@ -60,6 +60,8 @@ impl ConditionalSealer {
         );
 
         let mut final_seal_resolution = SealResolution::NoSeal;
+        // Name of the first criterion that returned the final resolution.
+        let mut seal_reason = None;
         for sealer in &self.sealers {
             let seal_resolution = sealer.should_seal(
                 &self.config,
@@ -86,9 +88,13 @@ impl ConditionalSealer {
                 SealResolution::NoSeal => { /* Don't do anything */ }
             }
 
-            final_seal_resolution = final_seal_resolution.stricter(seal_resolution);
+            let new_seal_resolution = final_seal_resolution.clone().stricter(seal_resolution);
+            if new_seal_resolution != final_seal_resolution {
+                seal_reason = Some(sealer.prom_criterion_name());
+            }
+            final_seal_resolution = new_seal_resolution;
         }
-        final_seal_resolution
+        (final_seal_resolution, seal_reason)
     }
 
     fn default_sealers() -> Vec<Box<dyn SealCriterion>> {
@@ -138,9 +138,10 @@ pub struct SealManager {
     conditional_sealer: Option<ConditionalSealer>,
     /// Unconditional batch sealer, i.e. one that can be used if we should seal the batch *without* executing a tx.
     /// If any of the unconditional sealers returns `true`, the batch will be sealed.
+    /// Each sealer is accompanied by the name of its rule recorded as the seal reason; custom sealers are unnamed.
     ///
     /// Note: only non-empty batch can be sealed.
-    unconditional_sealers: Vec<Box<SealerFn>>,
+    unconditional_sealers: Vec<(Option<&'static str>, Box<SealerFn>)>,
     /// Miniblock sealer function used to determine if we should seal the miniblock.
     /// If any of the miniblock sealers returns `true`, the miniblock will be sealed.
     miniblock_sealers: Vec<Box<SealerFn>>,
@@ -166,11 +167,14 @@ impl SealManager {
             Self::timeout_miniblock_sealer(config.miniblock_commit_deadline_ms);
         let conditional_sealer = ConditionalSealer::new(config);
 
-        Self::custom(
-            Some(conditional_sealer),
-            vec![timeout_batch_sealer, code_hash_batch_sealer],
-            vec![timeout_miniblock_sealer],
-        )
+        Self {
+            conditional_sealer: Some(conditional_sealer),
+            unconditional_sealers: vec![
+                (Some(Self::TIMEOUT_BATCH_RULE), timeout_batch_sealer),
+                (Some(Self::CODE_HASH_BATCH_RULE), code_hash_batch_sealer),
+            ],
+            miniblock_sealers: vec![timeout_miniblock_sealer],
+        }
     }
 
     /// Allows to create a seal manager object from externally-defined sealers.
@@ -181,14 +185,20 @@ impl SealManager {
     ) -> Self {
         Self {
             conditional_sealer,
-            unconditional_sealers,
+            unconditional_sealers: unconditional_sealers
+                .into_iter()
+                .map(|sealer| (None, sealer))
+                .collect(),
             miniblock_sealers,
         }
     }
 
+    const TIMEOUT_BATCH_RULE: &'static str = "no_txs_timeout";
+    const CODE_HASH_BATCH_RULE: &'static str = "different_code_hashes";
+
     /// Creates a sealer function that would seal the batch because of the timeout.
     fn timeout_batch_sealer(block_commit_deadline_ms: u64) -> Box<SealerFn> {
-        const RULE_NAME: &str = "no_txs_timeout";
+        const RULE_NAME: &str = SealManager::TIMEOUT_BATCH_RULE;
 
         Box::new(move |manager| {
             // Verify timestamp
@@ -212,7 +222,7 @@ impl SealManager {
     pub(super) fn code_hash_batch_sealer(
         base_system_contracts_hashes: BaseSystemContractsHashes,
     ) -> Box<SealerFn> {
-        const RULE_NAME: &str = "different_code_hashes";
+        const RULE_NAME: &str = SealManager::CODE_HASH_BATCH_RULE;
 
         Box::new(move |manager| {
             // Verify code hashes
@@ -248,7 +258,7 @@ impl SealManager {
         tx_count: usize,
         block_data: &SealData,
         tx_data: &SealData,
-    ) -> SealResolution {
+    ) -> (SealResolution, Option<&'static str>) {
         if let Some(sealer) = &self.conditional_sealer {
             sealer.should_seal_l1_batch(
                 l1_batch_number,
@@ -258,20 +268,31 @@ impl SealManager {
                 tx_data,
             )
         } else {
-            SealResolution::NoSeal
+            (SealResolution::NoSeal, None)
         }
     }
 
+    /// Checks whether the batch should be sealed without executing a tx. If it should, the name
+    /// of the triggered rule (if known) is recorded as the seal reason in `updates_manager`.
     pub(super) fn should_seal_l1_batch_unconditionally(
         &self,
-        updates_manager: &UpdatesManager,
+        updates_manager: &mut UpdatesManager,
     ) -> bool {
         // Regardless of which sealers are provided, we never want to seal an empty batch.
-        updates_manager.pending_executed_transactions_len() != 0
-            && self
-                .unconditional_sealers
-                .iter()
-                .any(|sealer| (sealer)(updates_manager))
+        if updates_manager.pending_executed_transactions_len() == 0 {
+            return false;
+        }
+        let triggered_sealer = self
+            .unconditional_sealers
+            .iter()
+            .find(|(_, sealer)| (sealer)(updates_manager));
+        let Some((rule_name, _)) = triggered_sealer else {
+            return false;
+        };
+        if let Some(rule_name) = rule_name {
+            updates_manager.set_seal_reason(*rule_name);
+        }
+        true
     }
 
     pub(super) fn should_seal_miniblock(&self, updates_manager: &UpdatesManager) -> bool {
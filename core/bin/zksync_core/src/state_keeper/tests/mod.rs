@@ -249,7 +249,9 @@ async fn sealed_by_number_of_txs() {
         .miniblock_sealed("Miniblock 1")
         .next_tx("Second tx", random_tx(2), successful_exec())
         .miniblock_sealed("Miniblock 2")
-        .batch_sealed("Batch 1")
+        .batch_sealed_with("Batch 1", |_, updates, _| {
+            assert_eq!(updates.seal_reason(), Some("slots"));
+        })
         .run(sealer)
         .await;
 }
@@ -28,6 +28,8 @@ pub struct UpdatesManager {
     fair_l2_gas_price: u64,
     base_fee_per_gas: u64,
     base_system_contract_hashes: BaseSystemContractsHashes,
+    /// Name of the rule that caused the L1 batch to be sealed, if known.
+    seal_reason: Option<&'static str>,
     pub l1_batch: L1BatchUpdates,
     pub miniblock: MiniblockUpdates,
     pub storage_writes_deduplicator: StorageWritesDeduplicator,
@@ -46,6 +48,7 @@ impl UpdatesManager {
             fair_l2_gas_price: context.fair_l2_gas_price,
             base_fee_per_gas: block_context.inner_block_context().base_fee,
             base_system_contract_hashes,
+            seal_reason: None,
             l1_batch: L1BatchUpdates::new(),
             miniblock: MiniblockUpdates::new(batch_timestamp),
             storage_writes_deduplicator: StorageWritesDeduplicator::new(),
@@ -60,6 +63,14 @@ impl UpdatesManager {
         self.base_system_contract_hashes
     }
 
+    pub(crate) fn seal_reason(&self) -> Option<&'static str> {
+        self.seal_reason
+    }
+
+    pub(crate) fn set_seal_reason(&mut self, reason: &'static str) {
+        self.seal_reason = Some(reason);
+    }
+
     pub(crate) fn l1_gas_price(&self) -> u64 {
         self.l1_gas_price
     }
@@ -172,7 +172,7 @@ impl BatchStatusUpdater {
             let request_start = Instant::now();
             let Some(batch_info) = self
                 .client
-                .get_block_details(MiniblockNumber(start_miniblock.as_u32()))
+                .get_block_details(MiniblockNumber(start_miniblock.as_u32()).into())
                 .await?
             else {
                 // We cannot recover from an external API inconsistency.
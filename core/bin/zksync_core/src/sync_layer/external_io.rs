@@ -52,7 +52,10 @@ impl ExternalNodeSealer {
     }
 
     fn should_seal_batch(&self) -> bool {
-        let res = matches!(self.actions.peek_action(), Some(SyncAction::SealBatch));
+        let res = matches!(
+            self.actions.peek_action(),
+            Some(SyncAction::SealBatch { .. })
+        );
         if res {
             vlog::info!("Sealing the batch");
         }
@@ -259,7 +262,7 @@ impl StateKeeperIO for ExternalIO {
                     );
                     return Some(timestamp);
                 }
-                Some(SyncAction::SealBatch) => {
+                Some(SyncAction::SealBatch { .. }) => {
                     // We've reached the next batch, so this situation would be handled by the batch sealer.
                     // No need to pop the action from the queue.
                     // It also doesn't matter which timestamp we return, since there will be no more miniblocks in this
@@ -378,8 +381,8 @@ impl StateKeeperIO for ExternalIO {
         updates_manager: UpdatesManager,
         block_context: vm::vm_with_bootloader::DerivedBlockContext,
     ) {
-        match self.actions.pop_action() {
-            Some(SyncAction::SealBatch) => {}
+        let protocol_version = match self.actions.pop_action() {
+            Some(SyncAction::SealBatch { protocol_version }) => protocol_version,
             other => panic!(
                 "State keeper requested to seal the batch, but the next action is {:?}",
                 other
@@ -395,6 +398,7 @@ impl StateKeeperIO for ExternalIO {
                 block_result,
                 block_context,
                 self.l2_erc20_bridge_addr,
+                protocol_version,
             )
             .await;
 
@@ -194,7 +194,9 @@ impl MainNodeFetcher {
         // Last miniblock of the batch is a "fictive" miniblock and would be replicated locally.
         // We don't need to seal it explicitly, so we only put the seal miniblock command if it's not the last miniblock.
         if block.last_in_batch {
-            new_actions.push(SyncAction::SealBatch);
+            new_actions.push(SyncAction::SealBatch {
+                protocol_version: block.protocol_version,
+            });
         } else {
             new_actions.push(SyncAction::SealMiniblock);
         }
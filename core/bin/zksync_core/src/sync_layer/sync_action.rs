@@ -86,7 +86,7 @@ impl ActionQueue {
                         return Err(format!("Unexpected Tx: {:?}", actions));
                     }
                 }
-                SyncAction::SealMiniblock | SyncAction::SealBatch => {
+                SyncAction::SealMiniblock | SyncAction::SealBatch { .. } => {
                     if !opened || miniblock_sealed {
                         return Err(format!("Unexpected SealMiniblock/SealBatch: {:?}", actions));
                     }
@@ -142,7 +142,10 @@ pub(crate) enum SyncAction {
     /// the next one is sealed on the main node.
     SealMiniblock,
     /// Similarly to `SealMiniblock` we must be able to seal the batch even if there is no next miniblock yet.
-    SealBatch,
+    SealBatch {
+        /// Protocol version of the batch as reported by the main node.
+        protocol_version: Option<u16>,
+    },
 }
 
 impl From<Transaction> for SyncAction {
@@ -196,7 +199,9 @@ mod tests {
     }
 
     fn seal_batch() -> SyncAction {
-        SyncAction::SealBatch
+        SyncAction::SealBatch {
+            protocol_version: None,
+        }
     }
 
     #[test]
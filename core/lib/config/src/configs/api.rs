@@ -84,6 +84,53 @@ pub struct Web3JsonRpcConfig {
     /// Override value for the amount of threads used for WebSocket RPC server.
     /// If not set, the value from `threads_per_server` is used.
     pub ws_threads: Option<u32>,
+    /// Max possible number of miniblocks that can be covered by a single `eth_getLogs` request.
+    /// If not set, 10000 miniblocks is used.
+    pub max_logs_block_range: Option<u32>,
+    /// Number of miniblocks that have to be sealed on top of the miniblock including a transaction
+    /// for the transaction to be considered soft-confirmed.
+    /// If not set, inclusion into a sealed miniblock is enough.
+    pub soft_confirmation_blocks: Option<u32>,
+    /// Max possible number of L1 batches that can be covered by a single `zks_getBatchDiff` request.
+    pub max_batch_diff_range: Option<u32>,
+    /// Maximum number of leaves that `zks_getL2ToL1LogProof` returns when the full tree is requested.
+    pub max_l2_to_l1_log_proof_leaves: Option<usize>,
+    /// Maximum number of transactions that `zks_getTransactionsInBatch` returns in a single page.
+    pub max_batch_transactions_page_size: Option<u32>,
+    /// Whether `zks_getBatchL1CommitData` is enabled. Disabled by default since the commit data
+    /// includes all the batch pubdata and factory deps and may be large.
+    pub l1_commit_data_enabled: Option<bool>,
+    /// Maximum number of tokens `zks_getConfirmedTokens` returns when on-chain verification is requested.
+    pub max_verified_tokens: Option<u32>,
+    /// Safety buffer (in percent) that `zks_getRecommendedL1ToL2GasLimit` adds on top of the estimated gas limit.
+    pub l1_to_l2_gas_limit_buffer_percent: Option<u32>,
+    /// Whether `zks_getBatchCommitmentInputs` is enabled. Disabled by default since the inputs
+    /// include all the batch L2 -> L1 logs and storage writes and may be large.
+    pub commitment_inputs_enabled: Option<bool>,
+    /// Maximum number of storage keys that can be requested in a single `zks_getStorageAtBatch` call.
+    pub max_storage_keys_per_request: Option<u32>,
+    /// Maximum number of proofs that `zks_getBatchL2ToL1LogProofs` returns in a single page.
+    pub max_batch_l2_to_l1_log_proofs_page_size: Option<u32>,
+    /// Whether `zks_getConnectionPoolStats` is available. Should only be enabled on internal endpoints.
+    pub connection_pool_stats_enabled: Option<bool>,
+    /// Maximum number of transaction hashes accepted by a single `zks_getTransactionDetailsBatch` call.
+    pub max_transaction_details_batch_size: Option<u32>,
+    /// Maximum number of keys that `zks_getAccountStorageKeys` returns in a single page.
+    pub max_account_storage_keys_page_size: Option<u32>,
+    /// How often the counts returned by `zks_getChainStats` are refreshed, in seconds.
+    pub chain_stats_refresh_interval_sec: Option<u64>,
+    /// Maximum number of balances that `zks_getAccountBalancesPaged` returns in a single page.
+    pub max_account_balances_page_size: Option<u32>,
+    /// Time-to-live of cached `zks_getAllAccountBalances` responses, in milliseconds.
+    pub account_balances_cache_ttl_ms: Option<u64>,
+    /// Maximum number of accounts with cached `zks_getAllAccountBalances` responses.
+    pub account_balances_cache_capacity: Option<u64>,
+    /// Whether unsafe administrative methods (e.g. `zks_setKnownBytecode`) are enabled. Must stay disabled on public nodes.
+    pub unsafe_admin_methods_enabled: Option<bool>,
+    /// Maximum number of miniblocks that can be requested in a single `zks_getBlockDetailsRange` call.
+    pub max_block_details_range: Option<u32>,
+    /// Maximum number of miniblocks that can be requested in a single `zks_getL1GasPriceHistory` call.
+    pub max_l1_gas_price_history_range: Option<u32>,
 }
 
 impl Web3JsonRpcConfig {
@@ -135,6 +182,90 @@ impl Web3JsonRpcConfig {
     pub fn ws_server_threads(&self) -> usize {
         self.ws_threads.unwrap_or(self.threads_per_server) as usize
     }
+
+    pub fn soft_confirmation_blocks(&self) -> u32 {
+        self.soft_confirmation_blocks.unwrap_or(0)
+    }
+
+    pub fn max_logs_block_range(&self) -> u32 {
+        self.max_logs_block_range.unwrap_or(10000)
+    }
+
+    pub fn max_batch_diff_range(&self) -> u32 {
+        self.max_batch_diff_range.unwrap_or(100)
+    }
+
+    pub fn max_l2_to_l1_log_proof_leaves(&self) -> usize {
+        self.max_l2_to_l1_log_proof_leaves.unwrap_or(1024)
+    }
+
+    pub fn max_batch_transactions_page_size(&self) -> u32 {
+        self.max_batch_transactions_page_size.unwrap_or(100)
+    }
+
+    pub fn l1_commit_data_enabled(&self) -> bool {
+        self.l1_commit_data_enabled.unwrap_or(false)
+    }
+
+    pub fn max_verified_tokens(&self) -> u32 {
+        self.max_verified_tokens.unwrap_or(50)
+    }
+
+    pub fn l1_to_l2_gas_limit_buffer_percent(&self) -> u32 {
+        self.l1_to_l2_gas_limit_buffer_percent.unwrap_or(20)
+    }
+
+    pub fn commitment_inputs_enabled(&self) -> bool {
+        self.commitment_inputs_enabled.unwrap_or(false)
+    }
+
+    pub fn max_storage_keys_per_request(&self) -> u32 {
+        self.max_storage_keys_per_request.unwrap_or(100)
+    }
+
+    pub fn max_batch_l2_to_l1_log_proofs_page_size(&self) -> u32 {
+        self.max_batch_l2_to_l1_log_proofs_page_size.unwrap_or(100)
+    }
+
+    pub fn connection_pool_stats_enabled(&self) -> bool {
+        self.connection_pool_stats_enabled.unwrap_or(false)
+    }
+
+    pub fn max_transaction_details_batch_size(&self) -> u32 {
+        self.max_transaction_details_batch_size.unwrap_or(100)
+    }
+
+    pub fn max_account_storage_keys_page_size(&self) -> u32 {
+        self.max_account_storage_keys_page_size.unwrap_or(1000)
+    }
+
+    pub fn chain_stats_refresh_interval_sec(&self) -> u64 {
+        self.chain_stats_refresh_interval_sec.unwrap_or(60)
+    }
+
+    pub fn max_account_balances_page_size(&self) -> u32 {
+        self.max_account_balances_page_size.unwrap_or(100)
+    }
+
+    pub fn account_balances_cache_ttl_ms(&self) -> u64 {
+        self.account_balances_cache_ttl_ms.unwrap_or(1000)
+    }
+
+    pub fn account_balances_cache_capacity(&self) -> u64 {
+        self.account_balances_cache_capacity.unwrap_or(10000)
+    }
+
+    pub fn unsafe_admin_methods_enabled(&self) -> bool {
+        self.unsafe_admin_methods_enabled.unwrap_or(false)
+    }
+
+    pub fn max_block_details_range(&self) -> u32 {
+        self.max_block_details_range.unwrap_or(100)
+    }
+
+    pub fn max_l1_gas_price_history_range(&self) -> u32 {
+        self.max_l1_gas_price_history_range.unwrap_or(1000)
+    }
 }
 
 #[derive(Debug, Deserialize, Clone, PartialEq)]
@@ -232,6 +363,27 @@ mod tests {
                 factory_deps_cache_size_mb: Some(128),
                 http_threads: Some(128),
                 ws_threads: Some(256),
+                max_logs_block_range: Some(10000),
+                soft_confirmation_blocks: Some(1),
+                max_batch_diff_range: Some(50),
+                max_l2_to_l1_log_proof_leaves: Some(256),
+                max_batch_transactions_page_size: Some(50),
+                l1_commit_data_enabled: Some(true),
+                max_verified_tokens: Some(20),
+                l1_to_l2_gas_limit_buffer_percent: Some(30),
+                commitment_inputs_enabled: Some(true),
+                max_storage_keys_per_request: Some(100),
+                max_batch_l2_to_l1_log_proofs_page_size: Some(100),
+                connection_pool_stats_enabled: Some(true),
+                max_transaction_details_batch_size: Some(100),
+                max_account_storage_keys_page_size: Some(500),
+                chain_stats_refresh_interval_sec: Some(30),
+                max_account_balances_page_size: Some(50),
+                account_balances_cache_ttl_ms: Some(500),
+                account_balances_cache_capacity: Some(100),
+                unsafe_admin_methods_enabled: Some(true),
+                max_block_details_range: Some(50),
+                max_l1_gas_price_history_range: Some(500),
             },
             explorer: ExplorerApiConfig {
                 port: 3070,
@@ -274,6 +426,27 @@ API_WEB3_JSON_RPC_VM_CONCURRENCY_LIMIT=512
 API_WEB3_JSON_RPC_FACTORY_DEPS_CACHE_SIZE_MB=128
 API_WEB3_JSON_RPC_HTTP_THREADS=128
 API_WEB3_JSON_RPC_WS_THREADS=256
+API_WEB3_JSON_RPC_MAX_LOGS_BLOCK_RANGE=10000
+API_WEB3_JSON_RPC_SOFT_CONFIRMATION_BLOCKS=1
+API_WEB3_JSON_RPC_MAX_BATCH_DIFF_RANGE=50
+API_WEB3_JSON_RPC_MAX_L2_TO_L1_LOG_PROOF_LEAVES=256
+API_WEB3_JSON_RPC_MAX_BATCH_TRANSACTIONS_PAGE_SIZE=50
+API_WEB3_JSON_RPC_L1_COMMIT_DATA_ENABLED=true
+API_WEB3_JSON_RPC_MAX_VERIFIED_TOKENS=20
+API_WEB3_JSON_RPC_L1_TO_L2_GAS_LIMIT_BUFFER_PERCENT=30
+API_WEB3_JSON_RPC_COMMITMENT_INPUTS_ENABLED=true
+API_WEB3_JSON_RPC_MAX_STORAGE_KEYS_PER_REQUEST=100
+API_WEB3_JSON_RPC_MAX_BATCH_L2_TO_L1_LOG_PROOFS_PAGE_SIZE=100
+API_WEB3_JSON_RPC_CONNECTION_POOL_STATS_ENABLED=true
+API_WEB3_JSON_RPC_MAX_TRANSACTION_DETAILS_BATCH_SIZE=100
+API_WEB3_JSON_RPC_MAX_ACCOUNT_STORAGE_KEYS_PAGE_SIZE=500
+API_WEB3_JSON_RPC_CHAIN_STATS_REFRESH_INTERVAL_SEC=30
+API_WEB3_JSON_RPC_MAX_ACCOUNT_BALANCES_PAGE_SIZE=50
+API_WEB3_JSON_RPC_ACCOUNT_BALANCES_CACHE_TTL_MS=500
+API_WEB3_JSON_RPC_ACCOUNT_BALANCES_CACHE_CAPACITY=100
+API_WEB3_JSON_RPC_UNSAFE_ADMIN_METHODS_ENABLED=true
+API_WEB3_JSON_RPC_MAX_BLOCK_DETAILS_RANGE=50
+API_WEB3_JSON_RPC_MAX_L1_GAS_PRICE_HISTORY_RANGE=500
 API_EXPLORER_PORT="3070"
 API_EXPLORER_URL="http://127.0.0.1:3070"
 API_EXPLORER_NETWORK_STATS_POLLING_INTERVAL="1000"
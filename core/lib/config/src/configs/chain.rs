@@ -99,6 +99,10 @@ pub struct StateKeeperConfig {
     /// Max number of computational gas that validation step is allowed to take.
     pub validation_computational_gas_limit: u32,
     pub save_call_traces: bool,
+    /// Protocol version recorded for L1 batches sealed by this node. Must be set explicitly;
+    /// the state keeper refuses to start if it is lower than the version of the last L1 batch
+    /// recorded in the database.
+    pub protocol_version: u16,
 }
 
 impl StateKeeperConfig {
@@ -210,6 +214,7 @@ mod tests {
                 default_aa_hash: H256::from(&[254; 32]),
                 validation_computational_gas_limit: 10_000_000,
                 save_call_traces: false,
+                protocol_version: 1,
             },
             operations_manager: OperationsManagerConfig {
                 delay_interval: 100,
@@ -254,6 +259,7 @@ CHAIN_STATE_KEEPER_BOOTLOADER_HASH="0xfefefefefefefefefefefefefefefefefefefefefe
 CHAIN_STATE_KEEPER_DEFAULT_AA_HASH="0xfefefefefefefefefefefefefefefefefefefefefefefefefefefefefefefefe"
 CHAIN_STATE_KEEPER_VALIDATION_COMPUTATIONAL_GAS_LIMIT="10000000"
 CHAIN_STATE_KEEPER_SAVE_CALL_TRACES="false"
+CHAIN_STATE_KEEPER_PROTOCOL_VERSION="1"
 CHAIN_OPERATIONS_MANAGER_DELAY_INTERVAL="100"
 CHAIN_MEMPOOL_SYNC_INTERVAL_MS="10"
 CHAIN_MEMPOOL_SYNC_BATCH_SIZE="1000"
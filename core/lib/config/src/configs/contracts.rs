@@ -26,6 +26,11 @@ pub struct ContractsConfig {
     pub l2_weth_bridge_addr: Option<Address>,
     pub l1_allow_list_addr: Address,
     pub l2_testnet_paymaster_addr: Option<Address>,
+    /// Bridgehub proxy on L1. Only set for chains deployed with the shared bridge architecture.
+    pub bridgehub_proxy_addr: Option<Address>,
+    /// Commitment to the recursion scheduler verification key that the L1 verifier checks batch
+    /// proofs against. Not exposed via the API if unset.
+    pub recursion_scheduler_level_vk_hash: Option<H256>,
 }
 
 impl ContractsConfig {
@@ -61,6 +66,10 @@ mod tests {
             l1_weth_bridge_proxy_addr: Some(addr("8656770FA78c830456B00B4fFCeE6b1De0e1b888")),
             l2_weth_bridge_addr: Some(addr("8656770FA78c830456B00B4fFCeE6b1De0e1b888")),
             l2_testnet_paymaster_addr: Some(addr("FC073319977e314F251EAE6ae6bE76B0B3BAeeCF")),
+            bridgehub_proxy_addr: Some(addr("35A54c8C757806eB6820629bc82d90E056394C92")),
+            recursion_scheduler_level_vk_hash: Some(hash(
+                "063c6fb5c70404c2867f413a8e35563ad3d040b1ad8c11786231bfdba7b472c7",
+            )),
         }
     }
 
@@ -85,6 +94,8 @@ CONTRACTS_L2_ERC20_BRIDGE_ADDR="0x8656770FA78c830456B00B4fFCeE6b1De0e1b888"
 CONTRACTS_L1_WETH_BRIDGE_PROXY_ADDR="0x8656770FA78c830456B00B4fFCeE6b1De0e1b888"
 CONTRACTS_L2_WETH_BRIDGE_ADDR="0x8656770FA78c830456B00B4fFCeE6b1De0e1b888"
 CONTRACTS_L2_TESTNET_PAYMASTER_ADDR="FC073319977e314F251EAE6ae6bE76B0B3BAeeCF"
+CONTRACTS_BRIDGEHUB_PROXY_ADDR="0x35A54c8C757806eB6820629bc82d90E056394C92"
+CONTRACTS_RECURSION_SCHEDULER_LEVEL_VK_HASH="0x063c6fb5c70404c2867f413a8e35563ad3d040b1ad8c11786231bfdba7b472c7"
         "#;
         set_env(config);
 
@@ -0,0 +1 @@
+ALTER TABLE l1_batches ADD COLUMN IF NOT EXISTS seal_reason TEXT;
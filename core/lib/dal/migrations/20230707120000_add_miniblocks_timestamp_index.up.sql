@@ -0,0 +1 @@
+CREATE INDEX IF NOT EXISTS miniblocks_timestamp_number_idx ON miniblocks (timestamp, number);
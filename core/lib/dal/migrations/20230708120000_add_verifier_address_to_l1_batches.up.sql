@@ -0,0 +1 @@
+ALTER TABLE l1_batches ADD COLUMN IF NOT EXISTS verifier_address BYTEA;
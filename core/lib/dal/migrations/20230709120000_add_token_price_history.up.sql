@@ -0,0 +1,7 @@
+CREATE TABLE IF NOT EXISTS token_price_history (
+    l1_address BYTEA NOT NULL REFERENCES tokens (l1_address) ON DELETE CASCADE,
+    usd_price NUMERIC NOT NULL,
+    usd_price_updated_at TIMESTAMP NOT NULL,
+    created_at TIMESTAMP NOT NULL,
+    PRIMARY KEY (l1_address, usd_price_updated_at)
+);
@@ -0,0 +1 @@
+ALTER TABLE l1_batches ADD COLUMN IF NOT EXISTS protocol_version INT;
@@ -0,0 +1,2 @@
+DROP INDEX IF EXISTS transactions_initiator_address_miniblock_number_idx;
+DROP INDEX IF EXISTS events_topic1_topic3_miniblock_number_idx;
@@ -0,0 +1,4 @@
+CREATE INDEX IF NOT EXISTS transactions_initiator_address_miniblock_number_idx
+    ON transactions (initiator_address, miniblock_number);
+CREATE INDEX IF NOT EXISTS events_topic1_topic3_miniblock_number_idx
+    ON events (topic1, topic3, miniblock_number);
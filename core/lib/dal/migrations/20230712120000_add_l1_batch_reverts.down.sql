@@ -0,0 +1,2 @@
+ALTER TABLE l1_batches ADD COLUMN IF NOT EXISTS revert_tx_hash BYTEA;
+DROP TABLE IF EXISTS l1_batch_reverts;
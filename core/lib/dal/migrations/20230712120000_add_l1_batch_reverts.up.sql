@@ -0,0 +1,13 @@
+-- Revert records must outlive the reverted L1 batches, which are deleted once the node storage is rolled back.
+CREATE TABLE IF NOT EXISTS l1_batch_reverts (
+    l1_batch_number BIGINT NOT NULL,
+    revert_tx_hash BYTEA NOT NULL,
+    created_at TIMESTAMP NOT NULL,
+    PRIMARY KEY (l1_batch_number, revert_tx_hash)
+);
+
+INSERT INTO l1_batch_reverts (l1_batch_number, revert_tx_hash, created_at)
+    SELECT number, revert_tx_hash, updated_at FROM l1_batches WHERE revert_tx_hash IS NOT NULL
+    ON CONFLICT DO NOTHING;
+
+ALTER TABLE l1_batches DROP COLUMN IF EXISTS revert_tx_hash;
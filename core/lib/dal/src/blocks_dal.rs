@@ -11,7 +11,7 @@ use zksync_types::{
     aggregated_operations::AggregatedActionType,
     block::{BlockGasCount, L1BatchHeader, MiniblockHeader},
     commitment::{BlockMetadata, BlockWithMetadata},
-    L1BatchNumber, MiniblockNumber, H256, MAX_GAS_PER_PUBDATA_BYTE,
+    Address, L1BatchNumber, MiniblockNumber, H256, MAX_GAS_PER_PUBDATA_BYTE,
 };
 
 use crate::{
@@ -155,6 +155,40 @@ impl BlocksDal<'_, '_> {
         }
     }
 
+    /// Records the L1 verifier contract that verified proofs of L1 batches proven by the specified proof transaction.
+    pub async fn set_l1_batches_verifier_address(
+        &mut self,
+        eth_prove_tx_id: u32,
+        verifier_address: Address,
+    ) {
+        sqlx::query!(
+            "UPDATE l1_batches SET verifier_address = $1, updated_at = now() \
+            WHERE eth_prove_tx_id = $2",
+            verifier_address.as_bytes(),
+            eth_prove_tx_id as i32
+        )
+        .execute(self.storage.conn())
+        .await
+        .unwrap();
+    }
+
+    /// Records the hash of the verification key for L1 batches proven by the specified proof transaction.
+    pub async fn set_l1_batches_verification_key_hash(
+        &mut self,
+        eth_prove_tx_id: u32,
+        verification_key_hash: H256,
+    ) {
+        sqlx::query!(
+            "UPDATE l1_batches SET verification_key_hash = $1, updated_at = now() \
+            WHERE eth_prove_tx_id = $2",
+            verification_key_hash.as_bytes(),
+            eth_prove_tx_id as i32
+        )
+        .execute(self.storage.conn())
+        .await
+        .unwrap();
+    }
+
     pub async fn insert_l1_batch(
         &mut self,
         block: &L1BatchHeader,
@@ -289,6 +323,54 @@ impl BlocksDal<'_, '_> {
         .map(Into::into)
     }
 
+    /// Records the name of the rule that caused the L1 batch to be sealed.
+    pub async fn set_l1_batch_seal_reason(&mut self, l1_batch_number: L1BatchNumber, reason: &str) {
+        sqlx::query!(
+            "UPDATE l1_batches SET seal_reason = $2 WHERE number = $1",
+            l1_batch_number.0 as i64,
+            reason
+        )
+        .execute(self.storage.conn())
+        .await
+        .unwrap();
+    }
+
+    /// Records the protocol version the L1 batch was sealed under.
+    pub async fn set_l1_batch_protocol_version(
+        &mut self,
+        l1_batch_number: L1BatchNumber,
+        protocol_version: u16,
+    ) {
+        sqlx::query!(
+            "UPDATE l1_batches SET protocol_version = $2 WHERE number = $1",
+            l1_batch_number.0 as i64,
+            protocol_version as i32
+        )
+        .execute(self.storage.conn())
+        .await
+        .unwrap();
+    }
+
+    /// Records that committed L1 batches after `last_l1_batch_to_keep` were reverted on L1 by the specified
+    /// transaction. Records are stored separately from `l1_batches`, so they survive the node storage rollback.
+    pub async fn insert_l1_batch_reverts(
+        &mut self,
+        last_l1_batch_to_keep: L1BatchNumber,
+        revert_tx_hash: H256,
+    ) {
+        sqlx::query!(
+            "INSERT INTO l1_batch_reverts (l1_batch_number, revert_tx_hash, created_at) \
+            SELECT number, $2, now() FROM l1_batches \
+            WHERE number > $1 AND eth_commit_tx_id IS NOT NULL \
+            ON CONFLICT DO NOTHING",
+            last_l1_batch_to_keep.0 as i64,
+            revert_tx_hash.as_bytes()
+        )
+        .execute(self.storage.conn())
+        .await
+        .unwrap();
+    }
+
     pub async fn mark_miniblocks_as_executed_in_l1_batch(
         &mut self,
         l1_batch_number: L1BatchNumber,
@@ -550,7 +632,9 @@ impl BlocksDal<'_, '_> {
                 l2_l1_compressed_messages, l2_l1_merkle_root, l1_gas_price, l2_fair_gas_price, \
                 rollup_last_leaf_index, zkporter_is_available, bootloader_code_hash, \
                 default_aa_code_hash, base_fee_per_gas, aux_data_hash, pass_through_data_hash, \
-                meta_parameters_hash, skip_proof, gas_per_pubdata_byte_in_block, gas_per_pubdata_limit \
+                meta_parameters_hash, skip_proof, gas_per_pubdata_byte_in_block, gas_per_pubdata_limit, \
+                seal_reason, verifier_address, protocol_version, \
+                verification_key_hash \
             FROM \
             (SELECT l1_batches.*, row_number() OVER (ORDER BY number ASC) AS row_number \
                 FROM l1_batches \
@@ -1,9 +1,11 @@
 use bigdecimal::BigDecimal;
+use sqlx::types::chrono::NaiveDateTime;
 use sqlx::Row;
 
-use std::time::Instant;
+use std::{str::FromStr, time::Instant};
 
 use zksync_config::constants::EMPTY_UNCLES_HASH;
+use zksync_contracts::BaseSystemContractsHashes;
 use zksync_types::{
     api,
     l2_to_l1_log::L2ToL1Log,
@@ -257,6 +259,66 @@ impl BlocksWeb3Dal<'_, '_> {
         Ok(timestamp)
     }
 
+    /// Returns the miniblock closest to `timestamp` according to `rounding`. If several miniblocks
+    /// have the same timestamp, the first one is returned when rounding up and the last one otherwise.
+    pub async fn get_miniblock_by_timestamp(
+        &mut self,
+        timestamp: u64,
+        rounding: api::TimestampRounding,
+    ) -> Result<Option<MiniblockNumber>, SqlxError> {
+        let miniblock = match rounding {
+            api::TimestampRounding::Before => {
+                self.get_last_miniblock_at_or_before(timestamp).await?
+            }
+            api::TimestampRounding::After => {
+                self.get_first_miniblock_at_or_after(timestamp).await?
+            }
+            api::TimestampRounding::Closest => {
+                let before = self.get_last_miniblock_at_or_before(timestamp).await?;
+                let after = self.get_first_miniblock_at_or_after(timestamp).await?;
+                match (before, after) {
+                    (Some(before), Some(after)) => {
+                        if after.1 - timestamp < timestamp - before.1 {
+                            Some(after)
+                        } else {
+                            Some(before)
+                        }
+                    }
+                    (before, after) => before.or(after),
+                }
+            }
+        };
+        Ok(miniblock.map(|(number, _)| number))
+    }
+
+    async fn get_last_miniblock_at_or_before(
+        &mut self,
+        timestamp: u64,
+    ) -> Result<Option<(MiniblockNumber, u64)>, SqlxError> {
+        let row = sqlx::query!(
+            "SELECT number, timestamp FROM miniblocks WHERE timestamp <= $1 \
+            ORDER BY timestamp DESC, number DESC LIMIT 1",
+            timestamp as i64
+        )
+        .fetch_optional(self.storage.conn())
+        .await?;
+        Ok(row.map(|row| (MiniblockNumber(row.number as u32), row.timestamp as u64)))
+    }
+
+    async fn get_first_miniblock_at_or_after(
+        &mut self,
+        timestamp: u64,
+    ) -> Result<Option<(MiniblockNumber, u64)>, SqlxError> {
+        let row = sqlx::query!(
+            "SELECT number, timestamp FROM miniblocks WHERE timestamp >= $1 \
+            ORDER BY timestamp, number LIMIT 1",
+            timestamp as i64
+        )
+        .fetch_optional(self.storage.conn())
+        .await?;
+        Ok(row.map(|row| (MiniblockNumber(row.number as u32), row.timestamp as u64)))
+    }
+
     pub async fn get_l2_to_l1_logs(
         &mut self,
         block_number: L1BatchNumber,
@@ -291,6 +353,127 @@ impl BlocksWeb3Dal<'_, '_> {
         Ok(number.map(|number| L1BatchNumber(number as u32)))
     }
 
+    /// Returns the timestamp of the first miniblock of the pending L1 batch and the number of
+    /// transactions in the pending batch, or `None` if there are no miniblocks in the pending batch.
+    pub async fn get_pending_l1_batch_stats(&mut self) -> Result<Option<(u64, u64)>, SqlxError> {
+        let row = sqlx::query!(
+            "SELECT MIN(timestamp) as \"min_timestamp?\", \
+                SUM(l1_tx_count + l2_tx_count) as \"tx_count?\" \
+            FROM miniblocks \
+            WHERE l1_batch_number IS NULL"
+        )
+        .fetch_one(self.storage.conn())
+        .await?;
+
+        Ok(row
+            .min_timestamp
+            .map(|timestamp| (timestamp as u64, row.tx_count.unwrap_or(0) as u64)))
+    }
+
+    /// Returns up to `limit` most recent L1 batch lifecycle events (sealing, and confirmation
+    /// of commit, prove and execute L1 transactions) in chronological order.
+    pub async fn get_recent_l1_batch_events(
+        &mut self,
+        limit: usize,
+    ) -> Result<Vec<api::L1BatchEvent>, SqlxError> {
+        let rows = sqlx::query!(
+            r#"
+            SELECT number as "number!", stage as "stage!", happened_at as "happened_at!", tx_hash
+            FROM (
+                (
+                    SELECT number, 0 AS stage, created_at AS happened_at, NULL::text AS tx_hash
+                    FROM l1_batches
+                    ORDER BY number DESC
+                    LIMIT $1
+                )
+                UNION ALL
+                (
+                    SELECT l1_batches.number, 1, commit_tx.confirmed_at, commit_tx.tx_hash
+                    FROM l1_batches
+                    JOIN eth_txs_history AS commit_tx
+                        ON l1_batches.eth_commit_tx_id = commit_tx.eth_tx_id AND commit_tx.confirmed_at IS NOT NULL
+                    ORDER BY l1_batches.number DESC
+                    LIMIT $1
+                )
+                UNION ALL
+                (
+                    SELECT l1_batches.number, 2, prove_tx.confirmed_at, prove_tx.tx_hash
+                    FROM l1_batches
+                    JOIN eth_txs_history AS prove_tx
+                        ON l1_batches.eth_prove_tx_id = prove_tx.eth_tx_id AND prove_tx.confirmed_at IS NOT NULL
+                    ORDER BY l1_batches.number DESC
+                    LIMIT $1
+                )
+                UNION ALL
+                (
+                    SELECT l1_batches.number, 3, execute_tx.confirmed_at, execute_tx.tx_hash
+                    FROM l1_batches
+                    JOIN eth_txs_history AS execute_tx
+                        ON l1_batches.eth_execute_tx_id = execute_tx.eth_tx_id AND execute_tx.confirmed_at IS NOT NULL
+                    ORDER BY l1_batches.number DESC
+                    LIMIT $1
+                )
+            ) AS events
+            ORDER BY happened_at DESC, number DESC, stage DESC
+            LIMIT $1
+            "#,
+            limit as i64
+        )
+        .fetch_all(self.storage.conn())
+        .await?;
+
+        let mut events = rows
+            .into_iter()
+            .map(|row| l1_batch_event(row.number, row.stage, row.happened_at, row.tx_hash))
+            .collect::<Result<Vec<_>, _>>()?;
+        events.reverse();
+        Ok(events)
+    }
+
+    /// Returns L1 batch lifecycle events that happened after `from_timestamp` in chronological order,
+    /// together with the time of the last returned event.
+    pub async fn get_l1_batch_events_after(
+        &mut self,
+        from_timestamp: NaiveDateTime,
+    ) -> Result<(Vec<api::L1BatchEvent>, Option<NaiveDateTime>), SqlxError> {
+        let rows = sqlx::query!(
+            r#"
+            SELECT number as "number!", stage as "stage!", happened_at as "happened_at!", tx_hash
+            FROM (
+                SELECT number, 0 AS stage, created_at AS happened_at, NULL::text AS tx_hash
+                FROM l1_batches
+                WHERE created_at > $1
+                UNION ALL
+                SELECT l1_batches.number, 1, commit_tx.confirmed_at, commit_tx.tx_hash
+                FROM l1_batches
+                JOIN eth_txs_history AS commit_tx
+                    ON l1_batches.eth_commit_tx_id = commit_tx.eth_tx_id AND commit_tx.confirmed_at > $1
+                UNION ALL
+                SELECT l1_batches.number, 2, prove_tx.confirmed_at, prove_tx.tx_hash
+                FROM l1_batches
+                JOIN eth_txs_history AS prove_tx
+                    ON l1_batches.eth_prove_tx_id = prove_tx.eth_tx_id AND prove_tx.confirmed_at > $1
+                UNION ALL
+                SELECT l1_batches.number, 3, execute_tx.confirmed_at, execute_tx.tx_hash
+                FROM l1_batches
+                JOIN eth_txs_history AS execute_tx
+                    ON l1_batches.eth_execute_tx_id = execute_tx.eth_tx_id AND execute_tx.confirmed_at > $1
+            ) AS events
+            ORDER BY happened_at, number, stage
+            "#,
+            from_timestamp
+        )
+        .fetch_all(self.storage.conn())
+        .await?;
+
+        let last_happened_at = rows.last().map(|row| row.happened_at);
+        let events = rows
+            .into_iter()
+            .map(|row| l1_batch_event(row.number, row.stage, row.happened_at, row.tx_hash))
+            .collect::<Result<_, _>>()?;
+        Ok((events, last_happened_at))
+    }
+
     pub async fn get_miniblock_range_of_l1_batch(
         &mut self,
         l1_batch_number: L1BatchNumber,
@@ -336,6 +519,222 @@ impl BlocksWeb3Dal<'_, '_> {
         Ok(result)
     }
 
+    /// Returns the protocol version the L1 batch was sealed under, or `None` if the batch doesn't exist
+    /// or was sealed before protocol versions were recorded.
+    pub async fn get_l1_batch_protocol_version(
+        &mut self,
+        l1_batch_number: L1BatchNumber,
+    ) -> Result<Option<u16>, SqlxError> {
+        let row = sqlx::query!(
+            "SELECT protocol_version FROM l1_batches WHERE number = $1",
+            l1_batch_number.0 as i64
+        )
+        .fetch_optional(self.storage.conn())
+        .await?;
+        Ok(row
+            .and_then(|row| row.protocol_version)
+            .map(|version| version as u16))
+    }
+
+    /// Returns the hash of the verification key the L1 batch was proven with. The outer `None` means
+    /// that the batch isn't proven yet (or doesn't exist); the inner one means that the hash wasn't
+    /// recorded for the proven batch (e.g., on external nodes).
+    pub async fn get_l1_batch_verification_key_hash(
+        &mut self,
+        l1_batch_number: L1BatchNumber,
+    ) -> Result<Option<Option<H256>>, SqlxError> {
+        let row = sqlx::query!(
+            "SELECT l1_batches.verification_key_hash FROM l1_batches \
+            JOIN eth_txs_history AS prove_tx \
+                ON (l1_batches.eth_prove_tx_id = prove_tx.eth_tx_id AND prove_tx.confirmed_at IS NOT NULL) \
+            WHERE l1_batches.number = $1",
+            l1_batch_number.0 as i64
+        )
+        .fetch_optional(self.storage.conn())
+        .await?;
+        Ok(row.map(|row| row.verification_key_hash.as_deref().map(H256::from_slice)))
+    }
+
+    /// Returns L1 gas prices used to seal miniblocks in the specified inclusive range.
+    pub async fn get_l1_gas_price_history(
+        &mut self,
+        from_miniblock: MiniblockNumber,
+        to_miniblock: MiniblockNumber,
+    ) -> Result<Vec<(MiniblockNumber, u64)>, SqlxError> {
+        let rows = sqlx::query!(
+            "SELECT number, l1_gas_price FROM miniblocks \
+            WHERE number BETWEEN $1 AND $2 \
+            ORDER BY number",
+            from_miniblock.0 as i64,
+            to_miniblock.0 as i64
+        )
+        .fetch_all(self.storage.conn())
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| (MiniblockNumber(row.number as u32), row.l1_gas_price as u64))
+            .collect())
+    }
+
+    /// Returns aggregate statistics of the L1 batch. Pubdata size is not filled in, since it requires
+    /// the batch metadata.
+    pub async fn get_l1_batch_stats(
+        &mut self,
+        l1_batch_number: L1BatchNumber,
+    ) -> Result<Option<api::L1BatchStats>, SqlxError> {
+        let row = sqlx::query!(
+            r#"
+                SELECT
+                    (SELECT COUNT(*) FROM transactions WHERE l1_batch_number = $1) as "transaction_count!",
+                    (SELECT COALESCE(SUM(gas_limit - refunded_gas), 0)
+                        FROM transactions WHERE l1_batch_number = $1) as "gas_used!",
+                    MIN(timestamp) as "first_miniblock_timestamp?",
+                    MAX(timestamp) as "last_miniblock_timestamp?"
+                FROM miniblocks
+                WHERE l1_batch_number = $1
+            "#,
+            l1_batch_number.0 as i64
+        )
+        .fetch_one(self.storage.conn())
+        .await?;
+
+        let (Some(first_miniblock_timestamp), Some(last_miniblock_timestamp)) =
+            (row.first_miniblock_timestamp, row.last_miniblock_timestamp)
+        else {
+            return Ok(None);
+        };
+        Ok(Some(api::L1BatchStats {
+            l1_batch_number,
+            transaction_count: row.transaction_count as u64,
+            gas_used: bigdecimal_to_u256(row.gas_used),
+            pubdata_bytes: None,
+            first_miniblock_timestamp: first_miniblock_timestamp as u64,
+            last_miniblock_timestamp: last_miniblock_timestamp as u64,
+        }))
+    }
+
+    /// Returns fees collected in the L1 batch along with the costs of the L1 operations for it.
+    /// An L1 transaction handling several batches is split evenly among them. Transactions without
+    /// a recorded effective gas price (e.g., ones executed before EIP-1559 support) don't contribute
+    /// to collected fees.
+    pub async fn get_l1_batch_fees(
+        &mut self,
+        l1_batch_number: L1BatchNumber,
+    ) -> Result<Option<api::L1BatchFees>, SqlxError> {
+        let row = sqlx::query!(
+            r#"
+                WITH batch_eth_txs AS (
+                    SELECT UNNEST(ARRAY[eth_commit_tx_id, eth_prove_tx_id, eth_execute_tx_id]) AS eth_tx_id
+                    FROM l1_batches
+                    WHERE number = $1
+                ),
+                eth_tx_batch_counts AS (
+                    SELECT eth_tx_id, COUNT(*) AS batch_count
+                    FROM (
+                        SELECT eth_commit_tx_id AS eth_tx_id FROM l1_batches
+                            WHERE eth_commit_tx_id IN (SELECT eth_tx_id FROM batch_eth_txs)
+                        UNION ALL
+                        SELECT eth_prove_tx_id FROM l1_batches
+                            WHERE eth_prove_tx_id IN (SELECT eth_tx_id FROM batch_eth_txs)
+                        UNION ALL
+                        SELECT eth_execute_tx_id FROM l1_batches
+                            WHERE eth_execute_tx_id IN (SELECT eth_tx_id FROM batch_eth_txs)
+                    ) AS handled_batches
+                    GROUP BY eth_tx_id
+                )
+                SELECT
+                    (SELECT COALESCE(SUM((gas_limit - refunded_gas) * effective_gas_price), 0)
+                        FROM transactions
+                        WHERE l1_batch_number = $1 AND effective_gas_price IS NOT NULL
+                    ) as "fees_collected!",
+                    (SELECT COALESCE(FLOOR(SUM(
+                        eth_txs.gas_used * (eth_txs_history.base_fee_per_gas + eth_txs_history.priority_fee_per_gas)::numeric
+                        / eth_tx_batch_counts.batch_count
+                    )), 0)
+                        FROM eth_tx_batch_counts
+                        JOIN eth_txs ON eth_txs.id = eth_tx_batch_counts.eth_tx_id
+                        JOIN eth_txs_history ON eth_txs_history.id = eth_txs.confirmed_eth_tx_history_id
+                    ) as "l1_costs!"
+                FROM l1_batches
+                WHERE number = $1
+            "#,
+            l1_batch_number.0 as i64
+        )
+        .fetch_optional(self.storage.conn())
+        .await?;
+
+        Ok(row.map(|row| {
+            let fees_collected = bigdecimal_to_u256(row.fees_collected);
+            let l1_costs = bigdecimal_to_u256(row.l1_costs);
+            api::L1BatchFees {
+                l1_batch_number,
+                fees_collected,
+                l1_costs,
+                net_revenue: fees_collected.saturating_sub(l1_costs),
+            }
+        }))
+    }
+
+    /// Returns the protocol version of the latest L1 batch with a recorded version.
+    pub async fn get_latest_protocol_version(&mut self) -> Result<Option<u16>, SqlxError> {
+        let row = sqlx::query!(
+            "SELECT protocol_version FROM l1_batches \
+            WHERE protocol_version IS NOT NULL \
+            ORDER BY number DESC LIMIT 1"
+        )
+        .fetch_optional(self.storage.conn())
+        .await?;
+        Ok(row
+            .and_then(|row| row.protocol_version)
+            .map(|version| version as u16))
+    }
+
+    /// Returns the timestamp and base system contracts of the first L1 batch sealed under
+    /// the specified protocol version.
+    pub async fn get_protocol_version_activation(
+        &mut self,
+        protocol_version: u16,
+    ) -> Result<Option<(u64, BaseSystemContractsHashes)>, SqlxError> {
+        let started_at = Instant::now();
+        let row = sqlx::query!(
+            "SELECT timestamp, bootloader_code_hash, default_aa_code_hash FROM l1_batches \
+            WHERE protocol_version = $1 \
+            ORDER BY number LIMIT 1",
+            protocol_version as i32
+        )
+        .fetch_optional(self.storage.conn())
+        .await?;
+        metrics::histogram!("dal.request", started_at.elapsed(), "method" => "get_protocol_version_activation");
+
+        Ok(row.and_then(|row| {
+            let hashes = BaseSystemContractsHashes {
+                bootloader: H256::from_slice(&row.bootloader_code_hash?),
+                default_aa: H256::from_slice(&row.default_aa_code_hash?),
+            };
+            Some((row.timestamp as u64, hashes))
+        }))
+    }
+
+    /// Returns the total number of transactions in sealed miniblocks and the number of their distinct initiators.
+    /// Both values require full scans, so they should be cached by the caller.
+    pub async fn get_transaction_and_account_counts(&mut self) -> Result<(u64, u64), SqlxError> {
+        let started_at = Instant::now();
+        let row = sqlx::query!(
+            r#"
+                SELECT
+                    (SELECT COALESCE(SUM(l1_tx_count + l2_tx_count), 0) FROM miniblocks) as "transaction_count!",
+                    (SELECT COUNT(DISTINCT initiator_address) FROM transactions
+                        WHERE miniblock_number IS NOT NULL) as "account_count!"
+            "#
+        )
+        .fetch_one(self.storage.conn())
+        .await?;
+        metrics::histogram!("dal.request", started_at.elapsed(), "method" => "get_transaction_and_account_counts");
+
+        Ok((row.transaction_count as u64, row.account_count as u64))
+    }
+
     pub async fn get_trace_for_miniblock(&mut self, block_number: MiniblockNumber) -> Vec<Call> {
         sqlx::query_as!(
             CallTrace,
@@ -352,13 +751,59 @@ impl BlocksWeb3Dal<'_, '_> {
     }
 }
 
+/// Converts a row of L1 batch event queries. L1 tx hashes are stored as text in `eth_txs_history`,
+/// so a malformed hash is reported as a decoding error rather than causing a panic.
+fn l1_batch_event(
+    number: i64,
+    stage: i32,
+    happened_at: NaiveDateTime,
+    tx_hash: Option<String>,
+) -> Result<api::L1BatchEvent, SqlxError> {
+    let l1_tx_hash = tx_hash
+        .map(|hash| H256::from_str(&hash))
+        .transpose()
+        .map_err(|err| SqlxError::Decode(err.into()))?;
+    Ok(api::L1BatchEvent {
+        l1_batch_number: L1BatchNumber(number as u32),
+        stage: match stage {
+            0 => api::L1BatchStage::Sealed,
+            1 => api::L1BatchStage::Committed,
+            2 => api::L1BatchStage::Proven,
+            _ => api::L1BatchStage::Executed,
+        },
+        timestamp: happened_at.timestamp() as u64,
+        l1_tx_hash,
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use db_test_macro::db_test;
-    use zksync_types::{block::MiniblockHeader, MiniblockNumber};
+    use sqlx::types::chrono::{Duration, Utc};
+    use zksync_types::{
+        aggregated_operations::AggregatedActionType,
+        block::{L1BatchHeader, MiniblockHeader},
+        fee::TransactionExecutionMetrics,
+        Address, MiniblockNumber,
+    };
 
     use super::*;
-    use crate::{tests::create_miniblock_header, ConnectionPool};
+    use crate::{
+        tests::{create_miniblock_header, mock_execution_result, mock_l2_transaction},
+        ConnectionPool, StorageProcessor,
+    };
+
+    async fn insert_l1_batch(conn: &mut StorageProcessor<'_>, number: u32, timestamp: u64) {
+        let header = L1BatchHeader::new(
+            L1BatchNumber(number),
+            timestamp,
+            Default::default(),
+            Default::default(),
+        );
+        conn.blocks_dal()
+            .insert_l1_batch(&header, Default::default())
+            .await;
+    }
 
     #[db_test(dal_crate)]
     async fn getting_web3_block_and_tx_count(connection_pool: ConnectionPool) {
@@ -497,4 +942,416 @@ mod tests {
             .await;
         assert_eq!(miniblock_number.unwrap(), None);
     }
+
+    #[db_test(dal_crate)]
+    async fn getting_l1_batch_protocol_version(connection_pool: ConnectionPool) {
+        let mut conn = connection_pool.access_test_storage().await;
+        conn.blocks_dal().delete_l1_batches(L1BatchNumber(0)).await;
+        for number in 1..=3 {
+            let header = L1BatchHeader::new(
+                L1BatchNumber(number),
+                number.into(),
+                Default::default(),
+                Default::default(),
+            );
+            conn.blocks_dal()
+                .insert_l1_batch(&header, Default::default())
+                .await;
+        }
+        // Batch #1 is sealed before protocol versions were recorded.
+        conn.blocks_dal()
+            .set_l1_batch_protocol_version(L1BatchNumber(2), 1)
+            .await;
+        conn.blocks_dal()
+            .set_l1_batch_protocol_version(L1BatchNumber(3), 2)
+            .await;
+
+        let expected_versions = [(1, None), (2, Some(1)), (3, Some(2)), (4, None)];
+        for (number, expected_version) in expected_versions {
+            let protocol_version = conn
+                .blocks_web3_dal()
+                .get_l1_batch_protocol_version(L1BatchNumber(number))
+                .await
+                .unwrap();
+            assert_eq!(protocol_version, expected_version, "batch #{number}");
+        }
+    }
+
+    #[db_test(dal_crate)]
+    async fn getting_protocol_version_activation(connection_pool: ConnectionPool) {
+        let mut conn = connection_pool.access_test_storage().await;
+        conn.blocks_dal().delete_l1_batches(L1BatchNumber(0)).await;
+        let latest_version = conn.blocks_web3_dal().get_latest_protocol_version().await;
+        assert_eq!(latest_version.unwrap(), None);
+
+        let protocol_versions = [(1, None), (2, Some(1)), (3, Some(1)), (4, Some(2))];
+        for (number, protocol_version) in protocol_versions {
+            let header = L1BatchHeader::new(
+                L1BatchNumber(number),
+                u64::from(number) * 10,
+                Default::default(),
+                Default::default(),
+            );
+            conn.blocks_dal()
+                .insert_l1_batch(&header, Default::default())
+                .await;
+            if let Some(protocol_version) = protocol_version {
+                conn.blocks_dal()
+                    .set_l1_batch_protocol_version(L1BatchNumber(number), protocol_version)
+                    .await;
+            }
+        }
+
+        let latest_version = conn.blocks_web3_dal().get_latest_protocol_version().await;
+        assert_eq!(latest_version.unwrap(), Some(2));
+        let expected_timestamps = [(0, None), (1, Some(20)), (2, Some(40)), (3, None)];
+        for (protocol_version, expected_timestamp) in expected_timestamps {
+            let activation = conn
+                .blocks_web3_dal()
+                .get_protocol_version_activation(protocol_version)
+                .await
+                .unwrap();
+            let timestamp = activation.map(|(timestamp, _)| timestamp);
+            assert_eq!(timestamp, expected_timestamp, "version {protocol_version}");
+        }
+    }
+
+    #[db_test(dal_crate)]
+    async fn getting_l1_batch_verification_key_hash(connection_pool: ConnectionPool) {
+        let mut conn = connection_pool.access_test_storage().await;
+        conn.blocks_dal().delete_genesis().await;
+        for number in 1..=3 {
+            insert_l1_batch(&mut conn, number, number.into()).await;
+        }
+        for number in 1..=2 {
+            conn.eth_sender_dal()
+                .insert_bogus_confirmed_eth_tx(
+                    L1BatchNumber(number),
+                    AggregatedActionType::PublishProofBlocksOnchain,
+                    H256::from_low_u64_be(number.into()),
+                    Utc::now(),
+                )
+                .await;
+        }
+        let eth_prove_tx_id: i32 =
+            sqlx::query_scalar("SELECT eth_prove_tx_id FROM l1_batches WHERE number = 1")
+                .fetch_one(conn.conn())
+                .await
+                .unwrap();
+        let vk_hash = H256::repeat_byte(0x42);
+        conn.blocks_dal()
+            .set_l1_batches_verification_key_hash(eth_prove_tx_id as u32, vk_hash)
+            .await;
+
+        let expected_hashes = [
+            (1, Some(Some(vk_hash))),
+            (2, Some(None)),
+            (3, None),
+            (4, None),
+        ];
+        for (number, expected_hash) in expected_hashes {
+            let vk_hash = conn
+                .blocks_web3_dal()
+                .get_l1_batch_verification_key_hash(L1BatchNumber(number))
+                .await
+                .unwrap();
+            assert_eq!(vk_hash, expected_hash, "batch #{number}");
+        }
+    }
+
+    #[db_test(dal_crate)]
+    async fn getting_recent_l1_batch_events(connection_pool: ConnectionPool) {
+        let mut conn = connection_pool.access_test_storage().await;
+        conn.blocks_dal().delete_genesis().await;
+        let events = conn
+            .blocks_web3_dal()
+            .get_recent_l1_batch_events(10)
+            .await
+            .unwrap();
+        assert!(events.is_empty());
+
+        for number in 1..=3 {
+            insert_l1_batch(&mut conn, number, number.into()).await;
+        }
+        // Batches are sealed at the start of the DB transaction, so L1 confirmations happen after that.
+        let now = Utc::now();
+        let l1_txs = [
+            (1, AggregatedActionType::CommitBlocks, 10),
+            (1, AggregatedActionType::PublishProofBlocksOnchain, 20),
+            (2, AggregatedActionType::CommitBlocks, 30),
+        ];
+        for (number, action, delay) in l1_txs {
+            conn.eth_sender_dal()
+                .insert_bogus_confirmed_eth_tx(
+                    L1BatchNumber(number),
+                    action,
+                    H256::from_low_u64_be(delay as u64),
+                    now + Duration::seconds(delay),
+                )
+                .await;
+        }
+
+        let all_events = conn
+            .blocks_web3_dal()
+            .get_recent_l1_batch_events(10)
+            .await
+            .unwrap();
+        let all_events: Vec<_> = all_events
+            .iter()
+            .map(|event| (event.l1_batch_number.0, event.stage, event.l1_tx_hash))
+            .collect();
+        assert_eq!(
+            all_events,
+            [
+                (1, api::L1BatchStage::Sealed, None),
+                (2, api::L1BatchStage::Sealed, None),
+                (3, api::L1BatchStage::Sealed, None),
+                (
+                    1,
+                    api::L1BatchStage::Committed,
+                    Some(H256::from_low_u64_be(10))
+                ),
+                (
+                    1,
+                    api::L1BatchStage::Proven,
+                    Some(H256::from_low_u64_be(20))
+                ),
+                (
+                    2,
+                    api::L1BatchStage::Committed,
+                    Some(H256::from_low_u64_be(30))
+                ),
+            ]
+        );
+
+        // The limit keeps the most recent events, still in chronological order.
+        for limit in [1, 2, 4] {
+            let events = conn
+                .blocks_web3_dal()
+                .get_recent_l1_batch_events(limit)
+                .await
+                .unwrap();
+            let events: Vec<_> = events
+                .iter()
+                .map(|event| (event.l1_batch_number.0, event.stage, event.l1_tx_hash))
+                .collect();
+            assert_eq!(
+                events,
+                all_events[all_events.len() - limit..],
+                "limit {limit}"
+            );
+        }
+    }
+
+    async fn insert_miniblock(conn: &mut StorageProcessor<'_>, number: u32, timestamp: u64) {
+        let header = MiniblockHeader {
+            timestamp,
+            ..create_miniblock_header(number)
+        };
+        conn.blocks_dal().insert_miniblock(&header).await;
+    }
+
+    #[db_test(dal_crate)]
+    async fn getting_l1_batch_stats(connection_pool: ConnectionPool) {
+        let mut conn = connection_pool.access_test_storage().await;
+        conn.blocks_dal().delete_genesis().await;
+
+        insert_l1_batch(&mut conn, 1, 10).await;
+        insert_miniblock(&mut conn, 1, 10).await;
+        insert_miniblock(&mut conn, 2, 20).await;
+        let tx = mock_l2_transaction();
+        let gas_limit = tx.common_data.fee.gas_limit;
+        conn.transactions_dal()
+            .insert_transaction_l2(tx.clone(), TransactionExecutionMetrics::default())
+            .await;
+        let tx_results = [mock_execution_result(tx)];
+        conn.transactions_dal()
+            .mark_txs_as_executed_in_miniblock(MiniblockNumber(1), &tx_results, U256::from(1))
+            .await;
+        conn.blocks_dal()
+            .mark_miniblocks_as_executed_in_l1_batch(L1BatchNumber(1))
+            .await;
+        conn.transactions_dal()
+            .mark_txs_as_executed_in_l1_batch(L1BatchNumber(1), &tx_results)
+            .await;
+        // The second batch has no transactions.
+        insert_l1_batch(&mut conn, 2, 30).await;
+        insert_miniblock(&mut conn, 3, 30).await;
+        conn.blocks_dal()
+            .mark_miniblocks_as_executed_in_l1_batch(L1BatchNumber(2))
+            .await;
+
+        let stats = conn
+            .blocks_web3_dal()
+            .get_l1_batch_stats(L1BatchNumber(1))
+            .await
+            .unwrap()
+            .expect("no stats for batch #1");
+        assert_eq!(stats.l1_batch_number, L1BatchNumber(1));
+        assert_eq!(stats.transaction_count, 1);
+        assert_eq!(stats.gas_used, gas_limit);
+        assert_eq!(stats.pubdata_bytes, None);
+        assert_eq!(stats.first_miniblock_timestamp, 10);
+        assert_eq!(stats.last_miniblock_timestamp, 20);
+
+        let stats = conn
+            .blocks_web3_dal()
+            .get_l1_batch_stats(L1BatchNumber(2))
+            .await
+            .unwrap()
+            .expect("no stats for batch #2");
+        assert_eq!(stats.transaction_count, 0);
+        assert_eq!(stats.gas_used, U256::zero());
+        assert_eq!(stats.first_miniblock_timestamp, 30);
+        assert_eq!(stats.last_miniblock_timestamp, 30);
+
+        for missing_batch in [0, 3] {
+            let stats = conn
+                .blocks_web3_dal()
+                .get_l1_batch_stats(L1BatchNumber(missing_batch))
+                .await
+                .unwrap();
+            assert!(stats.is_none(), "batch #{missing_batch}");
+        }
+    }
+
+    #[db_test(dal_crate)]
+    async fn getting_l1_batch_fees(connection_pool: ConnectionPool) {
+        let mut conn = connection_pool.access_test_storage().await;
+        conn.blocks_dal().delete_genesis().await;
+
+        insert_l1_batch(&mut conn, 1, 10).await;
+        insert_miniblock(&mut conn, 1, 10).await;
+        let txs = [mock_l2_transaction(), mock_l2_transaction()];
+        for tx in &txs {
+            conn.transactions_dal()
+                .insert_transaction_l2(tx.clone(), TransactionExecutionMetrics::default())
+                .await;
+        }
+        let tx_results: Vec<_> = txs.iter().cloned().map(mock_execution_result).collect();
+        conn.transactions_dal()
+            .mark_txs_as_executed_in_miniblock(MiniblockNumber(1), &tx_results, U256::from(1))
+            .await;
+        conn.blocks_dal()
+            .mark_miniblocks_as_executed_in_l1_batch(L1BatchNumber(1))
+            .await;
+        conn.transactions_dal()
+            .mark_txs_as_executed_in_l1_batch(L1BatchNumber(1), &tx_results)
+            .await;
+        // Emulate a transaction without the recorded effective gas price.
+        sqlx::query("UPDATE transactions SET effective_gas_price = NULL WHERE hash = $1")
+            .bind(txs[1].hash().as_bytes().to_vec())
+            .execute(conn.conn())
+            .await
+            .unwrap();
+        insert_l1_batch(&mut conn, 2, 20).await;
+
+        // The commit transaction is shared by both batches; the proof one only handles batch #1.
+        let l1_txs = [
+            (AggregatedActionType::CommitBlocks, 1..=2, 12, 1_000),
+            (
+                AggregatedActionType::PublishProofBlocksOnchain,
+                1..=1,
+                1,
+                100,
+            ),
+        ];
+        for (i, (tx_type, batches, gas_price, gas_used)) in l1_txs.into_iter().enumerate() {
+            let eth_tx = conn
+                .eth_sender_dal()
+                .save_eth_tx(i as u64, vec![], tx_type, Address::zero(), 0)
+                .await;
+            let tx_hash = H256::from_low_u64_be(i as u64 + 1);
+            conn.eth_sender_dal()
+                .insert_tx_history(eth_tx.id, gas_price, 0, tx_hash, vec![])
+                .await
+                .unwrap();
+            conn.eth_sender_dal()
+                .confirm_tx(tx_hash, U256::from(gas_used))
+                .await;
+            conn.blocks_dal()
+                .set_eth_tx_id(
+                    L1BatchNumber(*batches.start()),
+                    L1BatchNumber(*batches.end()),
+                    eth_tx.id,
+                    tx_type,
+                )
+                .await;
+        }
+
+        let fees = conn
+            .blocks_web3_dal()
+            .get_l1_batch_fees(L1BatchNumber(1))
+            .await
+            .unwrap()
+            .expect("no fees for batch #1");
+        // Only the transaction with the effective gas price (equal to the base fee) is accounted for.
+        assert_eq!(fees.fees_collected, txs[0].common_data.fee.gas_limit);
+        assert_eq!(fees.l1_costs, U256::from(12 * 1_000 / 2 + 100));
+
+        let fees = conn
+            .blocks_web3_dal()
+            .get_l1_batch_fees(L1BatchNumber(2))
+            .await
+            .unwrap()
+            .expect("no fees for batch #2");
+        assert_eq!(fees.fees_collected, U256::zero());
+        assert_eq!(fees.l1_costs, U256::from(12 * 1_000 / 2));
+
+        let fees = conn
+            .blocks_web3_dal()
+            .get_l1_batch_fees(L1BatchNumber(3))
+            .await
+            .unwrap();
+        assert!(fees.is_none());
+    }
+
+    #[db_test(dal_crate)]
+    async fn getting_l2_to_l1_logs_of_multi_message_tx(connection_pool: ConnectionPool) {
+        let mut conn = connection_pool.access_test_storage().await;
+        conn.blocks_dal().delete_l1_batches(L1BatchNumber(0)).await;
+
+        // Transaction #1 sends several messages interleaved with messages of other transactions.
+        let tx_numbers_in_block = [0_u16, 1, 1, 2, 1];
+        let logs: Vec<_> = tx_numbers_in_block
+            .into_iter()
+            .enumerate()
+            .map(|(i, tx_number_in_block)| L2ToL1Log {
+                tx_number_in_block,
+                key: H256::from_low_u64_be(i as u64),
+                value: H256::repeat_byte(i as u8),
+                ..L2ToL1Log::default()
+            })
+            .collect();
+        let mut header = L1BatchHeader::new(
+            L1BatchNumber(1),
+            100,
+            Default::default(),
+            Default::default(),
+        );
+        header.l2_to_l1_logs = logs.clone();
+        conn.blocks_dal()
+            .insert_l1_batch(&header, Default::default())
+            .await;
+
+        let stored_logs = conn
+            .blocks_web3_dal()
+            .get_l2_to_l1_logs(L1BatchNumber(1))
+            .await
+            .unwrap();
+        assert_eq!(stored_logs, logs);
+        let tx_logs: Vec<_> = stored_logs
+            .iter()
+            .filter(|log| log.tx_number_in_block == 1)
+            .map(|log| log.key)
+            .collect();
+        assert_eq!(tx_logs, [1_u64, 2, 4].map(H256::from_low_u64_be));
+
+        let missing_logs = conn
+            .blocks_web3_dal()
+            .get_l2_to_l1_logs(L1BatchNumber(2))
+            .await
+            .unwrap();
+        assert!(missing_logs.is_empty());
+    }
 }
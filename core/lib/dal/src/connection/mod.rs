@@ -1,4 +1,8 @@
 // Built-in deps
+use std::sync::{
+    atomic::{AtomicU64, Ordering},
+    Arc,
+};
 use std::time::{Duration, Instant};
 // External imports
 use sqlx::pool::PoolConnection;
@@ -16,10 +20,42 @@ pub mod test_pool;
 
 #[derive(Clone, Debug)]
 pub enum ConnectionPool {
-    Real(PgPool),
+    Real(PgPool, Arc<AcquireStats>),
     Test(TestPool),
 }
 
+/// Connection acquisition statistics collected by a pool since its creation.
+#[derive(Debug, Default)]
+pub struct AcquireStats {
+    count: AtomicU64,
+    total_wait_us: AtomicU64,
+    max_wait_us: AtomicU64,
+}
+
+impl AcquireStats {
+    fn observe(&self, wait: Duration) {
+        let wait_us = wait.as_micros() as u64;
+        self.count.fetch_add(1, Ordering::Relaxed);
+        self.total_wait_us.fetch_add(wait_us, Ordering::Relaxed);
+        self.max_wait_us.fetch_max(wait_us, Ordering::Relaxed);
+    }
+}
+
+/// Snapshot of the connection pool state.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ConnectionPoolStats {
+    /// Number of open connections, both idle and in use.
+    pub size: u32,
+    /// Number of idle connections.
+    pub idle: u32,
+    /// Number of connections acquired since the pool creation.
+    pub acquired_count: u64,
+    /// Mean time spent waiting for a connection.
+    pub mean_acquire_time: Duration,
+    /// Max time spent waiting for a connection.
+    pub max_acquire_time: Duration,
+}
+
 #[derive(Clone, Debug)]
 pub enum DbVariant {
     Master,
@@ -41,7 +77,28 @@ impl ConnectionPool {
 
         let options = PgPoolOptions::new().max_connections(max_connections);
         let pool = options.connect(&database_url).await.unwrap();
-        Self::Real(pool)
+        Self::Real(pool, Arc::default())
+    }
+
+    /// Returns the current state of the pool. For test pools, returns zeroed stats.
+    pub fn stats(&self) -> ConnectionPoolStats {
+        match self {
+            ConnectionPool::Real(real_pool, acquire_stats) => {
+                let acquired_count = acquire_stats.count.load(Ordering::Relaxed);
+                let total_wait_us = acquire_stats.total_wait_us.load(Ordering::Relaxed);
+                let mean_wait_us = total_wait_us.checked_div(acquired_count).unwrap_or(0);
+                ConnectionPoolStats {
+                    size: real_pool.size(),
+                    idle: real_pool.num_idle() as u32,
+                    acquired_count,
+                    mean_acquire_time: Duration::from_micros(mean_wait_us),
+                    max_acquire_time: Duration::from_micros(
+                        acquire_stats.max_wait_us.load(Ordering::Relaxed),
+                    ),
+                }
+            }
+            ConnectionPool::Test(_) => ConnectionPoolStats::default(),
+        }
     }
 
     /// Creates a `StorageProcessor` entity over a recoverable connection.
@@ -67,9 +124,10 @@ impl ConnectionPool {
 
     async fn access_storage_inner(&self, requester: Option<&'static str>) -> StorageProcessor<'_> {
         match self {
-            ConnectionPool::Real(real_pool) => {
+            ConnectionPool::Real(real_pool, acquire_stats) => {
                 let start = Instant::now();
                 let conn = Self::acquire_connection_retried(real_pool).await;
+                acquire_stats.observe(start.elapsed());
                 metrics::histogram!("sql.connection_acquire", start.elapsed());
                 if let Some(requester) = requester {
                     metrics::histogram!("sql.connection_acquire.tagged", start.elapsed(), "requester" => requester);
@@ -107,7 +165,7 @@ impl ConnectionPool {
     pub async fn access_test_storage(&self) -> StorageProcessor<'static> {
         match self {
             ConnectionPool::Test(test) => test.access_storage().await,
-            ConnectionPool::Real(_) => {
+            ConnectionPool::Real(..) => {
                 panic!("Attempt to access test storage with the real pool");
             }
         }
@@ -1,14 +1,16 @@
 use std::collections::HashMap;
 
+use zksync_config::constants::ERC20_TRANSFER_TOPIC;
 use zksync_types::{
     api,
     explorer_api::{AccountType, BalanceItem, ExplorerTokenInfo},
     get_code_key,
     tokens::ETHEREUM_ADDRESS,
     utils::storage_key_for_standard_token_balance,
-    AccountTreeId, Address, Nonce, FAILED_CONTRACT_DEPLOYMENT_BYTECODE_HASH, L2_ETH_TOKEN_ADDRESS,
-    U256,
+    AccountTreeId, Address, MiniblockNumber, Nonce, FAILED_CONTRACT_DEPLOYMENT_BYTECODE_HASH, H256,
+    L2_ETH_TOKEN_ADDRESS, U256,
 };
+use zksync_utils::address_to_h256;
 
 use crate::{SqlxError, StorageProcessor};
 
@@ -89,6 +91,140 @@ impl ExplorerAccountsDal<'_, '_> {
         }
     }
 
+    /// Returns up to `limit` non-zero balances of the address, ordered by token L2 address
+    /// and starting right after `after_token`.
+    pub async fn get_balances_for_address_paged(
+        &mut self,
+        address: Address,
+        after_token: Option<Address>,
+        limit: usize,
+    ) -> Result<Vec<(Address, U256)>, SqlxError> {
+        {
+            let token_l2_addresses = self
+                .storage
+                .explorer()
+                .misc_dal()
+                .get_well_known_token_l2_addresses()
+                .await?;
+            let hashed_keys: Vec<Vec<u8>> = token_l2_addresses
+                .into_iter()
+                .map(|mut l2_token_address| {
+                    if l2_token_address == ETHEREUM_ADDRESS {
+                        l2_token_address = L2_ETH_TOKEN_ADDRESS;
+                    }
+                    storage_key_for_standard_token_balance(
+                        AccountTreeId::new(l2_token_address),
+                        &address,
+                    )
+                    .hashed_key()
+                    .0
+                    .to_vec()
+                })
+                .collect();
+            let rows = sqlx::query!(
+                r#"
+                    SELECT storage.value as "value!", tokens.l2_address as "l2_address!"
+                        FROM storage
+                    INNER JOIN tokens ON
+                        storage.address = tokens.l2_address OR (storage.address = $2 AND tokens.l2_address = $3)
+                    WHERE storage.hashed_key = ANY($1) AND storage.value != $4
+                        AND ($5::bytea IS NULL OR tokens.l2_address > $5)
+                    ORDER BY tokens.l2_address
+                    LIMIT $6
+                "#,
+                &hashed_keys,
+                L2_ETH_TOKEN_ADDRESS.as_bytes(),
+                ETHEREUM_ADDRESS.as_bytes(),
+                H256::zero().as_bytes(),
+                after_token.as_ref().map(Address::as_bytes),
+                limit as i64
+            )
+            .fetch_all(self.storage.conn())
+            .await?;
+            let result = rows
+                .into_iter()
+                .map(|row| {
+                    (
+                        Address::from_slice(&row.l2_address),
+                        U256::from_big_endian(&row.value),
+                    )
+                })
+                .collect();
+            Ok(result)
+        }
+    }
+
+    /// Returns aggregated activity of the address. A transaction is counted as received
+    /// if it transferred tokens to the address and wasn't initiated by the address itself.
+    pub async fn get_address_activity(
+        &mut self,
+        address: Address,
+    ) -> Result<api::AddressActivity, SqlxError> {
+        {
+            let padded_address = address_to_h256(&address);
+            let row = sqlx::query!(
+                r#"
+                    SELECT
+                        (SELECT COUNT(*) FROM transactions
+                            WHERE initiator_address = $1 AND miniblock_number IS NOT NULL) as "sent_count!",
+                        (SELECT COUNT(DISTINCT tx_hash) FROM events
+                            WHERE topic1 = $2 AND topic3 = $3 AND tx_initiator_address != $1) as "received_count!",
+                        LEAST(
+                            (SELECT MIN(miniblock_number) FROM transactions WHERE initiator_address = $1),
+                            (SELECT MIN(miniblock_number) FROM events WHERE topic1 = $2 AND topic3 = $3)
+                        ) as "first_seen?",
+                        GREATEST(
+                            (SELECT MAX(miniblock_number) FROM transactions WHERE initiator_address = $1),
+                            (SELECT MAX(miniblock_number) FROM events WHERE topic1 = $2 AND topic3 = $3)
+                        ) as "last_seen?"
+                "#,
+                address.as_bytes(),
+                ERC20_TRANSFER_TOPIC.as_bytes(),
+                padded_address.as_bytes()
+            )
+            .fetch_one(self.storage.conn())
+            .await?;
+            let tokens_held = self.get_balances_for_address(address).await?.len();
+
+            Ok(api::AddressActivity {
+                sent_tx_count: row.sent_count as u64,
+                received_tx_count: row.received_count as u64,
+                first_seen_block: row.first_seen.map(|number| MiniblockNumber(number as u32)),
+                last_seen_block: row.last_seen.map(|number| MiniblockNumber(number as u32)),
+                tokens_held: tokens_held as u32,
+            })
+        }
+    }
+
+    /// Returns the number of transactions initiated by the address, split by their status.
+    /// Rejected transactions are counted as failed.
+    pub async fn get_address_transaction_count(
+        &mut self,
+        address: Address,
+    ) -> Result<api::AddressTransactionCount, SqlxError> {
+        {
+            let row = sqlx::query!(
+                r#"
+                    SELECT
+                        COUNT(*) FILTER (WHERE miniblock_number IS NOT NULL AND error IS NULL) as "successful!",
+                        COUNT(*) FILTER (WHERE error IS NOT NULL) as "failed!",
+                        COUNT(*) FILTER (WHERE miniblock_number IS NULL AND error IS NULL) as "pending!"
+                    FROM transactions
+                    WHERE initiator_address = $1
+                "#,
+                address.as_bytes()
+            )
+            .fetch_one(self.storage.conn())
+            .await?;
+
+            Ok(api::AddressTransactionCount {
+                successful: row.successful as u64,
+                failed: row.failed as u64,
+                pending: row.pending as u64,
+            })
+        }
+    }
+
     /// Returns sealed and verified nonces for address.
     pub async fn get_account_nonces(
         &mut self,
@@ -149,3 +285,215 @@ impl ExplorerAccountsDal<'_, '_> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        tests::{create_miniblock_header, mock_execution_result, mock_l2_transaction},
+        ConnectionPool,
+    };
+    use db_test_macro::db_test;
+    use zksync_types::{
+        fee::TransactionExecutionMetrics,
+        tokens::{TokenInfo, TokenMetadata},
+        tx::IncludedTxLocation,
+        L1BatchNumber, StorageLog, VmEvent,
+    };
+
+    async fn add_well_known_tokens(conn: &mut StorageProcessor<'_>, tokens: &[Address]) {
+        let token_infos = tokens
+            .iter()
+            .map(|&address| TokenInfo {
+                l1_address: address,
+                l2_address: address,
+                metadata: TokenMetadata::default(address),
+            })
+            .collect();
+        conn.tokens_dal().add_tokens(token_infos).await;
+        for address in tokens {
+            conn.tokens_dal()
+                .update_well_known_l1_token(address, TokenMetadata::default(*address))
+                .await;
+        }
+    }
+
+    async fn set_balances(
+        conn: &mut StorageProcessor<'_>,
+        account: Address,
+        balances: &[(Address, u64)],
+    ) {
+        let storage_logs = balances
+            .iter()
+            .map(|&(token, balance)| {
+                let key =
+                    storage_key_for_standard_token_balance(AccountTreeId::new(token), &account);
+                StorageLog::new_write_log(key, H256::from_low_u64_be(balance))
+            })
+            .collect();
+        conn.storage_dal()
+            .apply_storage_logs(&[(H256::zero(), storage_logs)])
+            .await;
+    }
+
+    #[db_test(dal_crate)]
+    async fn paginating_balances_for_address(connection_pool: ConnectionPool) {
+        let mut conn = connection_pool.access_test_storage().await;
+        let account = Address::repeat_byte(0xaa);
+        let tokens: Vec<_> = (1..=4).map(Address::repeat_byte).collect();
+        add_well_known_tokens(&mut conn, &tokens).await;
+        // The third token has a zero balance and must be skipped.
+        set_balances(
+            &mut conn,
+            account,
+            &[
+                (tokens[0], 1),
+                (tokens[1], 2),
+                (tokens[2], 0),
+                (tokens[3], 4),
+            ],
+        )
+        .await;
+
+        let first_page = conn
+            .explorer()
+            .accounts_dal()
+            .get_balances_for_address_paged(account, None, 2)
+            .await
+            .unwrap();
+        assert_eq!(
+            first_page,
+            [(tokens[0], U256::from(1)), (tokens[1], U256::from(2))]
+        );
+
+        // The cursor is exclusive: the page starts right after the given token.
+        let second_page = conn
+            .explorer()
+            .accounts_dal()
+            .get_balances_for_address_paged(account, Some(tokens[1]), 2)
+            .await
+            .unwrap();
+        assert_eq!(second_page, [(tokens[3], U256::from(4))]);
+
+        let last_page = conn
+            .explorer()
+            .accounts_dal()
+            .get_balances_for_address_paged(account, Some(tokens[3]), 2)
+            .await
+            .unwrap();
+        assert!(last_page.is_empty());
+
+        let unknown_account_page = conn
+            .explorer()
+            .accounts_dal()
+            .get_balances_for_address_paged(Address::repeat_byte(0xbb), None, 2)
+            .await
+            .unwrap();
+        assert!(unknown_account_page.is_empty());
+    }
+
+    fn transfer_event(token: Address, from: Address, to: Address) -> VmEvent {
+        VmEvent {
+            location: (L1BatchNumber(1), 0),
+            address: token,
+            indexed_topics: vec![
+                ERC20_TRANSFER_TOPIC,
+                address_to_h256(&from),
+                address_to_h256(&to),
+            ],
+            value: vec![],
+        }
+    }
+
+    #[db_test(dal_crate)]
+    async fn getting_address_activity(connection_pool: ConnectionPool) {
+        let mut conn = connection_pool.access_test_storage().await;
+        conn.events_dal().rollback_events(MiniblockNumber(0)).await;
+        conn.blocks_dal()
+            .delete_miniblocks(MiniblockNumber(0))
+            .await;
+        for number in 1..=3 {
+            conn.blocks_dal()
+                .insert_miniblock(&create_miniblock_header(number))
+                .await;
+        }
+
+        let tx = mock_l2_transaction();
+        let account = tx.initiator_account();
+        conn.transactions_dal()
+            .insert_transaction_l2(tx.clone(), TransactionExecutionMetrics::default())
+            .await;
+        conn.transactions_dal()
+            .mark_txs_as_executed_in_miniblock(
+                MiniblockNumber(1),
+                &[mock_execution_result(tx.clone())],
+                U256::from(1),
+            )
+            .await;
+
+        let token = Address::repeat_byte(1);
+        let sender = Address::repeat_byte(0xbb);
+        // A transfer to self in an own transaction doesn't count as received.
+        let self_transfer = transfer_event(token, account, account);
+        let own_location = IncludedTxLocation {
+            tx_hash: tx.hash(),
+            tx_index_in_miniblock: 0,
+            tx_initiator_address: account,
+        };
+        conn.events_dal()
+            .save_events(MiniblockNumber(1), &[(own_location, vec![&self_transfer])])
+            .await;
+        // Several transfers in a single transaction are counted once.
+        let incoming_transfer = transfer_event(token, sender, account);
+        let incoming_location = IncludedTxLocation {
+            tx_hash: H256::repeat_byte(0xcc),
+            tx_index_in_miniblock: 0,
+            tx_initiator_address: sender,
+        };
+        conn.events_dal()
+            .save_events(
+                MiniblockNumber(3),
+                &[(
+                    incoming_location,
+                    vec![&incoming_transfer, &incoming_transfer],
+                )],
+            )
+            .await;
+        add_well_known_tokens(&mut conn, &[token]).await;
+        set_balances(&mut conn, account, &[(token, 1)]).await;
+
+        let activity = conn
+            .explorer()
+            .accounts_dal()
+            .get_address_activity(account)
+            .await
+            .unwrap();
+        assert_eq!(
+            activity,
+            api::AddressActivity {
+                sent_tx_count: 1,
+                received_tx_count: 1,
+                first_seen_block: Some(MiniblockNumber(1)),
+                last_seen_block: Some(MiniblockNumber(3)),
+                tokens_held: 1,
+            }
+        );
+
+        let unknown_account_activity = conn
+            .explorer()
+            .accounts_dal()
+            .get_address_activity(Address::repeat_byte(0xdd))
+            .await
+            .unwrap();
+        assert_eq!(
+            unknown_account_activity,
+            api::AddressActivity {
+                sent_tx_count: 0,
+                received_tx_count: 0,
+                first_seen_block: None,
+                last_seen_block: None,
+                tokens_held: 0,
+            }
+        );
+    }
+}
@@ -1,10 +1,10 @@
 use std::time::Instant;
 
 use zksync_types::explorer_api::{
-    BlockDetails, BlockPageItem, BlocksQuery, L1BatchDetails, L1BatchPageItem, L1BatchesQuery,
-    PaginationDirection,
+    BlockDetails, BlockHeaderDetails, BlockPageItem, BlocksQuery, L1BatchDetails, L1BatchPageItem,
+    L1BatchesQuery, PaginationDirection,
 };
-use zksync_types::{Address, L1BatchNumber, MiniblockNumber};
+use zksync_types::{Address, L1BatchNumber, MiniblockNumber, H256};
 
 use crate::models::storage_block::{
     block_page_item_from_storage, l1_batch_page_item_from_storage, StorageBlockDetails,
@@ -105,6 +105,95 @@ impl ExplorerBlocksDal<'_, '_> {
         }
     }
 
+    pub async fn get_block_details_range(
+        &mut self,
+        from_block: MiniblockNumber,
+        to_block: MiniblockNumber,
+        current_operator_address: Address,
+    ) -> Result<Vec<BlockDetails>, SqlxError> {
+        {
+            let started_at = Instant::now();
+            let storage_block_details: Vec<StorageBlockDetails> = sqlx::query_as!(
+                StorageBlockDetails,
+                r#"
+                    SELECT miniblocks.number,
+                        COALESCE(miniblocks.l1_batch_number, (SELECT (max(number) + 1) FROM l1_batches)) as "l1_batch_number!",
+                        miniblocks.timestamp,
+                        miniblocks.l1_tx_count,
+                        miniblocks.l2_tx_count,
+                        miniblocks.hash as "root_hash?",
+                        commit_tx.tx_hash as "commit_tx_hash?",
+                        commit_tx.confirmed_at as "committed_at?",
+                        prove_tx.tx_hash as "prove_tx_hash?",
+                        prove_tx.confirmed_at as "proven_at?",
+                        execute_tx.tx_hash as "execute_tx_hash?",
+                        execute_tx.confirmed_at as "executed_at?",
+                        miniblocks.l1_gas_price,
+                        miniblocks.l2_fair_gas_price,
+                        miniblocks.bootloader_code_hash,
+                        miniblocks.default_aa_code_hash,
+                        l1_batches.fee_account_address as "fee_account_address?"
+                    FROM miniblocks
+                    LEFT JOIN l1_batches ON miniblocks.l1_batch_number = l1_batches.number
+                    LEFT JOIN eth_txs_history as commit_tx ON (l1_batches.eth_commit_tx_id = commit_tx.eth_tx_id AND commit_tx.confirmed_at IS NOT NULL)
+                    LEFT JOIN eth_txs_history as prove_tx ON (l1_batches.eth_prove_tx_id = prove_tx.eth_tx_id AND prove_tx.confirmed_at IS NOT NULL)
+                    LEFT JOIN eth_txs_history as execute_tx ON (l1_batches.eth_execute_tx_id = execute_tx.eth_tx_id AND execute_tx.confirmed_at IS NOT NULL)
+                    WHERE miniblocks.number BETWEEN $1 AND $2
+                    ORDER BY miniblocks.number
+                "#,
+                from_block.0 as i64,
+                to_block.0 as i64
+            )
+            .fetch_all(self.storage.conn())
+            .await?;
+            metrics::histogram!("dal.request", started_at.elapsed(), "method" => "explorer_get_block_details_range");
+            Ok(storage_block_details
+                .into_iter()
+                .map(|storage_block_details| {
+                    storage_block_details.into_block_details(current_operator_address)
+                })
+                .collect())
+        }
+    }
+
+    pub async fn get_block_header_details(
+        &mut self,
+        block_number: MiniblockNumber,
+    ) -> Result<Option<BlockHeaderDetails>, SqlxError> {
+        {
+            let started_at = Instant::now();
+            let row = sqlx::query!(
+                r#"
+                    SELECT miniblocks.number,
+                        miniblocks.hash,
+                        prev_miniblocks.hash as "parent_hash?",
+                        miniblocks.timestamp,
+                        COALESCE(miniblocks.l1_batch_number, (SELECT (max(number) + 1) FROM l1_batches)) as "l1_batch_number!",
+                        l1_batches.hash as "root_hash?"
+                    FROM miniblocks
+                    LEFT JOIN miniblocks as prev_miniblocks ON prev_miniblocks.number = miniblocks.number - 1
+                    LEFT JOIN l1_batches ON miniblocks.l1_batch_number = l1_batches.number
+                    WHERE miniblocks.number = $1
+                "#,
+                block_number.0 as i64
+            )
+            .fetch_optional(self.storage.conn())
+            .await?;
+            metrics::histogram!("dal.request", started_at.elapsed(), "method" => "explorer_get_block_header_details");
+            Ok(row.map(|row| BlockHeaderDetails {
+                number: MiniblockNumber(row.number as u32),
+                hash: H256::from_slice(&row.hash),
+                parent_hash: row
+                    .parent_hash
+                    .as_deref()
+                    .map_or_else(H256::zero, H256::from_slice),
+                timestamp: row.timestamp as u64,
+                l1_batch_number: L1BatchNumber(row.l1_batch_number as u32),
+                root_hash: row.root_hash.as_deref().map(H256::from_slice),
+            }))
+        }
+    }
+
     pub async fn get_l1_batches_page(
         &mut self,
         query: L1BatchesQuery,
@@ -169,7 +258,14 @@ impl ExplorerBlocksDal<'_, '_> {
                         l1_batches.l1_gas_price,
                         l1_batches.l2_fair_gas_price,
                         l1_batches.bootloader_code_hash,
-                        l1_batches.default_aa_code_hash
+                        l1_batches.default_aa_code_hash,
+                        l1_batches.seal_reason,
+                        (
+                            SELECT revert_tx_hash FROM l1_batch_reverts
+                            WHERE l1_batch_reverts.l1_batch_number = l1_batches.number
+                            ORDER BY created_at DESC LIMIT 1
+                        ) as "revert_tx_hash?",
+                        l1_batches.verifier_address
                     FROM l1_batches
                     LEFT JOIN eth_txs_history as commit_tx ON (l1_batches.eth_commit_tx_id = commit_tx.eth_tx_id AND commit_tx.confirmed_at IS NOT NULL)
                     LEFT JOIN eth_txs_history as prove_tx ON (l1_batches.eth_prove_tx_id = prove_tx.eth_tx_id AND prove_tx.confirmed_at IS NOT NULL)
@@ -185,3 +281,77 @@ impl ExplorerBlocksDal<'_, '_> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use db_test_macro::db_test;
+    use sqlx::types::chrono::Utc;
+    use zksync_types::{aggregated_operations::AggregatedActionType, block::L1BatchHeader};
+
+    use super::*;
+    use crate::ConnectionPool;
+
+    async fn insert_l1_batch(conn: &mut StorageProcessor<'_>, number: u32) {
+        let header = L1BatchHeader::new(
+            L1BatchNumber(number),
+            number.into(),
+            Default::default(),
+            Default::default(),
+        );
+        conn.blocks_dal()
+            .insert_l1_batch(&header, Default::default())
+            .await;
+    }
+
+    #[db_test(dal_crate)]
+    async fn l1_batch_reverts_survive_rollback(connection_pool: ConnectionPool) {
+        let mut conn = connection_pool.access_test_storage().await;
+        conn.blocks_dal().delete_genesis().await;
+        for number in 1..=3 {
+            insert_l1_batch(&mut conn, number).await;
+            conn.eth_sender_dal()
+                .insert_bogus_confirmed_eth_tx(
+                    L1BatchNumber(number),
+                    AggregatedActionType::CommitBlocks,
+                    H256::from_low_u64_be(number.into()),
+                    Utc::now(),
+                )
+                .await;
+        }
+
+        let revert_tx_hash = H256::repeat_byte(0x42);
+        conn.blocks_dal()
+            .insert_l1_batch_reverts(L1BatchNumber(1), revert_tx_hash)
+            .await;
+        conn.blocks_dal().delete_l1_batches(L1BatchNumber(1)).await;
+        insert_l1_batch(&mut conn, 2).await;
+
+        let details = conn
+            .explorer()
+            .blocks_dal()
+            .get_l1_batch_details(L1BatchNumber(1))
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(!details.reverted);
+        assert_eq!(details.revert_tx_hash, None);
+
+        let details = conn
+            .explorer()
+            .blocks_dal()
+            .get_l1_batch_details(L1BatchNumber(2))
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(details.reverted);
+        assert_eq!(details.revert_tx_hash, Some(revert_tx_hash));
+
+        let details = conn
+            .explorer()
+            .blocks_dal()
+            .get_l1_batch_details(L1BatchNumber(3))
+            .await
+            .unwrap();
+        assert!(details.is_none());
+    }
+}
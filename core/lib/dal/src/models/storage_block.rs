@@ -12,7 +12,9 @@ use zksync_contracts::BaseSystemContractsHashes;
 use zksync_types::api;
 use zksync_types::block::MiniblockHeader;
 use zksync_types::commitment::{BlockMetaParameters, BlockMetadata};
-use zksync_types::explorer_api::{BlockDetails, L1BatchDetails, L1BatchPageItem};
+use zksync_types::explorer_api::{
+    BlockDetails, BlockFinality, L1BatchCommitmentMode, L1BatchDetails, L1BatchPageItem,
+};
 use zksync_types::{
     block::L1BatchHeader,
     explorer_api::{BlockPageItem, BlockStatus},
@@ -83,6 +85,10 @@ pub struct StorageBlock {
     pub gas_per_pubdata_limit: i64,
 
     pub skip_proof: bool,
+    pub seal_reason: Option<String>,
+    pub verifier_address: Option<Vec<u8>>,
+    pub protocol_version: Option<i32>,
+    pub verification_key_hash: Option<Vec<u8>>,
 }
 
 impl From<StorageBlock> for L1BatchHeader {
@@ -353,6 +359,15 @@ impl StorageBlockDetails {
         } else {
             BlockStatus::Sealed
         };
+        let finality = if self.number == 0 || self.execute_tx_hash.is_some() {
+            BlockFinality::Executed
+        } else if self.prove_tx_hash.is_some() {
+            BlockFinality::Proven
+        } else if self.commit_tx_hash.is_some() {
+            BlockFinality::Committed
+        } else {
+            BlockFinality::Pending
+        };
         BlockDetails {
             number: MiniblockNumber(self.number as u32),
             l1_batch_number: L1BatchNumber(self.l1_batch_number as u32),
@@ -398,6 +413,7 @@ impl StorageBlockDetails {
                 .fee_account_address
                 .map(|fee_account_address| Address::from_slice(&fee_account_address))
                 .unwrap_or(current_operator_address),
+            finality,
         }
     }
 }
@@ -419,10 +435,30 @@ pub struct StorageL1BatchDetails {
     pub l2_fair_gas_price: i64,
     pub bootloader_code_hash: Option<Vec<u8>>,
     pub default_aa_code_hash: Option<Vec<u8>>,
+    pub seal_reason: Option<String>,
+    pub revert_tx_hash: Option<Vec<u8>>,
+    pub verifier_address: Option<Vec<u8>>,
+}
+
+fn seconds_between(from: Option<NaiveDateTime>, to: Option<NaiveDateTime>) -> Option<u64> {
+    let duration = to?.signed_duration_since(from?);
+    Some(duration.num_seconds().max(0) as u64)
 }
 
 impl From<StorageL1BatchDetails> for L1BatchDetails {
     fn from(storage_l1_batch_details: StorageL1BatchDetails) -> Self {
+        let commit_to_prove_secs = seconds_between(
+            storage_l1_batch_details.committed_at,
+            storage_l1_batch_details.proven_at,
+        );
+        let prove_to_execute_secs = seconds_between(
+            storage_l1_batch_details.proven_at,
+            storage_l1_batch_details.executed_at,
+        );
+        let verifier_address = storage_l1_batch_details
+            .verifier_address
+            .filter(|_| storage_l1_batch_details.proven_at.is_some())
+            .map(|address| Address::from_slice(&address));
         let status = if storage_l1_batch_details.number == 0
             || storage_l1_batch_details.execute_tx_hash.is_some()
         {
@@ -473,6 +509,19 @@ impl From<StorageL1BatchDetails> for L1BatchDetails {
                     .map(|default_aa_code_hash| H256::from_slice(&default_aa_code_hash))
                     .expect("should not be none"),
             },
+            // The node always publishes the entire batch pubdata in the commit transaction calldata
+            // (see `L1BatchWithMetadata::l1_commit_data()`), so every batch is a rollup batch.
+            commitment_mode: L1BatchCommitmentMode::Rollup,
+            seal_reason: storage_l1_batch_details.seal_reason,
+            reverted: storage_l1_batch_details.revert_tx_hash.is_some(),
+            revert_tx_hash: storage_l1_batch_details
+                .revert_tx_hash
+                .as_deref()
+                .map(H256::from_slice),
+            da_reference: None,
+            commit_to_prove_secs,
+            prove_to_execute_secs,
+            verifier_address,
         }
     }
 }
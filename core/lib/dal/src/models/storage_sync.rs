@@ -27,6 +27,7 @@ pub struct StorageSyncBlock {
     pub bootloader_code_hash: Option<Vec<u8>>,
     pub default_aa_code_hash: Option<Vec<u8>>,
     pub fee_account_address: Option<Vec<u8>>, // May be None if the block is not yet sealed
+    pub protocol_version: Option<i32>,
 }
 
 impl StorageSyncBlock {
@@ -81,6 +82,7 @@ impl StorageSyncBlock {
                 .fee_account_address
                 .map(|fee_account_address| Address::from_slice(&fee_account_address))
                 .unwrap_or(current_operator_address),
+            protocol_version: self.protocol_version.map(|version| version as u16),
             transactions,
         }
     }
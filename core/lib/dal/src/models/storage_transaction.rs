@@ -146,6 +146,8 @@ impl From<StorageTransactionDetails> for api::TransactionDetails {
         );
         let gas_refunded = U256::from(tx_details.refunded_gas as u32);
         let fee = (gas_limit - gas_refunded) * effective_gas_price;
+        let max_fee_per_gas = tx_details.max_fee_per_gas.clone().map(bigdecimal_to_u256);
+        let max_fee = max_fee_per_gas.map(|max_fee_per_gas| gas_limit * max_fee_per_gas);
 
         let gas_per_pubdata =
             bigdecimal_to_u256(tx_details.gas_per_pubdata_limit.unwrap_or_default());
@@ -173,6 +175,16 @@ impl From<StorageTransactionDetails> for api::TransactionDetails {
             eth_commit_tx_hash,
             eth_prove_tx_hash,
             eth_execute_tx_hash,
+            index_in_block: tx_details.index_in_block.map(|index| index as u32),
+            l1_batch_tx_index: tx_details.l1_batch_tx_index.map(|index| index as u32),
+            gas_limit: Some(gas_limit),
+            gas_used: tx_details
+                .miniblock_number
+                .map(|_| gas_limit - gas_refunded),
+            estimated_gas_limit: None,
+            raw_transaction: None,
+            max_fee_per_gas,
+            max_fee,
         }
     }
 }
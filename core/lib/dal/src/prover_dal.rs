@@ -334,6 +334,36 @@ impl ProverDal<'_, '_> {
         }
     }
 
+    pub async fn get_prover_jobs_stats_for_l1_batch(
+        &mut self,
+        l1_batch_number: L1BatchNumber,
+    ) -> JobCountStatistics {
+        {
+            let mut results: HashMap<String, usize> = sqlx::query!(
+                r#"
+                SELECT COUNT(*) as "count!", status as "status!"
+                FROM prover_jobs
+                WHERE l1_batch_number = $1
+                GROUP BY status
+                "#,
+                l1_batch_number.0 as i64
+            )
+            .fetch_all(self.storage.conn())
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|row| (row.status, row.count as usize))
+            .collect::<HashMap<String, usize>>();
+            JobCountStatistics {
+                queued: results.remove("queued").unwrap_or(0usize),
+                in_progress: results.remove("in_progress").unwrap_or(0usize)
+                    + results.remove("in_gpu_proof").unwrap_or(0usize),
+                failed: results.remove("failed").unwrap_or(0usize),
+                successful: results.remove("successful").unwrap_or(0usize),
+            }
+        }
+    }
+
     pub async fn min_unproved_l1_batch_number(&mut self) -> Option<L1BatchNumber> {
         {
             sqlx::query!(
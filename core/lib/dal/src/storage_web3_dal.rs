@@ -1,4 +1,4 @@
-use std::time::Instant;
+use std::{collections::HashMap, time::Instant};
 
 use zksync_types::{
     get_code_key, get_nonce_key,
@@ -78,6 +78,143 @@ impl StorageWeb3Dal<'_, '_> {
         }
     }
 
+    /// Batched version of [`Self::get_historical_value_unchecked()`]. Returns values keyed by the hashed storage key;
+    /// keys that were never written are absent from the returned map.
+    pub async fn get_historical_values_unchecked(
+        &mut self,
+        hashed_keys: &[H256],
+        block_number: MiniblockNumber,
+    ) -> Result<HashMap<H256, H256>, SqlxError> {
+        let started_at = Instant::now();
+        let hashed_keys: Vec<_> = hashed_keys.iter().map(H256::as_bytes).collect();
+        let rows = sqlx::query!(
+            r#"
+            SELECT DISTINCT ON (hashed_key) hashed_key, value
+            FROM storage_logs
+            WHERE storage_logs.hashed_key = ANY($1) AND storage_logs.miniblock_number <= $2
+            ORDER BY hashed_key, storage_logs.miniblock_number DESC, storage_logs.operation_number DESC
+            "#,
+            &hashed_keys as &[&[u8]],
+            block_number.0 as i64
+        )
+        .fetch_all(self.storage.conn())
+        .await?;
+        metrics::histogram!("dal.request", started_at.elapsed(), "method" => "get_historical_values_unchecked");
+
+        Ok(rows
+            .into_iter()
+            .map(|row| {
+                (
+                    H256::from_slice(&row.hashed_key),
+                    H256::from_slice(&row.value),
+                )
+            })
+            .collect())
+    }
+
+    /// Returns the latest values of storage slots modified in the `from_miniblock..=to_miniblock` range,
+    /// ordered by hashed key. Only slots with hashed keys greater than `after` (if specified) are returned.
+    pub async fn get_modified_slots(
+        &mut self,
+        from_miniblock: MiniblockNumber,
+        to_miniblock: MiniblockNumber,
+        after: Option<H256>,
+        limit: usize,
+    ) -> Result<Vec<(StorageKey, H256)>, SqlxError> {
+        let started_at = Instant::now();
+        let rows = sqlx::query!(
+            r#"
+            SELECT DISTINCT ON (hashed_key) hashed_key, address, key, value
+            FROM storage_logs
+            WHERE miniblock_number BETWEEN $1 AND $2 AND ($3::bytea IS NULL OR hashed_key > $3)
+            ORDER BY hashed_key, miniblock_number DESC, operation_number DESC
+            LIMIT $4
+            "#,
+            from_miniblock.0 as i64,
+            to_miniblock.0 as i64,
+            after.as_ref().map(H256::as_bytes),
+            limit as i64
+        )
+        .fetch_all(self.storage.conn())
+        .await?;
+
+        let slots = rows
+            .into_iter()
+            .map(|row| {
+                let key = StorageKey::new(
+                    AccountTreeId::new(Address::from_slice(&row.address)),
+                    H256::from_slice(&row.key),
+                );
+                (key, H256::from_slice(&row.value))
+            })
+            .collect();
+        metrics::histogram!("dal.request", started_at.elapsed(), "method" => "get_modified_slots");
+        Ok(slots)
+    }
+
+    /// Returns keys of the storage slots of the `address` contract written at or before `block_number`,
+    /// ordered by key. Only keys greater than `after` (if specified) are returned.
+    pub async fn get_account_storage_keys(
+        &mut self,
+        address: Address,
+        block_number: MiniblockNumber,
+        after: Option<H256>,
+        limit: usize,
+    ) -> Result<Vec<H256>, SqlxError> {
+        let started_at = Instant::now();
+        let rows = sqlx::query!(
+            r#"
+            SELECT DISTINCT key
+            FROM storage_logs
+            WHERE address = $1 AND miniblock_number <= $2 AND ($3::bytea IS NULL OR key > $3)
+            ORDER BY key
+            LIMIT $4
+            "#,
+            address.as_bytes(),
+            block_number.0 as i64,
+            after.as_ref().map(H256::as_bytes),
+            limit as i64
+        )
+        .fetch_all(self.storage.conn())
+        .await?;
+        metrics::histogram!("dal.request", started_at.elapsed(), "method" => "get_account_storage_keys");
+
+        Ok(rows
+            .into_iter()
+            .map(|row| H256::from_slice(&row.key))
+            .collect())
+    }
+
+    /// Returns the final values of storage slots written by the specified transaction,
+    /// ordered by hashed key.
+    pub async fn get_transaction_writes(
+        &mut self,
+        tx_hash: H256,
+    ) -> Result<Vec<(StorageKey, H256)>, SqlxError> {
+        let rows = sqlx::query!(
+            r#"
+            SELECT DISTINCT ON (hashed_key) hashed_key, address, key, value
+            FROM storage_logs
+            WHERE tx_hash = $1
+            ORDER BY hashed_key, miniblock_number DESC, operation_number DESC
+            "#,
+            tx_hash.as_bytes()
+        )
+        .fetch_all(self.storage.conn())
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| {
+                let key = StorageKey::new(
+                    AccountTreeId::new(Address::from_slice(&row.address)),
+                    H256::from_slice(&row.key),
+                );
+                (key, H256::from_slice(&row.value))
+            })
+            .collect())
+    }
+
     /// Gets the L1 batch number that the miniblock has now or will have in the future (provided
     /// that the node will operate correctly). Assumes that the miniblock is present in the DB;
     /// this is not checked, and if this is false, the returned value will be meaningless.
@@ -120,6 +257,22 @@ impl StorageWeb3Dal<'_, '_> {
         Ok(l1_batch_number)
     }
 
+    /// Returns the hash of the bytecode deployed at `address` as of `block_number`, or `None`
+    /// if no contract is deployed there. This method doesn't check if the block exists.
+    pub async fn get_contract_bytecode_hash_unchecked(
+        &mut self,
+        address: Address,
+        block_number: MiniblockNumber,
+    ) -> Result<Option<H256>, SqlxError> {
+        let code_key = get_code_key(&address);
+        let bytecode_hash = self
+            .get_historical_value_unchecked(&code_key, block_number)
+            .await?;
+        let is_deployed =
+            !bytecode_hash.is_zero() && bytecode_hash != FAILED_CONTRACT_DEPLOYMENT_BYTECODE_HASH;
+        Ok(is_deployed.then_some(bytecode_hash))
+    }
+
     /// This method doesn't check if block with number equals to `block_number`
     /// is present in the database. For such blocks `None` will be returned.
     pub async fn get_contract_code_unchecked(
@@ -171,4 +324,20 @@ impl StorageWeb3Dal<'_, '_> {
             .map(|option_row| option_row.map(|row| row.bytecode))
         }
     }
+
+    /// Returns bytecode for a factory dep with the specified bytecode `hash` together with
+    /// the miniblock it was first published in.
+    pub async fn get_factory_dep_with_miniblock(
+        &mut self,
+        hash: H256,
+    ) -> Result<Option<(Vec<u8>, MiniblockNumber)>, SqlxError> {
+        let row = sqlx::query!(
+            "SELECT bytecode, miniblock_number FROM factory_deps WHERE bytecode_hash = $1",
+            hash.as_bytes()
+        )
+        .fetch_optional(self.storage.conn())
+        .await?;
+
+        Ok(row.map(|row| (row.bytecode, MiniblockNumber(row.miniblock_number as u32))))
+    }
 }
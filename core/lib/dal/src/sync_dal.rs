@@ -40,7 +40,8 @@ impl SyncDal<'_, '_> {
                     miniblocks.l2_fair_gas_price,
                     miniblocks.bootloader_code_hash,
                     miniblocks.default_aa_code_hash,
-                    l1_batches.fee_account_address as "fee_account_address?"
+                    l1_batches.fee_account_address as "fee_account_address?",
+                    l1_batches.protocol_version as "protocol_version?"
                 FROM miniblocks
                 LEFT JOIN l1_batches ON miniblocks.l1_batch_number = l1_batches.number
                 LEFT JOIN eth_txs_history as commit_tx ON (l1_batches.eth_commit_tx_id = commit_tx.eth_tx_id AND commit_tx.confirmed_at IS NOT NULL)
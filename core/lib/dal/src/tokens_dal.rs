@@ -146,15 +146,29 @@ impl TokensDal<'_, '_> {
 
     pub async fn set_l1_token_price(&mut self, l1_address: &Address, price: TokenPrice) {
         {
+            let usd_price = ratio_to_big_decimal(&price.usd_price, STORED_USD_PRICE_PRECISION);
+            let mut transaction = self.storage.start_transaction().await;
             sqlx::query!(
             "UPDATE tokens SET usd_price = $2, usd_price_updated_at = $3, updated_at = now() WHERE l1_address = $1",
             l1_address.as_bytes(),
-            ratio_to_big_decimal(&price.usd_price, STORED_USD_PRICE_PRECISION),
+            usd_price,
             price.last_updated.naive_utc(),
         )
-            .execute(self.storage.conn())
+            .execute(transaction.conn())
+            .await
+            .unwrap();
+            sqlx::query!(
+                "INSERT INTO token_price_history (l1_address, usd_price, usd_price_updated_at, created_at)
+                 VALUES ($1, $2, $3, now())
+                 ON CONFLICT (l1_address, usd_price_updated_at) DO NOTHING",
+                l1_address.as_bytes(),
+                usd_price,
+                price.last_updated.naive_utc(),
+            )
+            .execute(transaction.conn())
             .await
             .unwrap();
+            transaction.commit().await;
         }
     }
 
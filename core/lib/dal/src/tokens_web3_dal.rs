@@ -3,11 +3,14 @@ use crate::SqlxError;
 use crate::StorageProcessor;
 use num::{rational::Ratio, BigUint};
 use sqlx::postgres::types::PgInterval;
+use sqlx::types::chrono::NaiveDateTime;
+use zksync_config::constants::ERC20_TRANSFER_TOPIC;
 use zksync_types::{
-    tokens::{TokenInfo, TokenMetadata, TokenPrice},
-    Address,
+    api::TokenSortOrder,
+    tokens::{TokenInfo, TokenMetadata, TokenPrice, ETHEREUM_ADDRESS},
+    Address, H256, U256,
 };
-use zksync_utils::ratio_to_big_decimal;
+use zksync_utils::{ratio_to_big_decimal, u256_to_big_decimal};
 
 // Precision of the USD price per token
 pub(crate) const STORED_USD_PRICE_PRECISION: usize = 6;
@@ -43,6 +46,160 @@ impl TokensWeb3Dal<'_, '_> {
         }
     }
 
+    /// Returns a page of well-known tokens with the symbol containing `symbol_substring` (case-insensitive),
+    /// ordered according to `sort`.
+    pub async fn get_well_known_tokens_filtered(
+        &mut self,
+        symbol_substring: Option<&str>,
+        sort: TokenSortOrder,
+        offset: u32,
+        limit: u32,
+    ) -> Result<Vec<TokenInfo>, SqlxError> {
+        let sort = match sort {
+            TokenSortOrder::ByName => "name",
+            TokenSortOrder::ByAddress => "address",
+            TokenSortOrder::BySymbol => "symbol",
+        };
+        let records = sqlx::query!(
+            "SELECT l1_address, l2_address, name, symbol, decimals FROM tokens
+             WHERE well_known = true AND ($1::text IS NULL OR strpos(lower(symbol), lower($1)) > 0)
+             ORDER BY CASE WHEN $2 = 'name' THEN name END,
+                CASE WHEN $2 = 'symbol' THEN symbol END,
+                l2_address
+             OFFSET $3
+             LIMIT $4",
+            symbol_substring,
+            sort,
+            i64::from(offset),
+            i64::from(limit)
+        )
+        .fetch_all(self.storage.conn())
+        .await?;
+        let result = records
+            .into_iter()
+            .map(|record| TokenInfo {
+                l1_address: Address::from_slice(&record.l1_address),
+                l2_address: Address::from_slice(&record.l2_address),
+                metadata: TokenMetadata {
+                    name: record.name,
+                    symbol: record.symbol,
+                    decimals: record.decimals as u8,
+                },
+            })
+            .collect();
+        Ok(result)
+    }
+
+    /// Returns well-known tokens having at least `min_holders` holders and at least `min_total_supply`
+    /// total supply, ordered by symbol. Holder balances and the total supply are aggregated
+    /// from ERC20 `Transfer` events; the total supply is the sum of all non-zero-address balances.
+    /// ETH is never filtered out since its mints and burns don't emit `Transfer` events.
+    pub async fn get_well_known_tokens_with_min_liquidity(
+        &mut self,
+        min_holders: Option<u64>,
+        min_total_supply: Option<U256>,
+    ) -> Result<Vec<TokenInfo>, SqlxError> {
+        let min_total_supply = min_total_supply.map(u256_to_big_decimal);
+        let records = sqlx::query!(
+            r#"
+                WITH transfers AS (
+                    SELECT address, topic2, topic3,
+                        (SELECT SUM(get_byte(value, i)::NUMERIC * 256::NUMERIC ^ (31 - i))
+                            FROM generate_series(0, 31) AS i) AS amount
+                    FROM events
+                    WHERE topic1 = $1 AND length(value) = 32
+                        AND address IN (SELECT l2_address FROM tokens WHERE well_known = true)
+                ),
+                balances AS (
+                    SELECT address, holder, SUM(amount) AS balance FROM (
+                        SELECT address, topic3 AS holder, amount FROM transfers
+                        UNION ALL
+                        SELECT address, topic2 AS holder, -amount FROM transfers
+                    ) AS balance_changes
+                    WHERE holder != $2
+                    GROUP BY address, holder
+                ),
+                token_stats AS (
+                    SELECT address, COUNT(*) FILTER (WHERE balance > 0) AS holders, SUM(balance) AS total_supply
+                    FROM balances
+                    GROUP BY address
+                )
+                SELECT tokens.l1_address, tokens.l2_address, tokens.name, tokens.symbol, tokens.decimals
+                FROM tokens
+                LEFT JOIN token_stats ON token_stats.address = tokens.l2_address
+                WHERE tokens.well_known = true AND (
+                    tokens.l2_address = $3 OR (
+                        ($4::BIGINT IS NULL OR COALESCE(token_stats.holders, 0) >= $4)
+                        AND ($5::NUMERIC IS NULL OR COALESCE(token_stats.total_supply, 0) >= $5)
+                    )
+                )
+                ORDER BY tokens.symbol
+            "#,
+            ERC20_TRANSFER_TOPIC.as_bytes(),
+            H256::zero().as_bytes(),
+            ETHEREUM_ADDRESS.as_bytes(),
+            min_holders.map(|holders| holders as i64),
+            min_total_supply
+        )
+        .fetch_all(self.storage.conn())
+        .await?;
+        let result = records
+            .into_iter()
+            .map(|record| TokenInfo {
+                l1_address: Address::from_slice(&record.l1_address),
+                l2_address: Address::from_slice(&record.l2_address),
+                metadata: TokenMetadata {
+                    name: record.name,
+                    symbol: record.symbol,
+                    decimals: record.decimals as u8,
+                },
+            })
+            .collect();
+        Ok(result)
+    }
+
+    /// Returns the time of the latest update of any well-known token, or `None` if there are no such tokens.
+    /// Note that price updates are counted as well.
+    pub async fn get_well_known_tokens_last_updated_at(
+        &mut self,
+    ) -> Result<Option<NaiveDateTime>, SqlxError> {
+        let record = sqlx::query!(
+            "SELECT MAX(updated_at) as updated_at FROM tokens WHERE well_known = true"
+        )
+        .fetch_one(self.storage.conn())
+        .await?;
+        Ok(record.updated_at)
+    }
+
+    /// Returns the token with the specified L2 address together with its `well_known` flag.
+    pub async fn get_token_by_l2_address(
+        &mut self,
+        l2_address: &Address,
+    ) -> Result<Option<(TokenInfo, bool)>, SqlxError> {
+        {
+            let record = sqlx::query!(
+                "SELECT l1_address, l2_address, name, symbol, decimals, well_known FROM tokens
+                 WHERE l2_address = $1",
+                l2_address.as_bytes()
+            )
+            .fetch_optional(self.storage.conn())
+            .await?;
+            let result = record.map(|record| {
+                let token_info = TokenInfo {
+                    l1_address: Address::from_slice(&record.l1_address),
+                    l2_address: Address::from_slice(&record.l2_address),
+                    metadata: TokenMetadata {
+                        name: record.name,
+                        symbol: record.symbol,
+                        decimals: record.decimals as u8,
+                    },
+                };
+                (token_info, record.well_known)
+            });
+            Ok(result)
+        }
+    }
+
     pub async fn is_token_actively_trading(
         &mut self,
         l2_token: &Address,
@@ -99,6 +256,36 @@ impl TokensWeb3Dal<'_, '_> {
         }
     }
 
+    /// Returns the latest price of the token recorded at or before `timestamp` (in seconds since the Unix epoch),
+    /// or the latest known price if `timestamp` is `None`.
+    pub async fn get_token_price_at(
+        &mut self,
+        l2_address: &Address,
+        timestamp: Option<u64>,
+    ) -> Result<Option<TokenPrice>, SqlxError> {
+        let timestamp =
+            timestamp.and_then(|timestamp| NaiveDateTime::from_timestamp_opt(timestamp as i64, 0));
+        let storage_price = sqlx::query_as!(
+            StorageTokenPrice,
+            r#"
+            SELECT token_price_history.usd_price as "usd_price?",
+                token_price_history.usd_price_updated_at as "usd_price_updated_at?"
+            FROM token_price_history
+            JOIN tokens ON tokens.l1_address = token_price_history.l1_address
+            WHERE tokens.l2_address = $1
+                AND ($2::timestamp IS NULL OR token_price_history.usd_price_updated_at <= $2)
+            ORDER BY token_price_history.usd_price_updated_at DESC
+            LIMIT 1
+            "#,
+            l2_address.as_bytes(),
+            timestamp
+        )
+        .fetch_optional(self.storage.conn())
+        .await?;
+
+        Ok(storage_price.and_then(Into::into))
+    }
+
     pub async fn get_token_metadata(
         &mut self,
         l2_address: &Address,
@@ -122,3 +309,140 @@ impl TokensWeb3Dal<'_, '_> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use db_test_macro::db_test;
+    use zksync_types::{tx::IncludedTxLocation, L1BatchNumber, MiniblockNumber, VmEvent};
+    use zksync_utils::address_to_h256;
+
+    use super::*;
+    use crate::{tests::create_miniblock_header, ConnectionPool};
+
+    fn create_token(index: u8, symbol: &str) -> TokenInfo {
+        TokenInfo {
+            l1_address: Address::repeat_byte(index),
+            l2_address: Address::repeat_byte(index + 0x10),
+            metadata: TokenMetadata {
+                name: symbol.to_owned(),
+                symbol: symbol.to_owned(),
+                decimals: 18,
+            },
+        }
+    }
+
+    fn create_transfer_event(
+        token: &TokenInfo,
+        from: Address,
+        to: Address,
+        amount: u64,
+    ) -> VmEvent {
+        let mut value = [0_u8; 32];
+        U256::from(amount).to_big_endian(&mut value);
+        VmEvent {
+            location: (L1BatchNumber(1), 0),
+            address: token.l2_address,
+            indexed_topics: vec![
+                ERC20_TRANSFER_TOPIC,
+                address_to_h256(&from),
+                address_to_h256(&to),
+            ],
+            value: value.to_vec(),
+        }
+    }
+
+    #[db_test(dal_crate)]
+    async fn filtering_tokens_by_liquidity(connection_pool: ConnectionPool) {
+        let mut conn = connection_pool.access_test_storage().await;
+        conn.events_dal().rollback_events(MiniblockNumber(0)).await;
+        conn.blocks_dal()
+            .delete_miniblocks(MiniblockNumber(0))
+            .await;
+        conn.blocks_dal()
+            .insert_miniblock(&create_miniblock_header(1))
+            .await;
+
+        let eth_token = TokenInfo {
+            l1_address: ETHEREUM_ADDRESS,
+            l2_address: ETHEREUM_ADDRESS,
+            metadata: TokenMetadata {
+                name: "Ether".to_owned(),
+                symbol: "ETH".to_owned(),
+                decimals: 18,
+            },
+        };
+        let has_eth = conn
+            .tokens_web3_dal()
+            .get_token_by_l2_address(&ETHEREUM_ADDRESS)
+            .await
+            .unwrap()
+            .is_some();
+        let mut tokens = vec![
+            create_token(1, "AAA"),
+            create_token(2, "BBB"),
+            create_token(3, "CCC"),
+        ];
+        if !has_eth {
+            tokens.push(eth_token);
+        }
+        conn.tokens_dal().add_tokens(tokens.clone()).await;
+        for token in &tokens {
+            conn.tokens_dal()
+                .update_well_known_l1_token(&token.l1_address, token.metadata.clone())
+                .await;
+        }
+
+        // AAA: 3 holders minted 200 each, then one of them burns the whole balance
+        // (2 holders, total supply 400). BBB: a single holder after moving the whole balance
+        // (1 holder, total supply 100). CCC: no transfers at all.
+        let (aaa, bbb) = (&tokens[0], &tokens[1]);
+        let holders = [1_u8, 2, 3].map(Address::repeat_byte);
+        let zero = Address::zero();
+        let events = [
+            create_transfer_event(aaa, zero, holders[0], 200),
+            create_transfer_event(aaa, zero, holders[1], 200),
+            create_transfer_event(aaa, zero, holders[2], 200),
+            create_transfer_event(aaa, holders[2], zero, 200),
+            create_transfer_event(bbb, zero, holders[0], 100),
+            create_transfer_event(bbb, holders[0], holders[1], 100),
+        ];
+        let location = IncludedTxLocation {
+            tx_hash: H256::repeat_byte(1),
+            tx_index_in_miniblock: 0,
+            tx_initiator_address: Address::default(),
+        };
+        conn.events_dal()
+            .save_events(MiniblockNumber(1), &[(location, events.iter().collect())])
+            .await;
+
+        let test_cases = [
+            ((None, None), vec!["AAA", "BBB", "CCC", "ETH"]),
+            ((Some(0), Some(0)), vec!["AAA", "BBB", "CCC", "ETH"]),
+            ((Some(1), None), vec!["AAA", "BBB", "ETH"]),
+            ((Some(2), None), vec!["AAA", "ETH"]),
+            ((Some(3), None), vec!["ETH"]),
+            ((None, Some(100)), vec!["AAA", "BBB", "ETH"]),
+            ((None, Some(400)), vec!["AAA", "ETH"]),
+            ((None, Some(401)), vec!["ETH"]),
+            ((Some(1), Some(101)), vec!["AAA", "ETH"]),
+        ];
+        for ((min_holders, min_total_supply), expected_symbols) in test_cases {
+            let tokens = conn
+                .tokens_web3_dal()
+                .get_well_known_tokens_with_min_liquidity(
+                    min_holders,
+                    min_total_supply.map(U256::from),
+                )
+                .await
+                .unwrap();
+            let symbols: Vec<_> = tokens
+                .iter()
+                .map(|token| token.metadata.symbol.as_str())
+                .collect();
+            assert_eq!(
+                symbols, expected_symbols,
+                "min_holders={min_holders:?}, min_total_supply={min_total_supply:?}"
+            );
+        }
+    }
+}
@@ -1,8 +1,10 @@
+use std::collections::HashMap;
+
 use sqlx::types::chrono::NaiveDateTime;
 
 use zksync_types::{
-    api, Address, L2ChainId, MiniblockNumber, Transaction, ACCOUNT_CODE_STORAGE_ADDRESS,
-    FAILED_CONTRACT_DEPLOYMENT_BYTECODE_HASH, H160, H256, U256, U64,
+    api, Address, L1BatchNumber, L2ChainId, MiniblockNumber, Transaction,
+    ACCOUNT_CODE_STORAGE_ADDRESS, FAILED_CONTRACT_DEPLOYMENT_BYTECODE_HASH, H160, H256, U256, U64,
 };
 use zksync_utils::{bigdecimal_to_u256, h256_to_account_address};
 
@@ -211,37 +213,82 @@ impl TransactionsWeb3Dal<'_, '_> {
         Ok(tx)
     }
 
-    pub async fn get_transaction_details(
+    /// Returns details of the transactions with the specified hashes.
+    /// Transactions unknown to the node are not included into the returned map.
+    pub async fn get_transactions_details(
         &mut self,
-        hash: H256,
-    ) -> Result<Option<api::TransactionDetails>, SqlxError> {
-        {
-            let storage_tx_details: Option<StorageTransactionDetails> = sqlx::query_as!(
-                StorageTransactionDetails,
-                r#"
-                    SELECT transactions.*,
-                        miniblocks.timestamp as "miniblock_timestamp?",
-                        miniblocks.hash as "block_hash?",
-                        commit_tx.tx_hash as "eth_commit_tx_hash?",
-                        prove_tx.tx_hash as "eth_prove_tx_hash?",
-                        execute_tx.tx_hash as "eth_execute_tx_hash?"
-                    FROM transactions
-                    LEFT JOIN miniblocks ON miniblocks.number = transactions.miniblock_number
-                    LEFT JOIN l1_batches ON l1_batches.number = miniblocks.l1_batch_number
-                    LEFT JOIN eth_txs_history as commit_tx ON (l1_batches.eth_commit_tx_id = commit_tx.eth_tx_id AND commit_tx.confirmed_at IS NOT NULL)
-                    LEFT JOIN eth_txs_history as prove_tx ON (l1_batches.eth_prove_tx_id = prove_tx.eth_tx_id AND prove_tx.confirmed_at IS NOT NULL)
-                    LEFT JOIN eth_txs_history as execute_tx ON (l1_batches.eth_execute_tx_id = execute_tx.eth_tx_id AND execute_tx.confirmed_at IS NOT NULL)
-                    WHERE transactions.hash = $1
-                "#,
-                hash.as_bytes()
-            )
-            .fetch_optional(self.storage.conn())
-            .await?;
+        hashes: &[H256],
+    ) -> Result<HashMap<H256, api::TransactionDetails>, SqlxError> {
+        let hashes: Vec<_> = hashes.iter().map(|hash| hash.as_bytes().to_vec()).collect();
+        let storage_tx_details: Vec<StorageTransactionDetails> = sqlx::query_as!(
+            StorageTransactionDetails,
+            r#"
+                SELECT transactions.*,
+                    miniblocks.timestamp as "miniblock_timestamp?",
+                    miniblocks.hash as "block_hash?",
+                    commit_tx.tx_hash as "eth_commit_tx_hash?",
+                    prove_tx.tx_hash as "eth_prove_tx_hash?",
+                    execute_tx.tx_hash as "eth_execute_tx_hash?"
+                FROM transactions
+                LEFT JOIN miniblocks ON miniblocks.number = transactions.miniblock_number
+                LEFT JOIN l1_batches ON l1_batches.number = miniblocks.l1_batch_number
+                LEFT JOIN eth_txs_history as commit_tx ON (l1_batches.eth_commit_tx_id = commit_tx.eth_tx_id AND commit_tx.confirmed_at IS NOT NULL)
+                LEFT JOIN eth_txs_history as prove_tx ON (l1_batches.eth_prove_tx_id = prove_tx.eth_tx_id AND prove_tx.confirmed_at IS NOT NULL)
+                LEFT JOIN eth_txs_history as execute_tx ON (l1_batches.eth_execute_tx_id = execute_tx.eth_tx_id AND execute_tx.confirmed_at IS NOT NULL)
+                WHERE transactions.hash = ANY($1)
+            "#,
+            &hashes
+        )
+        .fetch_all(self.storage.conn())
+        .await?;
 
-            let tx = storage_tx_details.map(|tx_details| tx_details.into());
+        let details = storage_tx_details
+            .into_iter()
+            .map(|tx_details| (H256::from_slice(&tx_details.hash), tx_details.into()))
+            .collect();
+        Ok(details)
+    }
 
-            Ok(tx)
-        }
+    /// Returns lifecycle stages of the transactions with the specified hashes.
+    /// Transactions unknown to the node are not included into the returned map.
+    pub async fn get_transaction_stages(
+        &mut self,
+        hashes: &[H256],
+    ) -> Result<HashMap<H256, api::TransactionStage>, SqlxError> {
+        let hashes: Vec<_> = hashes.iter().map(|hash| hash.as_bytes().to_vec()).collect();
+        let rows = sqlx::query!(
+            r#"
+                SELECT transactions.hash,
+                    transactions.miniblock_number,
+                    prove_tx.tx_hash as "prove_tx_hash?",
+                    execute_tx.tx_hash as "execute_tx_hash?"
+                FROM transactions
+                LEFT JOIN l1_batches ON l1_batches.number = transactions.l1_batch_number
+                LEFT JOIN eth_txs_history as prove_tx ON (l1_batches.eth_prove_tx_id = prove_tx.eth_tx_id AND prove_tx.confirmed_at IS NOT NULL)
+                LEFT JOIN eth_txs_history as execute_tx ON (l1_batches.eth_execute_tx_id = execute_tx.eth_tx_id AND execute_tx.confirmed_at IS NOT NULL)
+                WHERE transactions.hash = ANY($1)
+            "#,
+            &hashes
+        )
+        .fetch_all(self.storage.conn())
+        .await?;
+
+        let stages = rows
+            .into_iter()
+            .map(|row| {
+                let stage = if row.execute_tx_hash.is_some() {
+                    api::TransactionStage::Executed
+                } else if row.prove_tx_hash.is_some() {
+                    api::TransactionStage::Proven
+                } else if row.miniblock_number.is_some() {
+                    api::TransactionStage::Included
+                } else {
+                    api::TransactionStage::Accepted
+                };
+                (H256::from_slice(&row.hash), stage)
+            })
+            .collect();
+        Ok(stages)
     }
 
     /// Returns hashes of txs which were received after `from_timestamp` and the time of receiving the last tx.
@@ -321,6 +368,76 @@ impl TransactionsWeb3Dal<'_, '_> {
         Ok(U256::from(pending_nonce))
     }
 
+    /// Returns the miniblock and the L1 batch a transaction was included in.
+    /// Returns `None` if the transaction is unknown or was rejected.
+    pub async fn get_transaction_inclusion(
+        &mut self,
+        hash: H256,
+    ) -> Result<Option<(Option<MiniblockNumber>, Option<L1BatchNumber>)>, SqlxError> {
+        let row = sqlx::query!(
+            "SELECT miniblock_number, l1_batch_number FROM transactions \
+            WHERE hash = $1 AND (miniblock_number IS NOT NULL OR error IS NULL)",
+            hash.as_bytes()
+        )
+        .fetch_optional(self.storage.conn())
+        .await?;
+
+        Ok(row.map(|row| {
+            (
+                row.miniblock_number
+                    .map(|number| MiniblockNumber(number as u32)),
+                row.l1_batch_number
+                    .map(|number| L1BatchNumber(number as u32)),
+            )
+        }))
+    }
+
+    /// Returns up to `limit` transactions of the L1 batch in execution order, starting from
+    /// the transaction with index `from_index` in the batch.
+    pub async fn get_l1_batch_transactions_page(
+        &mut self,
+        l1_batch_number: L1BatchNumber,
+        from_index: u32,
+        limit: u32,
+        chain_id: L2ChainId,
+    ) -> Result<Vec<api::Transaction>, SqlxError> {
+        let query = format!(
+            "SELECT {}
+            FROM transactions
+            LEFT JOIN miniblocks ON miniblocks.number = transactions.miniblock_number
+            WHERE transactions.l1_batch_number = $1 AND transactions.l1_batch_tx_index >= $2
+            ORDER BY transactions.l1_batch_tx_index
+            LIMIT $3",
+            web3_transaction_select_sql()
+        );
+        let transactions = sqlx::query(&query)
+            .bind(l1_batch_number.0 as i64)
+            .bind(from_index as i32)
+            .bind(limit as i64)
+            .fetch_all(self.storage.conn())
+            .await?
+            .into_iter()
+            .map(|row| extract_web3_transaction(row, chain_id))
+            .collect();
+        Ok(transactions)
+    }
+
+    /// Returns the server transaction (not the API one) with the specified hash.
+    pub async fn get_raw_transaction(
+        &mut self,
+        hash: H256,
+    ) -> Result<Option<Transaction>, SqlxError> {
+        let row = sqlx::query_as!(
+            StorageTransaction,
+            "SELECT * FROM transactions WHERE hash = $1",
+            hash.as_bytes()
+        )
+        .fetch_optional(self.storage.conn())
+        .await?;
+
+        Ok(row.map(Into::into))
+    }
+
     /// Returns the server transactions (not API ones) from a certain miniblock.
     /// Returns an empty list if the miniblock doesn't exist.
     pub async fn get_raw_miniblock_transactions(
@@ -344,7 +461,7 @@ impl TransactionsWeb3Dal<'_, '_> {
 #[cfg(test)]
 mod tests {
     use db_test_macro::db_test;
-    use zksync_types::{fee::TransactionExecutionMetrics, l2::L2Tx};
+    use zksync_types::{block::L1BatchHeader, fee::TransactionExecutionMetrics, l2::L2Tx};
     use zksync_utils::miniblock_hash;
 
     use super::*;
@@ -452,4 +569,59 @@ mod tests {
         assert_eq!(raw_txs.len(), 1);
         assert_eq!(raw_txs[0].hash(), tx_hash);
     }
+
+    #[db_test(dal_crate)]
+    async fn paginating_l1_batch_transactions(connection_pool: ConnectionPool) {
+        let mut conn = connection_pool.access_test_storage().await;
+        conn.blocks_dal().delete_genesis().await;
+        let header =
+            L1BatchHeader::new(L1BatchNumber(1), 1, Default::default(), Default::default());
+        conn.blocks_dal()
+            .insert_l1_batch(&header, Default::default())
+            .await;
+        let mut miniblock_header = create_miniblock_header(1);
+        miniblock_header.l2_tx_count = 3;
+        conn.blocks_dal().insert_miniblock(&miniblock_header).await;
+
+        let txs: Vec<_> = (0..3).map(|_| mock_l2_transaction()).collect();
+        for tx in &txs {
+            conn.transactions_dal()
+                .insert_transaction_l2(tx.clone(), TransactionExecutionMetrics::default())
+                .await;
+        }
+        let tx_results: Vec<_> = txs.iter().cloned().map(mock_execution_result).collect();
+        conn.transactions_dal()
+            .mark_txs_as_executed_in_miniblock(MiniblockNumber(1), &tx_results, U256::from(1))
+            .await;
+        conn.blocks_dal()
+            .mark_miniblocks_as_executed_in_l1_batch(L1BatchNumber(1))
+            .await;
+        conn.transactions_dal()
+            .mark_txs_as_executed_in_l1_batch(L1BatchNumber(1), &tx_results)
+            .await;
+
+        let tx_hashes: Vec<_> = txs.iter().map(L2Tx::hash).collect();
+        let pages = [
+            (0, 2, &tx_hashes[..2]),
+            (2, 2, &tx_hashes[2..]),
+            (1, 10, &tx_hashes[1..]),
+            (3, 2, &[][..]),
+        ];
+        for (from_index, limit, expected_hashes) in pages {
+            let page = conn
+                .transactions_web3_dal()
+                .get_l1_batch_transactions_page(L1BatchNumber(1), from_index, limit, L2ChainId(270))
+                .await
+                .unwrap();
+            let hashes: Vec<_> = page.iter().map(|tx| tx.hash).collect();
+            assert_eq!(hashes, expected_hashes, "from {from_index}, limit {limit}");
+        }
+
+        let page = conn
+            .transactions_web3_dal()
+            .get_l1_batch_transactions_page(L1BatchNumber(2), 0, 10, L2ChainId(270))
+            .await
+            .unwrap();
+        assert!(page.is_empty());
+    }
 }
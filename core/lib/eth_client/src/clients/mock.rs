@@ -341,6 +341,24 @@ impl BoundEthInterface for MockEthereum {
         Address::repeat_byte(0x11)
     }
 
+    /// The mock doesn't emulate the main contract, so calls to it always fail.
+    async fn call_main_contract_function<R, A, P, B>(
+        &self,
+        _func: &str,
+        _params: P,
+        _from: A,
+        _options: Options,
+        _block: B,
+    ) -> Result<R, Error>
+    where
+        R: Detokenize + Unpin,
+        A: Into<Option<Address>> + Send,
+        P: Tokenize + Send,
+        B: Into<Option<BlockId>> + Send,
+    {
+        Err(Error::EthereumGateway(Web3Error::Unreachable))
+    }
+
     async fn sign_prepared_tx_for_addr(
         &self,
         data: Vec<u8>,
@@ -523,6 +541,25 @@ impl<T: AsRef<MockEthereum> + Send + Sync> BoundEthInterface for T {
         self.as_ref().sender_account()
     }
 
+    async fn call_main_contract_function<R, A, P, B>(
+        &self,
+        func: &str,
+        params: P,
+        from: A,
+        options: Options,
+        block: B,
+    ) -> Result<R, Error>
+    where
+        R: Detokenize + Unpin,
+        A: Into<Option<Address>> + Send,
+        P: Tokenize + Send,
+        B: Into<Option<BlockId>> + Send,
+    {
+        self.as_ref()
+            .call_main_contract_function(func, params, from, options, block)
+            .await
+    }
+
     async fn sign_prepared_tx_for_addr(
         &self,
         data: Vec<u8>,
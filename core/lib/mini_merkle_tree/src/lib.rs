@@ -101,6 +101,55 @@ impl<'a, const LEAF_SIZE: usize> MiniMerkleTree<'a, LEAF_SIZE> {
         (root_hash, merkle_path)
     }
 
+    /// Returns the root hash and the Merkle proofs for leaves with the specified 0-based `indices`.
+    /// Unlike calling [`Self::merkle_root_and_path()`] for each leaf, the tree is only hashed once.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any of the `indices` is out of bounds.
+    pub fn merkle_root_and_paths(
+        self,
+        indices: impl IntoIterator<Item = usize>,
+    ) -> (H256, Vec<Vec<H256>>) {
+        let depth = tree_depth_by_size(self.tree_size);
+        let leaf_count = self.hashes.len();
+
+        let mut levels = Vec::with_capacity(depth);
+        let mut hashes = self.hashes.into_vec();
+        for level in 0..depth {
+            let empty_hash_at_level = self.hasher.empty_subtree_hash(level);
+            let next_level = hashes
+                .chunks(2)
+                .map(|pair| match pair {
+                    [lhs, rhs] => self.hasher.compress(lhs, rhs),
+                    [lhs] => self.hasher.compress(lhs, &empty_hash_at_level),
+                    _ => unreachable!(),
+                })
+                .collect();
+            levels.push(hashes);
+            hashes = next_level;
+        }
+        let root_hash = hashes.first().copied().unwrap_or_else(H256::zero);
+
+        let merkle_paths = indices
+            .into_iter()
+            .map(|mut index| {
+                assert!(index < leaf_count, "invalid tree leaf index");
+                let mut merkle_path = Vec::with_capacity(depth);
+                for (level, level_hashes) in levels.iter().enumerate() {
+                    let adjacent_hash = level_hashes
+                        .get(index ^ 1)
+                        .copied()
+                        .unwrap_or_else(|| self.hasher.empty_subtree_hash(level));
+                    merkle_path.push(adjacent_hash);
+                    index /= 2;
+                }
+                merkle_path
+            })
+            .collect();
+        (root_hash, merkle_paths)
+    }
+
     fn compute_merkle_root_and_path(
         self,
         mut index: usize,
@@ -141,6 +190,21 @@ impl<'a, const LEAF_SIZE: usize> MiniMerkleTree<'a, LEAF_SIZE> {
     }
 }
 
+/// Recomputes the root hash of a keccak-256 Merkle tree from the hash of a leaf with the specified
+/// 0-based `index` and its Merkle path, as returned by [`MiniMerkleTree::merkle_root_and_path()`].
+pub fn compute_root_from_path(leaf_hash: H256, mut index: usize, merkle_path: &[H256]) -> H256 {
+    let mut hash = leaf_hash;
+    for path_item in merkle_path {
+        hash = if index % 2 == 0 {
+            KeccakHasher.compress(&hash, path_item)
+        } else {
+            KeccakHasher.compress(path_item, &hash)
+        };
+        index /= 2;
+    }
+    hash
+}
+
 fn tree_depth_by_size(tree_size: usize) -> usize {
     debug_assert!(tree_size.is_power_of_two());
     tree_size.trailing_zeros() as usize
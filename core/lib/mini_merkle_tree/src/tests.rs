@@ -175,3 +175,32 @@ fn merkle_proofs_are_valid_in_larger_tree() {
         verify_merkle_proof(&item, i, 512, &path, merkle_root);
     }
 }
+
+#[test]
+fn batched_merkle_proofs_match_single_proofs() {
+    let leaves = (1_u8..=50).map(|byte| [byte; 88]);
+    let tree = MiniMerkleTree::new(leaves.clone(), 64);
+
+    let (merkle_root, paths) = tree.clone().merkle_root_and_paths(0..50);
+    assert_eq!(paths.len(), 50);
+    for (i, (item, path)) in leaves.zip(paths).enumerate() {
+        assert_eq!(
+            tree.clone().merkle_root_and_path(i),
+            (merkle_root, path.clone())
+        );
+        verify_merkle_proof(&item, i, 64, &path, merkle_root);
+    }
+}
+
+#[test]
+fn root_is_recomputed_from_merkle_path() {
+    let leaves = (1_u8..=50).map(|byte| [byte; 88]);
+    let tree = MiniMerkleTree::new(leaves.clone(), 64);
+
+    for (i, item) in leaves.enumerate() {
+        let (merkle_root, path) = tree.clone().merkle_root_and_path(i);
+        let leaf_hash = KeccakHasher.hash_bytes(&item);
+        assert_eq!(compute_root_from_path(leaf_hash, i, &path), merkle_root);
+        assert_ne!(compute_root_from_path(leaf_hash, i ^ 1, &path), merkle_root);
+    }
+}
@@ -48,6 +48,10 @@ pub struct SyncBlock {
     pub base_system_contracts_hashes: BaseSystemContractsHashes,
     /// Address of the operator account who produced for the L1 batch corresponding to this L2 block.
     pub operator_address: Address,
+    /// Protocol version of the L1 batch corresponding to this L2 block.
+    /// May be `None` if the L1 batch is not sealed yet or if the main node didn't record the version.
+    #[serde(default)]
+    pub protocol_version: Option<u16>,
     /// List of transactions included in the L2 block.
     /// These are not the API representation of transactions, but rather the actual type used by the server.
     /// May be `None` if transactions were not requested (as opposed to the empty vector).
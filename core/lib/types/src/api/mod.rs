@@ -6,12 +6,14 @@ pub use crate::transaction_request::{
 };
 use crate::vm_trace::{Call, CallType};
 use crate::web3::types::{AccessList, Index, H2048};
-use crate::{Address, MiniblockNumber};
+use crate::{Address, L1BatchNumber, MiniblockNumber};
+use bigdecimal::BigDecimal;
 use chrono::{DateTime, Utc};
 pub use zksync_basic_types::web3::{
     self, ethabi,
     types::{Bytes, Work, H160, H256, H64, U256, U64},
 };
+use zksync_contracts::BaseSystemContractsHashes;
 
 pub mod en;
 
@@ -136,6 +138,41 @@ impl From<BlockIdVariant> for BlockId {
     }
 }
 
+/// Miniblock referenced either by its number, e.g. `42`, or by a block tag or a hex number
+/// accepted by the `eth` namespace, e.g. `"latest"` or `"0x2a"`.
+#[derive(Copy, Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum MiniblockId {
+    Number(MiniblockNumber),
+    Tag(BlockNumber),
+}
+
+impl From<MiniblockNumber> for MiniblockId {
+    fn from(number: MiniblockNumber) -> Self {
+        Self::Number(number)
+    }
+}
+
+impl From<MiniblockId> for BlockId {
+    fn from(value: MiniblockId) -> BlockId {
+        match value {
+            MiniblockId::Number(number) => BlockId::Number(BlockNumber::Number(number.0.into())),
+            MiniblockId::Tag(tag) => BlockId::Number(tag),
+        }
+    }
+}
+
+/// Point in the chain history addressed either by a miniblock or by an L1 batch number.
+/// Serialized as a tagged object, e.g. `{"miniblock": 42}` or `{"l1Batch": 7}`.
+#[derive(Copy, Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum BlockOrBatchNumber {
+    /// State right after the specified miniblock.
+    Miniblock(MiniblockNumber),
+    /// State right after the last miniblock of the specified (sealed) L1 batch.
+    L1Batch(L1BatchNumber),
+}
+
 /// Transaction variant
 ///
 /// Utility structure. Some Web3 API methods have to return a block with a list of either full
@@ -173,6 +210,177 @@ pub struct L2ToL1LogProof {
     pub id: u32,
     /// The root of the tree.
     pub root: H256,
+    /// Hashes of all the leaves in the tree, in order. Only present if the full tree was requested.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub leaves: Option<Vec<H256>>,
+    /// Version of the proof format. Clients should reject proofs with unknown versions.
+    #[serde(default)]
+    pub proof_version: u16,
+    /// Number of the L1 batch the log was included into.
+    #[serde(default)]
+    pub l1_batch_number: Option<L1BatchNumber>,
+    /// Whether the L1 batch is executed on L1. Withdrawals can only be finalized
+    /// with proofs for executed batches.
+    #[serde(default)]
+    pub is_executed: bool,
+}
+
+impl L2ToL1LogProof {
+    /// Proof in a Merkle tree over serialized L2 -> L1 logs of the batch, padded to
+    /// `L2ToL1Log::LIMIT_PER_BLOCK` leaves.
+    pub const MINI_MERKLE_TREE_VERSION: u16 = 0;
+
+    /// Proof formats paired with the first protocol version using them, in ascending order.
+    const PROOF_VERSIONS: &'static [(u16, u16)] = &[(0, Self::MINI_MERKLE_TREE_VERSION)];
+
+    /// Returns the proof format for L1 batches sealed under `protocol_version`. Batches sealed
+    /// before protocol versions were recorded (i.e., with `None` version) use the initial format.
+    pub fn proof_version_for(protocol_version: Option<u16>) -> u16 {
+        Self::select_proof_version(Self::PROOF_VERSIONS, protocol_version)
+    }
+
+    fn select_proof_version(proof_versions: &[(u16, u16)], protocol_version: Option<u16>) -> u16 {
+        let protocol_version = protocol_version.unwrap_or(0);
+        proof_versions
+            .iter()
+            .rev()
+            .find(|&&(first_protocol_version, _)| first_protocol_version <= protocol_version)
+            .map(|&(_, proof_version)| proof_version)
+            .expect("proof formats must start from protocol version 0")
+    }
+}
+
+/// Information about the L1 batch that is currently being filled by the state keeper.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NextL1BatchInfo {
+    /// Number of the next L1 batch to be sealed.
+    pub number: L1BatchNumber,
+    /// Timestamp of the first miniblock in the batch. `None` if the batch has no miniblocks yet.
+    pub opened_at: Option<u64>,
+    /// Number of transactions already included into the batch.
+    pub tx_count: u64,
+    /// Best-effort estimate of the batch seal timestamp based on the batch timeout and its current
+    /// fill rate. Batches can be sealed earlier by other criteria (e.g., gas or pubdata limits),
+    /// so this is not a guarantee. `None` if the node cannot estimate it (e.g., on external nodes).
+    pub estimated_seal_timestamp: Option<u64>,
+}
+
+/// State of the database connection pool used by the API server.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConnectionPoolStats {
+    /// Number of open connections, both idle and in use.
+    pub size: u32,
+    pub idle: u32,
+    pub active: u32,
+    /// Number of connections acquired since the server start.
+    pub acquired_count: u64,
+    pub mean_acquire_time_us: u64,
+    pub max_acquire_time_us: u64,
+}
+
+/// Result of re-executing a processed transaction on top of its original pre-state.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionReplay {
+    pub transaction_hash: H256,
+    pub miniblock_number: MiniblockNumber,
+    pub success: bool,
+    pub revert_reason: Option<String>,
+    pub gas_used: U256,
+    pub logs: Vec<ReplayedLog>,
+    /// Final values of storage slots written by the transaction, ordered by hashed key.
+    pub state_diff: Vec<StorageSlotDiff>,
+    /// Human-readable descriptions of the differences between the replayed and the recorded outcome.
+    /// Empty if the replay matches the recorded outcome.
+    pub discrepancies: Vec<String>,
+}
+
+/// Event emitted during a transaction replay.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReplayedLog {
+    pub address: Address,
+    pub topics: Vec<H256>,
+    pub data: Bytes,
+}
+
+/// A page of proofs for the L2 -> L1 logs of an L1 batch.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BatchL2ToL1LogProofsPage {
+    pub l1_batch_number: L1BatchNumber,
+    /// Root of the tree over all L2 -> L1 logs of the batch.
+    pub root: H256,
+    /// Proofs ordered by the log index in the batch (`id`).
+    pub proofs: Vec<L2ToL1LogProof>,
+    /// Cursor to request the next page with; `None` for the last page.
+    pub next_cursor: Option<u32>,
+}
+
+/// Parameters of the `finalizeWithdrawal` call on the L1 bridge for a withdrawal transaction.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WithdrawalParams {
+    /// Number of the L1 batch the withdrawal was included into.
+    pub l1_batch_number: L1BatchNumber,
+    /// Index of the L2 -> L1 message in the batch, i.e. the leaf index in the batch tree.
+    pub l2_message_index: u32,
+    /// Index of the withdrawal transaction in the L1 batch.
+    pub l2_tx_number_in_batch: u16,
+    pub miniblock_number: MiniblockNumber,
+    /// Address of the contract that sent the L2 -> L1 message (usually the L2 bridge).
+    pub sender: Address,
+    /// Message sent to L1.
+    pub message: Bytes,
+    /// Merkle path for the message in the batch tree.
+    pub proof: Vec<H256>,
+    /// Whether the L1 batch is executed on L1. The withdrawal can only be finalized if it is.
+    pub is_executed: bool,
+}
+
+/// Result of the server-side verification of an L2 -> L1 log proof.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct L2ToL1LogProofVerification {
+    /// Whether the computed root matches both the root claimed by the proof and the root
+    /// of the tree over the batch logs.
+    pub valid: bool,
+    /// Root computed from the supplied leaf and Merkle path.
+    pub computed_root: H256,
+    /// Root of the tree over all L2 -> L1 logs of the batch.
+    pub expected_root: H256,
+}
+
+/// Protocol version recorded for L1 batches sealed by the node.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProtocolVersion {
+    pub version_id: u16,
+    /// Timestamp of the first L1 batch produced with this version.
+    pub timestamp: u64,
+    /// Hash of the verification key batch proofs are checked against. Only known for the latest
+    /// version; `None` for earlier versions or if the node doesn't have the key hash configured.
+    pub verification_keys_hash: Option<H256>,
+    pub base_system_contracts: BaseSystemContractsHashes,
+}
+
+/// Headline metrics of the chain. The values are cached by the node and can lag behind
+/// the chain head by up to the refresh interval (60 seconds by default).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChainStats {
+    /// Number of sealed L1 batches, including the genesis one.
+    pub l1_batch_count: u64,
+    /// Number of sealed miniblocks, including the genesis one.
+    pub miniblock_count: u64,
+    /// Number of transactions included into sealed miniblocks.
+    pub transaction_count: u64,
+    /// Number of distinct initiators of the included transactions.
+    pub account_count: u64,
+    /// Unix timestamp of the moment the values were computed.
+    pub updated_at: u64,
 }
 
 /// A struct with the two default bridge contracts.
@@ -185,6 +393,14 @@ pub struct BridgeAddresses {
     pub l2_weth_bridge: Option<Address>,
 }
 
+/// Bridgehub contract on L1 and the ID the chain is registered with on it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BridgehubContract {
+    pub bridgehub_proxy_addr: Address,
+    pub chain_id: U64,
+}
+
 #[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
 pub struct TransactionReceipt {
     /// Transaction hash.
@@ -246,6 +462,30 @@ pub struct TransactionReceipt {
     pub effective_gas_price: Option<U256>,
 }
 
+/// Position of an L2 -> L1 log emitted by a transaction in the L1 batch, required to finalize
+/// withdrawals on L1.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct L2ToL1LogIndex {
+    /// Index of the log among the logs of the transaction, i.e. the `index` argument of `zks_getL2ToL1LogProof`.
+    pub log_index_in_tx: u32,
+    /// Index of the log among the logs of the L1 batch. Equals to the `id` of the log proof and is passed
+    /// as the message index to the L1 bridge finalization methods.
+    pub log_index_in_batch: u32,
+    pub sender: Address,
+    pub key: H256,
+}
+
+/// Transaction receipt extended with the batch positions of its L2 -> L1 logs.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExtendedTransactionReceipt {
+    #[serde(flatten)]
+    pub receipt: TransactionReceipt,
+    /// Positions of the L2 -> L1 logs of the transaction. Empty if the transaction is not in a sealed L1 batch yet.
+    pub l2_to_l1_log_indices: Vec<L2ToL1LogIndex>,
+}
+
 /// The block type returned from RPC calls.
 /// This is generic over a `TX` type.
 #[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
@@ -521,6 +761,502 @@ pub struct TransactionDetails {
     pub eth_commit_tx_hash: Option<H256>,
     pub eth_prove_tx_hash: Option<H256>,
     pub eth_execute_tx_hash: Option<H256>,
+    /// Index of the transaction in its miniblock. `None` if the transaction is not included yet.
+    #[serde(default)]
+    pub index_in_block: Option<u32>,
+    /// Index of the transaction in its L1 batch. `None` if the transaction is not included yet.
+    #[serde(default)]
+    pub l1_batch_tx_index: Option<u32>,
+    /// Gas limit requested by the transaction.
+    #[serde(default)]
+    pub gas_limit: Option<U256>,
+    /// Gas actually spent by the transaction. `None` if the transaction is not executed yet.
+    #[serde(default)]
+    pub gas_used: Option<U256>,
+    /// Gas limit the node would estimate for the transaction on top of the preceding miniblock.
+    /// Only computed if explicitly requested, since it requires re-executing the transaction.
+    #[serde(default)]
+    pub estimated_gas_limit: Option<U256>,
+    /// Raw signed transaction bytes as submitted to the node. Only returned if explicitly requested;
+    /// always `None` for L1-originated transactions, which have no signed L2 form.
+    #[serde(default)]
+    pub raw_transaction: Option<Bytes>,
+    /// Max fee per gas set by the initiator when submitting the transaction.
+    #[serde(default)]
+    pub max_fee_per_gas: Option<U256>,
+    /// Maximum fee the initiator agreed to pay at submission, i.e. `gas_limit * max_fee_per_gas`.
+    /// The charged `fee` is usually lower because of the effective gas price and gas refunds.
+    #[serde(default)]
+    pub max_fee: Option<U256>,
+}
+
+/// Gas limit recommended for an L1->L2 transaction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct L1ToL2GasLimitRecommendation {
+    /// Gas limit returned by `zks_estimateGasL1ToL2`.
+    pub estimated_gas_limit: U256,
+    /// Estimated gas limit with the safety buffer applied.
+    pub recommended_gas_limit: U256,
+}
+
+/// How publishing pubdata on L1 is priced.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum PubdataPricingMode {
+    /// Pubdata is posted to L1 as calldata, each byte costing a fixed amount of L1 gas.
+    Rollup,
+}
+
+/// Parameters of the fee model used by the node, sufficient to replicate fee estimation locally.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FeeModelParams {
+    /// L1 gas price used for estimation, in wei, with `gas_price_scale_factor` already applied.
+    pub l1_gas_price: U256,
+    /// Fair price for L2 gas, in wei.
+    pub fair_l2_gas_price: U256,
+    /// Factor the L1 gas price reported by the gas price provider is multiplied by during estimation.
+    pub gas_price_scale_factor: f64,
+    /// Currently always `Rollup`: the node publishes all pubdata on L1 and prices it using the L1 gas price.
+    pub pubdata_pricing_mode: PubdataPricingMode,
+    /// L1 gas spent per byte of published pubdata.
+    pub l1_gas_per_pubdata_byte: u32,
+    /// L2 gas overhead of an L1 batch, shared by its transactions.
+    pub batch_overhead_l2_gas: u32,
+    /// L1 gas overhead of an L1 batch, shared by its transactions.
+    pub batch_overhead_l1_gas: u32,
+    /// Pubdata overhead of an L1 batch in bytes, shared by its transactions.
+    pub batch_overhead_pubdata: u32,
+}
+
+/// Stage of the L1 batch lifecycle.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum L1BatchStage {
+    /// The batch is sealed by the state keeper.
+    #[default]
+    Sealed,
+    /// The batch commit transaction is confirmed on L1.
+    Committed,
+    /// The batch prove transaction is confirmed on L1.
+    Proven,
+    /// The batch execute transaction is confirmed on L1.
+    Executed,
+}
+
+/// L1 batch lifecycle event, e.g. the batch being sealed or its commit transaction being confirmed on L1.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct L1BatchEvent {
+    pub l1_batch_number: L1BatchNumber,
+    /// Stage the batch has reached with this event.
+    pub stage: L1BatchStage,
+    /// UNIX timestamp of the event in seconds.
+    pub timestamp: u64,
+    /// Hash of the L1 transaction; `None` for sealing events.
+    pub l1_tx_hash: Option<H256>,
+}
+
+/// Stage of the transaction lifecycle reported by the `zks_subscribeTransaction` subscription.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum TransactionStage {
+    /// The transaction is accepted by the node, but is not included in a miniblock yet.
+    Accepted,
+    /// The transaction is included in a miniblock.
+    Included,
+    /// The proof for the L1 batch containing the transaction is verified on L1.
+    Proven,
+    /// The L1 batch containing the transaction is executed on L1, so the transaction is final.
+    Executed,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionStageUpdate {
+    pub transaction_hash: H256,
+    pub stage: TransactionStage,
+}
+
+/// Gas limits enforced by the node for miniblocks and transactions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BlockGasLimit {
+    /// Computational gas limit of a block, as reported in block headers.
+    pub block_gas_limit: U256,
+    /// Maximum gas limit of a single L2 transaction accepted by the node.
+    pub max_transaction_gas_limit: U256,
+}
+
+/// Confirmations a transaction still needs before it is considered final by the node.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionConfirmationsNeeded {
+    /// Number of miniblocks left to be sealed until the transaction is soft-confirmed.
+    pub blocks_to_soft_confirmation: u32,
+    /// Number of L1 batches left to be executed on L1 until the transaction is final.
+    pub batches_to_finality: u32,
+}
+
+/// Latest value of a storage slot modified between two L1 batch states.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StorageSlotDiff {
+    pub address: Address,
+    pub key: H256,
+    pub hashed_key: H256,
+    pub value: H256,
+}
+
+/// A page of storage changes made after `from_batch` up to and including `to_batch`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BatchDiff {
+    pub from_batch: L1BatchNumber,
+    pub to_batch: L1BatchNumber,
+    /// Changes ordered by hashed key.
+    pub changes: Vec<StorageSlotDiff>,
+    /// Hashed key to pass as `after` to request the next page; `None` for the last page.
+    pub next_after: Option<H256>,
+}
+
+/// A page of transactions included into an L1 batch.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BatchTransactionsPage {
+    pub l1_batch_number: L1BatchNumber,
+    /// Transactions in execution order, possibly spanning several miniblocks.
+    pub transactions: Vec<Transaction>,
+    /// Cursor to request the next page with; `None` for the last page.
+    pub next_cursor: Option<u32>,
+}
+
+/// A page of storage keys written by a contract.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountStorageKeysPage {
+    /// Storage keys in ascending order.
+    pub keys: Vec<H256>,
+    /// Cursor to request the next page with; `None` for the last page.
+    pub next_cursor: Option<H256>,
+}
+
+/// Non-zero balance of a single token held by an account.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TokenBalance {
+    /// L2 address of the token; ETH is reported under the zero address.
+    pub token_address: Address,
+    pub balance: U256,
+}
+
+/// A page of non-zero token balances of an account.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountBalancesPage {
+    /// Balances in ascending order of token address.
+    pub balances: Vec<TokenBalance>,
+    /// Last token address of this page to request the next page after; `None` for the last page.
+    pub next_cursor: Option<Address>,
+}
+
+/// Data submitted to L1 when committing an L1 batch.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BatchL1CommitData {
+    pub l1_batch_number: L1BatchNumber,
+    /// Hash of the L1 transaction that committed the batch. It may commit several batches at once.
+    pub commit_tx_hash: H256,
+    /// ABI-encoded `CommitBlockInfo` struct passed for the batch to `commitBlocks`.
+    pub commit_data: Bytes,
+}
+
+/// Inputs of an L1 batch commitment.
+///
+/// The commitment is `keccak256(pass_through_data_hash ++ meta_parameters_hash ++ aux_data_hash)`.
+/// Auxiliary data is built from the L2 -> L1 logs and the state diffs of the batch, which are
+/// represented by the compressed initial and repeated storage writes.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BatchCommitmentInputs {
+    pub l1_batch_number: L1BatchNumber,
+    pub commitment: H256,
+    pub pass_through_data_hash: H256,
+    pub meta_parameters_hash: H256,
+    pub aux_data_hash: H256,
+    /// Root hash of the state tree after the batch.
+    pub root_hash: H256,
+    pub rollup_last_leaf_index: u64,
+    pub zkporter_is_available: bool,
+    pub bootloader_code_hash: H256,
+    pub default_aa_code_hash: H256,
+    /// Serialized L2 -> L1 logs of the batch, prefixed with their number.
+    pub l2_l1_logs: Bytes,
+    pub l2_l1_logs_linear_hash: H256,
+    pub l2_l1_logs_merkle_root: H256,
+    /// Serialized initial storage writes of the batch, prefixed with their number.
+    pub initial_writes: Bytes,
+    pub initial_writes_hash: H256,
+    /// Serialized repeated storage writes of the batch, prefixed with their number.
+    pub repeated_writes: Bytes,
+    pub repeated_writes_hash: H256,
+}
+
+/// USD price of the token that fees are paid in.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BaseTokenPrice {
+    /// L2 address of the base token.
+    pub l2_address: Address,
+    pub usd_price: BigDecimal,
+    /// UNIX timestamp (in seconds) of the last price update.
+    pub timestamp: u64,
+}
+
+/// Fees collected in an L1 batch and the L1 costs of processing it. All values are in wei.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct L1BatchFees {
+    pub l1_batch_number: L1BatchNumber,
+    /// Total fees paid by the batch transactions, with refunds subtracted.
+    pub fees_collected: U256,
+    /// Share of the commit, prove and execute L1 transactions cost attributed to the batch.
+    /// Only operations already confirmed on L1 are accounted for.
+    pub l1_costs: U256,
+    /// `fees_collected` minus `l1_costs`, saturating at zero.
+    pub net_revenue: U256,
+}
+
+/// Aggregate statistics of an L1 batch.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct L1BatchStats {
+    pub l1_batch_number: L1BatchNumber,
+    pub transaction_count: u64,
+    /// Gas used by the batch transactions, with refunds subtracted.
+    pub gas_used: U256,
+    /// Size of the pubdata published on L1 for the batch in bytes. `None` if the batch
+    /// metadata is not computed yet.
+    pub pubdata_bytes: Option<u64>,
+    /// Timestamp of the first miniblock in the batch.
+    pub first_miniblock_timestamp: u64,
+    /// Timestamp of the last miniblock in the batch.
+    pub last_miniblock_timestamp: u64,
+}
+
+/// Machine-readable reason for rejecting a transaction in `zks_sendRawTransactionWithDetailedError`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum TransactionRejectionReason {
+    /// Transaction bytes cannot be decoded into a valid transaction.
+    MalformedTransaction,
+    NonceTooHigh,
+    NonceTooLow,
+    /// Transaction fails static checks (e.g., has invalid fee fields or factory deps).
+    IncorrectTransaction,
+    InsufficientFundsForFee,
+    InsufficientFundsForTransfer,
+    ExecutionReverted,
+    GasLimitTooBig,
+    IntrinsicGasTooLow,
+    Unexecutable,
+    RateLimitExceeded,
+    BootloaderFailure,
+    ValidationFailed,
+    FailedToChargeFee,
+    PaymasterValidationFailed,
+    PrePaymasterPreparationFailed,
+    FromIsNotAnAccount,
+    MaxFeePerGasTooLow,
+    MaxPriorityFeeGreaterThanMaxFee,
+    FeePerGasTooHigh,
+    FeePerPubdataByteTooHigh,
+    UnrealisticPubdataPriceLimit,
+    TooManyFactoryDependencies,
+    PubdataLimitExceeded,
+    UnexpectedVmBehavior,
+    /// Transaction was rejected by the main node the request was proxied to.
+    ProxyError,
+    /// Node failed to process the request for reasons unrelated to the transaction itself.
+    InternalError,
+}
+
+/// Structured description of a rejected transaction.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionRejection {
+    pub reason: TransactionRejectionReason,
+    /// Human-readable error message, same as the one returned by `eth_sendRawTransaction`.
+    pub message: String,
+    /// Revert data if the transaction reverted during execution.
+    pub revert_data: Option<Bytes>,
+}
+
+/// Progress of proof generation for an L1 batch that is not proven on L1 yet.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct L1BatchProvingStatus {
+    pub l1_batch_number: L1BatchNumber,
+    /// Number of prover jobs that finished successfully.
+    pub completed_jobs: u32,
+    /// Number of prover jobs that are queued or in progress.
+    pub pending_jobs: u32,
+    /// Number of prover jobs that failed; these may be retried.
+    pub failed_jobs: u32,
+}
+
+/// Aggregated on-chain activity of an address.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AddressActivity {
+    /// Number of included transactions initiated by the address.
+    pub sent_tx_count: u64,
+    /// Number of transactions initiated by other accounts that transferred tokens to the address.
+    pub received_tx_count: u64,
+    /// First miniblock in which the address sent or received a transaction.
+    pub first_seen_block: Option<MiniblockNumber>,
+    /// Last miniblock in which the address sent or received a transaction.
+    pub last_seen_block: Option<MiniblockNumber>,
+    /// Number of well-known tokens with a non-zero balance.
+    pub tokens_held: u32,
+}
+
+/// Code used to deploy a contract. Contracts in zkEVM don't have separate init code:
+/// the constructor is a part of the deployed bytecode, so `bytecode` is the same as the runtime code.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ContractCreationCode {
+    pub bytecode: Bytes,
+    /// Constructor arguments passed on deployment. `None` if the contract wasn't deployed
+    /// via a `ContractDeployer` call or the constructor wasn't called.
+    pub constructor_args: Option<Bytes>,
+}
+
+/// Bytecode published on L2 together with its metadata.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BytecodeDetails {
+    pub bytecode: Bytes,
+    /// Length of the bytecode in 32-byte words.
+    pub length_in_words: u32,
+    /// Whether the bytecode hash is marked as known in the `KnownCodesStorage` system contract.
+    pub known: bool,
+    /// Miniblock in which the bytecode was first published.
+    pub first_seen_miniblock: MiniblockNumber,
+}
+
+/// Order of tokens returned by `zks_getConfirmedTokensFiltered`. Ties are broken by the L2 address.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum TokenSortOrder {
+    ByName,
+    ByAddress,
+    #[default]
+    BySymbol,
+}
+
+/// Filter hiding spam / dust tokens from `zks_getConfirmedTokens`. Tokens must satisfy all specified bounds.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TokenLiquidityFilter {
+    /// Minimum number of addresses with a non-zero balance of the token.
+    pub min_holders: Option<u64>,
+    /// Minimum total supply of the token in its smallest units.
+    pub min_total_supply: Option<U256>,
+}
+
+impl TokenLiquidityFilter {
+    pub fn is_empty(&self) -> bool {
+        self.min_holders.is_none() && self.min_total_supply.is_none()
+    }
+}
+
+/// How to pick a miniblock for a timestamp that doesn't exactly match one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum TimestampRounding {
+    /// Last miniblock with the timestamp at or before the requested one.
+    Before,
+    /// First miniblock with the timestamp at or after the requested one.
+    After,
+    /// Miniblock with the timestamp closest to the requested one; ties are resolved as for `Before`.
+    Closest,
+}
+
+/// Number of transactions initiated by an address, split by their status.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AddressTransactionCount {
+    /// Number of included transactions that were executed successfully.
+    pub successful: u64,
+    /// Number of included transactions that failed, and of transactions rejected by the mempool.
+    pub failed: u64,
+    /// Number of transactions that are not included in a miniblock yet.
+    pub pending: u64,
+}
+
+/// Encoding of `U256` values returned by `zks_getAllAccountBalancesEncoded` and
+/// `zks_estimateGasL1ToL2Encoded`. Other methods always use hex encoding.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum IntegerEncoding {
+    /// `0x`-prefixed hex string, same as for other Web3 methods.
+    #[default]
+    Hex,
+    /// Decimal string.
+    Decimal,
+}
+
+/// `U256` value serialized according to the requested [`IntegerEncoding`].
+/// Both encodings are accepted when deserializing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EncodedU256 {
+    pub value: U256,
+    pub encoding: IntegerEncoding,
+}
+
+impl EncodedU256 {
+    pub fn new(value: U256, encoding: Option<IntegerEncoding>) -> Self {
+        Self {
+            value,
+            encoding: encoding.unwrap_or_default(),
+        }
+    }
+}
+
+impl From<EncodedU256> for U256 {
+    fn from(value: EncodedU256) -> Self {
+        value.value
+    }
+}
+
+impl Serialize for EncodedU256 {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self.encoding {
+            IntegerEncoding::Hex => self.value.serialize(serializer),
+            IntegerEncoding::Decimal => serializer.serialize_str(&self.value.to_string()),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for EncodedU256 {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        if value.starts_with("0x") {
+            let value = U256::deserialize(de::value::StrDeserializer::<D::Error>::new(&value))?;
+            Ok(Self::new(value, Some(IntegerEncoding::Hex)))
+        } else {
+            let value = U256::from_dec_str(&value).map_err(de::Error::custom)?;
+            Ok(Self::new(value, Some(IntegerEncoding::Decimal)))
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -603,3 +1339,39 @@ pub struct TracerConfig {
     pub tracer: SupportedTracers,
     pub tracer_config: CallTracerConfig,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn proof_version_for_known_protocol_versions() {
+        for protocol_version in [None, Some(0), Some(1), Some(u16::MAX)] {
+            assert_eq!(
+                L2ToL1LogProof::proof_version_for(protocol_version),
+                L2ToL1LogProof::MINI_MERKLE_TREE_VERSION
+            );
+        }
+    }
+
+    #[test]
+    fn proof_version_is_selected_by_batch_protocol_version() {
+        let proof_versions = [(0, 0), (3, 1), (7, 2)];
+        let expected = [
+            (None, 0),
+            (Some(0), 0),
+            (Some(2), 0),
+            (Some(3), 1),
+            (Some(6), 1),
+            (Some(7), 2),
+            (Some(100), 2),
+        ];
+        for (protocol_version, proof_version) in expected {
+            assert_eq!(
+                L2ToL1LogProof::select_proof_version(&proof_versions, protocol_version),
+                proof_version,
+                "{protocol_version:?}"
+            );
+        }
+    }
+}
@@ -160,6 +160,21 @@ impl BlockWithMetadata {
     pub fn l1_commit_data_size(&self) -> usize {
         crate::ethabi::encode(&[Token::Array(vec![self.l1_commit_data()])]).len()
     }
+
+    /// Returns the size of the data published on L1 for the block, i.e., the compressed storage writes,
+    /// L2 -> L1 logs and messages, and the published bytecodes.
+    pub fn pubdata_size(&self) -> usize {
+        self.metadata.initial_writes_compressed.len()
+            + self.metadata.repeated_writes_compressed.len()
+            + self.metadata.l2_l1_messages_compressed.len()
+            + self
+                .header
+                .l2_to_l1_messages
+                .iter()
+                .map(Vec::len)
+                .sum::<usize>()
+            + self.factory_deps.iter().map(Vec::len).sum::<usize>()
+    }
 }
 
 impl SerializeCommitment for L2ToL1Log {
@@ -40,7 +40,7 @@ pub static DEPLOY_EVENT_SIGNATURE: Lazy<H256> = Lazy::new(|| {
     )
 });
 
-static L1_MESSAGE_EVENT_SIGNATURE: Lazy<H256> = Lazy::new(|| {
+pub static L1_MESSAGE_EVENT_SIGNATURE: Lazy<H256> = Lazy::new(|| {
     ethabi::long_signature(
         "L1MessageSent",
         &[
@@ -114,6 +114,18 @@ pub enum BlockStatus {
     Verified,
 }
 
+/// Finality of a miniblock on L1, derived from the L1 transactions of the batch it belongs to.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum BlockFinality {
+    /// The batch is not committed yet (including the case when it's still being sealed).
+    #[default]
+    Pending,
+    Committed,
+    Proven,
+    Executed,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub enum TransactionStatus {
@@ -287,6 +299,23 @@ pub struct BlockDetails {
     pub l2_fair_gas_price: u64,
     pub base_system_contracts_hashes: BaseSystemContractsHashes,
     pub operator_address: Address,
+    #[serde(default)]
+    pub finality: BlockFinality,
+}
+
+/// Header fields of a miniblock, a lightweight subset of [`BlockDetails`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BlockHeaderDetails {
+    pub number: MiniblockNumber,
+    pub hash: H256,
+    /// Hash of the previous miniblock; zero for the genesis miniblock.
+    pub parent_hash: H256,
+    pub timestamp: u64,
+    pub l1_batch_number: L1BatchNumber,
+    /// State root of the L1 batch containing the miniblock. `None` if the batch is not sealed
+    /// or its metadata is not yet calculated.
+    pub root_hash: Option<H256>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -307,6 +336,45 @@ pub struct L1BatchDetails {
     pub l1_gas_price: u64,
     pub l2_fair_gas_price: u64,
     pub base_system_contracts_hashes: BaseSystemContractsHashes,
+    /// Data availability mode the batch is committed with.
+    #[serde(default)]
+    pub commitment_mode: L1BatchCommitmentMode,
+    /// Name of the sealing rule that caused the batch to be sealed, e.g. `slots` or `no_txs_timeout`.
+    /// `None` if the reason wasn't recorded (e.g., for batches sealed by an external node).
+    #[serde(default)]
+    pub seal_reason: Option<String>,
+    /// Whether a batch with this number was reverted on L1. Reverted batches are removed from the node storage
+    /// once it is rolled back, after which the number is reused by a newly sealed batch.
+    #[serde(default)]
+    pub reverted: bool,
+    /// Hash of the latest L1 transaction that reverted a batch with this number.
+    #[serde(default)]
+    pub revert_tx_hash: Option<H256>,
+    /// Reference to the batch pubdata on an external data availability layer (e.g., a blob ID).
+    /// `None` if the pubdata is published on L1, which is currently the case for all batches.
+    #[serde(default)]
+    pub da_reference: Option<String>,
+    /// Time in seconds between the batch commit and proof L1 transactions, i.e. proving latency.
+    /// `None` until the batch is proven.
+    #[serde(default)]
+    pub commit_to_prove_secs: Option<u64>,
+    /// Time in seconds between the batch proof and execute L1 transactions. `None` until the batch is executed.
+    #[serde(default)]
+    pub prove_to_execute_secs: Option<u64>,
+    /// Address of the L1 verifier contract the batch proof was submitted to. `None` until the batch is proven.
+    #[serde(default)]
+    pub verifier_address: Option<Address>,
+}
+
+/// Data availability mode an L1 batch was committed with.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum L1BatchCommitmentMode {
+    /// All the pubdata of the batch is published on L1.
+    #[default]
+    Rollup,
+    /// A part of the batch pubdata is kept off-chain.
+    Validium,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
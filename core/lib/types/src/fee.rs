@@ -1,7 +1,7 @@
 use serde::{Deserialize, Serialize};
 use zksync_utils::ceil_div;
 
-use crate::U256;
+use crate::{L1_GAS_PER_PUBDATA_BYTE, U256};
 
 #[derive(Debug, Default, Clone, Copy, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase", tag = "result")]
@@ -50,6 +50,97 @@ impl Fee {
     }
 }
 
+/// Shape of the fee estimate returned by `zks_estimateFee`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum FeeFormat {
+    /// zkSync-native [`Fee`].
+    #[default]
+    Native,
+    /// EIP-1559-style fields (see [`Eip1559FeeEstimate`]).
+    Eip1559,
+}
+
+/// Fee estimate in the base fee / priority fee shape expected by Ethereum wallet libraries.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Eip1559FeeEstimate {
+    pub gas: U256,
+    pub max_fee_per_gas: U256,
+    pub max_priority_fee_per_gas: U256,
+    /// The same estimate as the zkSync-native fee.
+    pub fee: Fee,
+}
+
+/// Fee estimate in one of the supported [`FeeFormat`]s.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum FeeEstimate {
+    Eip1559(Eip1559FeeEstimate),
+    Native(Fee),
+}
+
+impl FeeEstimate {
+    pub fn new(fee: Fee, format: Option<FeeFormat>) -> Self {
+        match format.unwrap_or_default() {
+            FeeFormat::Native => Self::Native(fee),
+            FeeFormat::Eip1559 => Self::Eip1559(Eip1559FeeEstimate {
+                gas: fee.gas_limit,
+                max_fee_per_gas: fee.max_fee_per_gas,
+                max_priority_fee_per_gas: fee.max_priority_fee_per_gas,
+                fee,
+            }),
+        }
+    }
+}
+
+impl From<FeeEstimate> for Fee {
+    fn from(estimate: FeeEstimate) -> Self {
+        match estimate {
+            FeeEstimate::Eip1559(estimate) => estimate.fee,
+            FeeEstimate::Native(fee) => fee,
+        }
+    }
+}
+
+/// Fee estimate with the estimated gas limit split into its components.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DetailedFee {
+    /// The same estimate as the zkSync-native fee. Its gas limit is the sum of the components below.
+    pub fee: Fee,
+    /// Gas required to execute the transaction body, scaled by the estimation scale factor.
+    pub computational_gas: U256,
+    /// Gas required to publish the bytecodes of the factory dependencies.
+    /// Always zero for L1 transactions, since their bytecodes are published on L1.
+    pub pubdata_gas: U256,
+    /// Gas charged for the transaction's share of the batch overhead.
+    pub overhead_gas: U256,
+}
+
+/// Fee parameters used by the state keeper for the latest miniblock.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FeeParams {
+    /// L1 gas price, in wei.
+    pub l1_gas_price: U256,
+    /// Fair price for L2 gas, in wei.
+    pub fair_l2_gas_price: U256,
+    /// Price of publishing a single byte of pubdata on L1, in wei.
+    pub l1_pubdata_price: U256,
+}
+
+impl FeeParams {
+    pub fn new(l1_gas_price: u64, fair_l2_gas_price: u64) -> Self {
+        let l1_gas_price = U256::from(l1_gas_price);
+        Self {
+            l1_gas_price,
+            fair_l2_gas_price: fair_l2_gas_price.into(),
+            l1_pubdata_price: l1_gas_price * L1_GAS_PER_PUBDATA_BYTE,
+        }
+    }
+}
+
 /// Returns how many slots would ABI-encoding of the transaction with such parameters take
 pub fn encoding_len(
     data_len: u64,
@@ -62,6 +62,13 @@ pub struct CallRequest {
     /// Eip712 meta
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub eip712_meta: Option<Eip712Meta>,
+    /// Paymaster to estimate the fee with. Shorthand for `eip712Meta.paymasterParams`, which takes
+    /// precedence if both are provided. Only used by `zks_estimateFee`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub paymaster: Option<Address>,
+    /// Input for the `paymaster`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub paymaster_input: Option<Bytes>,
 }
 
 impl CallRequest {
@@ -142,6 +149,13 @@ impl CallRequestBuilder {
         self
     }
 
+    /// Set paymaster and its input
+    pub fn paymaster(mut self, paymaster: Address, paymaster_input: Bytes) -> Self {
+        self.call_request.paymaster = Some(paymaster);
+        self.call_request.paymaster_input = Some(paymaster_input);
+        self
+    }
+
     /// build the Call Request
     pub fn build(&self) -> CallRequest {
         self.call_request.clone()
@@ -1443,6 +1457,8 @@ mod tests {
             transaction_type: Some(U64::from(EIP_712_TX_TYPE)),
             access_list: None,
             eip712_meta: None,
+            paymaster: None,
+            paymaster_input: None,
         };
 
         let try_to_l2_tx: Result<L2Tx, SerializationTransactionError> =
@@ -1469,6 +1485,8 @@ mod tests {
             transaction_type: Some(U64::from(EIP_712_TX_TYPE)),
             access_list: None,
             eip712_meta: None,
+            paymaster: None,
+            paymaster_input: None,
         };
         let tx_request = tx_req_from_call_req(
             call_request_with_nonce.clone(),
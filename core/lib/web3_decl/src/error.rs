@@ -1,7 +1,10 @@
 //! Definition of errors that can occur in the zkSync Web3 API.
 
 use thiserror::Error;
-use zksync_types::api::SerializationTransactionError;
+use zksync_types::{
+    api::{SerializationTransactionError, TransactionRejection},
+    L1BatchNumber,
+};
 
 #[derive(Debug, Error)]
 pub enum Web3Error {
@@ -35,4 +38,27 @@ pub enum Web3Error {
     LogsLimitExceeded(usize, u32, u32),
     #[error("invalid filter: if blockHash is supplied fromBlock and toBlock must not be")]
     InvalidFilterBlockHash,
+    #[error("Requested range is too large, max allowed range is {max}")]
+    RangeTooLarge { max: u32 },
+    #[error("Invalid range: start {from} is greater than end {to}")]
+    InvalidRange { from: u32, to: u32 },
+    #[error(
+        "Transaction requires {pubdata} bytes of pubdata, while at most {limit} can be published \
+        in an L1 batch, so it can never be included"
+    )]
+    PubdataLimitExceeded { pubdata: u32, limit: u32 },
+    #[error("L1 batch contains {0} L2->L1 logs, while at most {1} can be returned as a full tree")]
+    TooManyL2ToL1Logs(usize, usize),
+    #[error("Unsupported L2->L1 log proof version: {0}")]
+    UnsupportedProofVersion(u16),
+    #[error("Token is not known to the node")]
+    UnknownToken,
+    #[error("No price is recorded for the token at or before the requested block")]
+    NoTokenPrice,
+    #[error("L1 batch #{0} is not proven yet")]
+    L1BatchNotProven(L1BatchNumber),
+    #[error("Invalid bytecode: {0}")]
+    InvalidBytecode(String),
+    #[error("{}", .0.message)]
+    TransactionRejected(TransactionRejection),
 }
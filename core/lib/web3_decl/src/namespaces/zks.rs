@@ -2,13 +2,24 @@ use crate::types::Token;
 use bigdecimal::BigDecimal;
 use jsonrpsee::{core::RpcResult, proc_macros::rpc};
 use std::collections::HashMap;
-use zksync_types::api::{BridgeAddresses, L2ToL1LogProof, TransactionDetails};
+use zksync_types::api::{
+    AccountBalancesPage, AccountStorageKeysPage, AddressActivity, AddressTransactionCount,
+    BaseTokenPrice, BatchCommitmentInputs, BatchDiff, BatchL1CommitData, BatchL2ToL1LogProofsPage,
+    BatchTransactionsPage, BlockGasLimit, BlockId, BlockIdVariant, BlockOrBatchNumber,
+    BridgeAddresses, BridgehubContract, BytecodeDetails, ChainStats, ConnectionPoolStats,
+    ContractCreationCode, EncodedU256, ExtendedTransactionReceipt, FeeModelParams, IntegerEncoding,
+    L1BatchEvent, L1BatchFees, L1BatchProvingStatus, L1BatchStage, L1BatchStats,
+    L1ToL2GasLimitRecommendation, L2ToL1LogProof, L2ToL1LogProofVerification, MiniblockId,
+    NextL1BatchInfo, ProtocolVersion, TimestampRounding, TokenLiquidityFilter, TokenSortOrder,
+    TransactionConfirmationsNeeded, TransactionDetails, TransactionReplay, WithdrawalParams,
+};
 use zksync_types::transaction_request::CallRequest;
 use zksync_types::{
     api::U64,
-    explorer_api::{BlockDetails, L1BatchDetails},
-    fee::Fee,
-    Address, H256, U256,
+    explorer_api::{BlockDetails, BlockHeaderDetails, L1BatchDetails},
+    fee::{DetailedFee, FeeEstimate, FeeFormat},
+    tokens::TokenPrice,
+    Address, Bytes, H256, U256,
 };
 use zksync_types::{L1BatchNumber, MiniblockNumber};
 
@@ -26,14 +37,47 @@ use zksync_types::{L1BatchNumber, MiniblockNumber};
 )]
 pub trait ZksNamespace {
     #[method(name = "estimateFee")]
-    async fn estimate_fee(&self, req: CallRequest) -> RpcResult<Fee>;
+    async fn estimate_fee(
+        &self,
+        req: CallRequest,
+        deterministic: Option<bool>,
+        format: Option<FeeFormat>,
+    ) -> RpcResult<FeeEstimate>;
+
+    #[method(name = "estimateFeeDetailed")]
+    async fn estimate_fee_detailed(&self, req: CallRequest) -> RpcResult<DetailedFee>;
 
     #[method(name = "estimateGasL1ToL2")]
     async fn estimate_gas_l1_to_l2(&self, req: CallRequest) -> RpcResult<U256>;
 
+    /// Same as `estimateGasL1ToL2`, but allows to return the estimate as a decimal string.
+    #[method(name = "estimateGasL1ToL2Encoded")]
+    async fn estimate_gas_l1_to_l2_encoded(
+        &self,
+        req: CallRequest,
+        encoding: Option<IntegerEncoding>,
+    ) -> RpcResult<EncodedU256>;
+
+    #[method(name = "estimateGasL1ToL2Detailed")]
+    async fn estimate_gas_l1_to_l2_detailed(
+        &self,
+        req: CallRequest,
+        gas_per_pubdata: Option<U256>,
+        refund_recipient: Option<Address>,
+    ) -> RpcResult<U256>;
+
+    #[method(name = "getRecommendedL1ToL2GasLimit")]
+    async fn get_recommended_l1_to_l2_gas_limit(
+        &self,
+        req: CallRequest,
+    ) -> RpcResult<L1ToL2GasLimitRecommendation>;
+
     #[method(name = "getMainContract")]
     async fn get_main_contract(&self) -> RpcResult<Address>;
 
+    #[method(name = "getBridgehubContract")]
+    async fn get_bridgehub_contract(&self) -> RpcResult<Option<BridgehubContract>>;
+
     #[method(name = "getTestnetPaymaster")]
     async fn get_testnet_paymaster(&self) -> RpcResult<Option<Address>>;
 
@@ -43,15 +87,74 @@ pub trait ZksNamespace {
     #[method(name = "L1ChainId")]
     async fn l1_chain_id(&self) -> RpcResult<U64>;
 
+    #[method(name = "getCurrentBlockGasLimit")]
+    async fn get_current_block_gas_limit(&self) -> RpcResult<BlockGasLimit>;
+
     #[method(name = "getConfirmedTokens")]
-    async fn get_confirmed_tokens(&self, from: u32, limit: u8) -> RpcResult<Vec<Token>>;
+    async fn get_confirmed_tokens(
+        &self,
+        from: u32,
+        limit: u8,
+        eth_first: Option<bool>,
+        verify_onchain: Option<bool>,
+        liquidity_filter: Option<TokenLiquidityFilter>,
+    ) -> RpcResult<Vec<Token>>;
+
+    #[method(name = "getConfirmedTokensIfModified")]
+    async fn get_confirmed_tokens_if_modified(
+        &self,
+        if_modified_after: u64,
+        from: u32,
+        limit: u8,
+        eth_first: Option<bool>,
+        verify_onchain: Option<bool>,
+    ) -> RpcResult<Option<Vec<Token>>>;
+
+    #[method(name = "getConfirmedTokensFiltered")]
+    async fn get_confirmed_tokens_filtered(
+        &self,
+        symbol: Option<String>,
+        sort: Option<TokenSortOrder>,
+        from: u32,
+        limit: u8,
+    ) -> RpcResult<Vec<Token>>;
+
+    #[method(name = "getTokenByL2Address")]
+    async fn get_token_by_l2_address(&self, l2_address: Address) -> RpcResult<Option<Token>>;
+
     #[method(name = "getTokenPrice")]
     async fn get_token_price(&self, token_address: Address) -> RpcResult<BigDecimal>;
 
+    #[method(name = "getTokenPriceAt")]
+    async fn get_token_price_at(
+        &self,
+        token_address: Address,
+        at: BlockIdVariant,
+    ) -> RpcResult<TokenPrice>;
+
+    #[method(name = "getBaseTokenPrice")]
+    async fn get_base_token_price(&self) -> RpcResult<Option<BaseTokenPrice>>;
+
     #[method(name = "getAllAccountBalances")]
     async fn get_all_account_balances(&self, address: Address)
         -> RpcResult<HashMap<Address, U256>>;
 
+    /// Same as `getAllAccountBalances`, but allows to return balances as decimal strings.
+    #[method(name = "getAllAccountBalancesEncoded")]
+    async fn get_all_account_balances_encoded(
+        &self,
+        address: Address,
+        encoding: Option<IntegerEncoding>,
+    ) -> RpcResult<HashMap<Address, EncodedU256>>;
+
+    #[method(name = "getAccountBalancesPaged")]
+    async fn get_account_balances_paged(
+        &self,
+        address: Address,
+        after_token: Option<Address>,
+        limit: Option<u32>,
+    ) -> RpcResult<AccountBalancesPage>;
+
     #[method(name = "getL2ToL1MsgProof")]
     async fn get_l2_to_l1_msg_proof(
         &self,
@@ -66,36 +169,256 @@ pub trait ZksNamespace {
         &self,
         tx_hash: H256,
         index: Option<usize>,
+        full_tree: Option<bool>,
+    ) -> RpcResult<Option<L2ToL1LogProof>>;
+
+    #[method(name = "getWithdrawalParams")]
+    async fn get_withdrawal_params(
+        &self,
+        withdrawal_hash: H256,
+        index: Option<usize>,
+    ) -> RpcResult<Option<WithdrawalParams>>;
+
+    #[method(name = "getL2ToL1LogProofForBatch")]
+    async fn get_l2_to_l1_log_proof_for_batch(
+        &self,
+        batch: L1BatchNumber,
+        index: usize,
     ) -> RpcResult<Option<L2ToL1LogProof>>;
 
+    #[method(name = "getBatchL2ToL1LogProofs")]
+    async fn get_batch_l2_to_l1_log_proofs(
+        &self,
+        batch: L1BatchNumber,
+        cursor: Option<u32>,
+        limit: Option<u32>,
+    ) -> RpcResult<Option<BatchL2ToL1LogProofsPage>>;
+
+    #[method(name = "verifyL2ToL1LogProof")]
+    async fn verify_l2_to_l1_log_proof(
+        &self,
+        proof: L2ToL1LogProof,
+        batch: L1BatchNumber,
+        leaf: H256,
+    ) -> RpcResult<Option<L2ToL1LogProofVerification>>;
+
     #[method(name = "L1BatchNumber")]
     async fn get_l1_batch_number(&self) -> RpcResult<U64>;
 
+    #[method(name = "getNextBatchInfo")]
+    async fn get_next_batch_info(&self) -> RpcResult<NextL1BatchInfo>;
+
     #[method(name = "getL1BatchBlockRange")]
     async fn get_miniblock_range(&self, batch: L1BatchNumber) -> RpcResult<Option<(U64, U64)>>;
 
     #[method(name = "getBlockDetails")]
-    async fn get_block_details(
+    async fn get_block_details(&self, block: MiniblockId) -> RpcResult<Option<BlockDetails>>;
+
+    #[method(name = "getBlockDetailsRange")]
+    async fn get_block_details_range(
+        &self,
+        from: MiniblockNumber,
+        to: MiniblockNumber,
+    ) -> RpcResult<Vec<BlockDetails>>;
+
+    #[method(name = "getMiniblockHeader")]
+    async fn get_miniblock_header(
         &self,
         block_number: MiniblockNumber,
-    ) -> RpcResult<Option<BlockDetails>>;
+    ) -> RpcResult<Option<BlockHeaderDetails>>;
+
+    #[method(name = "getMiniblockByTimestamp")]
+    async fn get_miniblock_by_timestamp(
+        &self,
+        timestamp: u64,
+        rounding: TimestampRounding,
+    ) -> RpcResult<Option<MiniblockNumber>>;
 
     #[method(name = "getTransactionDetails")]
-    async fn get_transaction_details(&self, hash: H256) -> RpcResult<Option<TransactionDetails>>;
+    async fn get_transaction_details(
+        &self,
+        hash: H256,
+        include_gas_estimate: Option<bool>,
+        include_raw: Option<bool>,
+    ) -> RpcResult<Option<TransactionDetails>>;
+
+    #[method(name = "getTransactionDetailsBatch")]
+    async fn get_transaction_details_batch(
+        &self,
+        hashes: Vec<H256>,
+    ) -> RpcResult<Vec<(H256, Option<TransactionDetails>)>>;
+
+    #[method(name = "sendRawTransactionWithDetailedError")]
+    async fn send_raw_transaction_with_detailed_error(&self, tx_bytes: Bytes) -> RpcResult<H256>;
+
+    #[method(name = "setKnownBytecode")]
+    async fn set_known_bytecode(&self, bytecode: Bytes) -> RpcResult<bool>;
 
     #[method(name = "getRawBlockTransactions")]
     async fn get_raw_block_transactions(
         &self,
-        block_number: MiniblockNumber,
+        block: MiniblockId,
     ) -> RpcResult<Vec<zksync_types::Transaction>>;
 
     #[method(name = "getL1BatchDetails")]
     async fn get_l1_batch_details(&self, batch: L1BatchNumber)
         -> RpcResult<Option<L1BatchDetails>>;
 
+    #[method(name = "getL1BatchStats")]
+    async fn get_l1_batch_stats(&self, batch: L1BatchNumber) -> RpcResult<Option<L1BatchStats>>;
+
+    #[method(name = "getLatestL1BatchDetails")]
+    async fn get_latest_l1_batch_details(
+        &self,
+        stage: Option<L1BatchStage>,
+    ) -> RpcResult<Option<L1BatchDetails>>;
+
+    #[method(name = "getBatchL1CommitData")]
+    async fn get_batch_l1_commit_data(
+        &self,
+        batch: L1BatchNumber,
+    ) -> RpcResult<Option<BatchL1CommitData>>;
+
+    #[method(name = "getBatchCommitmentInputs")]
+    async fn get_batch_commitment_inputs(
+        &self,
+        batch: L1BatchNumber,
+    ) -> RpcResult<Option<BatchCommitmentInputs>>;
+
+    #[method(name = "getL1BatchFees")]
+    async fn get_l1_batch_fees(&self, batch: L1BatchNumber) -> RpcResult<Option<L1BatchFees>>;
+
+    #[method(name = "getEffectiveL1GasPriceForBatch")]
+    async fn get_effective_l1_gas_price_for_batch(
+        &self,
+        batch: L1BatchNumber,
+    ) -> RpcResult<Option<U64>>;
+
     #[method(name = "getBytecodeByHash")]
     async fn get_bytecode_by_hash(&self, hash: H256) -> RpcResult<Option<Vec<u8>>>;
 
+    #[method(name = "getBytecodeDetailsByHash")]
+    async fn get_bytecode_details_by_hash(&self, hash: H256) -> RpcResult<Option<BytecodeDetails>>;
+
+    #[method(name = "getContractCreationCode")]
+    async fn get_contract_creation_code(
+        &self,
+        address: Address,
+    ) -> RpcResult<Option<ContractCreationCode>>;
+
+    /// Deprecated: the `U64` return type cannot represent every possible gas price;
+    /// use `getL1GasPriceWei` instead.
     #[method(name = "getL1GasPrice")]
     async fn get_l1_gas_price(&self) -> RpcResult<U64>;
+
+    #[method(name = "getL1GasPriceHistory")]
+    async fn get_l1_gas_price_history(
+        &self,
+        from_miniblock: MiniblockNumber,
+        to_miniblock: MiniblockNumber,
+    ) -> RpcResult<Vec<(MiniblockNumber, U64)>>;
+
+    #[method(name = "getL1GasPriceWei")]
+    async fn get_l1_gas_price_wei(&self) -> RpcResult<U256>;
+
+    #[method(name = "getFeeParams")]
+    async fn get_fee_params(&self) -> RpcResult<FeeModelParams>;
+
+    #[method(name = "getNonceAt")]
+    async fn get_nonce_at(
+        &self,
+        address: Address,
+        block_or_batch: BlockOrBatchNumber,
+    ) -> RpcResult<U256>;
+
+    #[method(name = "getTransactionConfirmationsNeeded")]
+    async fn get_transaction_confirmations_needed(
+        &self,
+        hash: H256,
+    ) -> RpcResult<Option<TransactionConfirmationsNeeded>>;
+
+    #[method(name = "getBatchDiff")]
+    async fn get_batch_diff(
+        &self,
+        from_batch: L1BatchNumber,
+        to_batch: L1BatchNumber,
+        after: Option<H256>,
+    ) -> RpcResult<BatchDiff>;
+
+    #[method(name = "getBatchProvingStatus")]
+    async fn get_batch_proving_status(
+        &self,
+        batch: L1BatchNumber,
+    ) -> RpcResult<Option<L1BatchProvingStatus>>;
+
+    #[method(name = "getAddressActivity")]
+    async fn get_address_activity(&self, address: Address) -> RpcResult<AddressActivity>;
+
+    #[method(name = "getAddressTransactionCount")]
+    async fn get_address_transaction_count(
+        &self,
+        address: Address,
+    ) -> RpcResult<AddressTransactionCount>;
+
+    #[method(name = "getVerificationKeyHash")]
+    async fn get_verification_key_hash(
+        &self,
+        batch: Option<L1BatchNumber>,
+    ) -> RpcResult<Option<H256>>;
+
+    #[method(name = "getProtocolVersion")]
+    async fn get_protocol_version(
+        &self,
+        version_id: Option<u16>,
+    ) -> RpcResult<Option<ProtocolVersion>>;
+
+    #[method(name = "getTransactionsInBatch")]
+    async fn get_transactions_in_batch(
+        &self,
+        batch: L1BatchNumber,
+        cursor: Option<u32>,
+        limit: Option<u32>,
+    ) -> RpcResult<BatchTransactionsPage>;
+
+    #[method(name = "getContractDeploymentBytecodeHash")]
+    async fn get_contract_deployment_bytecode_hash(
+        &self,
+        address: Address,
+        block: Option<BlockId>,
+    ) -> RpcResult<Option<H256>>;
+
+    #[method(name = "getTransactionReceipt")]
+    async fn get_transaction_receipt(
+        &self,
+        tx_hash: H256,
+    ) -> RpcResult<Option<ExtendedTransactionReceipt>>;
+
+    #[method(name = "getStorageAtBatch")]
+    async fn get_storage_at_batch(
+        &self,
+        address: Address,
+        keys: Vec<H256>,
+        block: Option<MiniblockNumber>,
+    ) -> RpcResult<HashMap<H256, H256>>;
+
+    #[method(name = "getAccountStorageKeys")]
+    async fn get_account_storage_keys(
+        &self,
+        address: Address,
+        block: Option<MiniblockNumber>,
+        cursor: Option<H256>,
+        limit: Option<u32>,
+    ) -> RpcResult<AccountStorageKeysPage>;
+
+    #[method(name = "replayTransaction")]
+    async fn replay_transaction(&self, hash: H256) -> RpcResult<Option<TransactionReplay>>;
+
+    #[method(name = "getConnectionPoolStats")]
+    async fn get_connection_pool_stats(&self) -> RpcResult<ConnectionPoolStats>;
+
+    #[method(name = "getChainStats")]
+    async fn get_chain_stats(&self) -> RpcResult<ChainStats>;
+
+    #[method(name = "getRecentBatchEvents")]
+    async fn get_recent_batch_events(&self, limit: u32) -> RpcResult<Vec<L1BatchEvent>>;
 }
@@ -15,7 +15,11 @@ use rlp::Rlp;
 use serde::{de, Deserialize, Serialize, Serializer};
 
 pub use zksync_types::{
-    api::{Block, BlockNumber, Log, TransactionReceipt, TransactionRequest},
+    api::{
+        Block, BlockNumber, L1BatchEvent, Log, TransactionReceipt, TransactionRequest,
+        TransactionStageUpdate,
+    },
+    fee::FeeParams,
     vm_trace::{ContractSourceDebugInfo, VmDebugTrace, VmExecutionStep},
     web3::{
         ethabi,
@@ -35,6 +39,12 @@ pub struct Token {
     pub name: String,
     pub symbol: String,
     pub decimals: u8,
+    /// Whether the token contract has code on L2. Only set if on-chain verification was requested.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deployed: Option<bool>,
+    /// Whether the token is confirmed (i.e., well-known). Only set for single-token lookups.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub confirmed: Option<bool>,
 }
 
 /// Helper structure used to parse deserialized `Ethereum` transaction.
@@ -346,6 +356,9 @@ pub enum PubSubResult {
     Header(BlockHeader),
     Log(Log),
     TxHash(H256),
+    FeeParams(FeeParams),
+    TransactionStage(TransactionStageUpdate),
+    L1BatchEvent(L1BatchEvent),
     Syncing(bool),
 }
 
@@ -169,7 +169,7 @@ impl Checker {
 
                     let instance_miniblock = match instance_client
                         .client
-                        .get_block_details(next_block_to_check)
+                        .get_block_details(next_block_to_check.into())
                         .await
                     {
                         Ok(Some(miniblock)) => miniblock,
@@ -195,7 +195,7 @@ impl Checker {
                     };
 
                     let main_node_miniblock = match main_node_client
-                        .get_block_details(next_block_to_check)
+                        .get_block_details(next_block_to_check.into())
                         .await
                     {
                         Ok(Some(miniblock)) => miniblock,
@@ -295,7 +295,7 @@ impl Checker {
         for miniblock_num_to_check in start_miniblock.0..=finish_miniblock.0 {
             let main_node_miniblock = match self
                 .main_node_client
-                .get_block_details(MiniblockNumber(miniblock_num_to_check))
+                .get_block_details(MiniblockNumber(miniblock_num_to_check).into())
                 .await
             {
                 Ok(Some(miniblock)) => miniblock,
@@ -310,7 +310,7 @@ impl Checker {
             for instance_client in self.instance_clients.clone() {
                 let instance_miniblock = match instance_client
                     .client
-                    .get_block_details(MiniblockNumber(miniblock_num_to_check))
+                    .get_block_details(MiniblockNumber(miniblock_num_to_check).into())
                     .await?
                 {
                     Some(miniblock) => miniblock,
@@ -717,11 +717,11 @@ impl Checker {
 
         let main_node_tx_details = self
             .main_node_client
-            .get_transaction_details(*tx_hash)
+            .get_transaction_details(*tx_hash, None, None)
             .await?;
         let instance_tx_details = instance_client
             .client
-            .get_transaction_details(*tx_hash)
+            .get_transaction_details(*tx_hash, None, None)
             .await?;
 
         let tx_details_differences =
@@ -155,8 +155,9 @@ where
         );
         self.wallet
             .provider
-            .estimate_fee(execute.into())
+            .estimate_fee(execute.into(), None, None)
             .await
+            .map(Into::into)
             .map_err(Into::into)
     }
 }
@@ -179,8 +179,9 @@ where
         };
         self.wallet
             .provider
-            .estimate_fee(l2_tx.into())
+            .estimate_fee(l2_tx.into(), None, None)
             .await
+            .map(Into::into)
             .map_err(Into::into)
     }
 }